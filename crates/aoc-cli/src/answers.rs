@@ -0,0 +1,101 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use aoc_core::Part;
+
+/// Expected answers, indexed by day then part, as stored in `answers.toml`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, String>>);
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        fs::read_to_string(path)
+            .map_err(|error| format!("Cannot read {}: {error}", path.display()))?
+            .parse()
+    }
+
+    pub fn expected(&self, day: u8, part: Part) -> Option<&str> {
+        self.0
+            .get(&day.to_string())?
+            .get(&part.to_string())
+            .map(String::as_str)
+    }
+}
+
+impl FromStr for Answers {
+    type Err = String;
+
+    fn from_str(content: &str) -> Result<Self, Self::Err> {
+        toml::from_str(content).map_err(|error| format!("Invalid answers file: {error}"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_core::report::Verdict;
+    use aoc_core::Answer;
+
+    const ANSWERS: &str = r#"
+[1]
+1 = "68467"
+2 = "203420"
+
+[10]
+2 = '''
+##  ##
+#  #
+'''
+"#;
+
+    #[test]
+    fn parse_answers() {
+        let answers = ANSWERS.parse::<Answers>().unwrap();
+
+        assert_eq!(answers.expected(1, Part::Two), Some("203420"));
+        assert_eq!(answers.expected(10, Part::One), None);
+        assert_eq!(answers.expected(2, Part::One), None);
+    }
+
+    #[test]
+    fn check_answers() {
+        let answers = ANSWERS.parse::<Answers>().unwrap();
+
+        assert_eq!(
+            Verdict::of_answer(&Answer::Unsigned(68467), answers.expected(1, Part::One)),
+            Verdict::Pass,
+        );
+        assert_eq!(
+            Verdict::of_answer(&Answer::Unsigned(42), answers.expected(1, Part::Two)),
+            Verdict::Fail {
+                expected: "203420".to_string(),
+                actual: "42".to_string(),
+            },
+        );
+        assert_eq!(
+            Verdict::of_answer(&Answer::Unsigned(1), answers.expected(3, Part::One)),
+            Verdict::Missing
+        );
+    }
+
+    #[test]
+    fn check_multiline_answer() {
+        let answers = ANSWERS.parse::<Answers>().unwrap();
+
+        let result = Verdict::of_answer(
+            &Answer::grid("##  ##\n#  #  \n"),
+            answers.expected(10, Part::Two),
+        );
+
+        assert_eq!(result, Verdict::Pass);
+    }
+
+    #[test]
+    fn invalid_answers_file() {
+        assert!("[1".parse::<Answers>().is_err());
+    }
+}
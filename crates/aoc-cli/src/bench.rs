@@ -0,0 +1,85 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Summary {
+    pub min: Duration,
+    pub mean: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+}
+
+impl Summary {
+    pub fn new(durations: &[Duration]) -> Self {
+        let mut sorted = durations.to_vec();
+        sorted.sort();
+        let count = sorted.len() as f64;
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / count;
+        let variance = sorted
+            .iter()
+            .map(|duration| (duration.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / count;
+        let middle = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[middle - 1] + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+        Self {
+            min: sorted[0],
+            mean: Duration::from_secs_f64(mean),
+            median,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+pub fn measure<T, F: Fn() -> T>(warmup: u32, iterations: u32, f: F) -> Summary {
+    for _ in 0..warmup {
+        black_box(f());
+    }
+    let durations = (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect::<Vec<_>>();
+    Summary::new(&durations)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn summary_of_odd_number_of_durations() {
+        let durations = [5, 1, 3].map(Duration::from_millis);
+
+        let result = Summary::new(&durations);
+
+        assert_eq!(result.min, Duration::from_millis(1));
+        assert_eq!(result.mean, Duration::from_millis(3));
+        assert_eq!(result.median, Duration::from_millis(3));
+        assert_eq!(result.stddev.as_micros(), 1632);
+    }
+
+    #[test]
+    fn summary_of_even_number_of_durations() {
+        let durations = [4, 1, 2, 3].map(Duration::from_millis);
+
+        let result = Summary::new(&durations);
+
+        assert_eq!(result.median, Duration::from_micros(2500));
+    }
+
+    #[test]
+    fn measure_runs_warmup_and_iterations() {
+        let calls = std::cell::Cell::new(0);
+
+        measure(2, 3, || calls.set(calls.get() + 1));
+
+        assert_eq!(calls.get(), 5);
+    }
+}
@@ -0,0 +1,98 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Puzzles are released at midnight in New York (UTC-5 in December), i.e. 6 AM in Paris.
+const RELEASE_OFFSET_SECS: u64 = 5 * 3600;
+
+const SECS_PER_DAY: u64 = 24 * 3600;
+
+/// Day of December of the latest puzzle released at `now`, or `None` outside of December.
+pub fn december_day(now: SystemTime) -> Option<u8> {
+    let secs = now
+        .duration_since(UNIX_EPOCH)
+        .ok()?
+        .as_secs()
+        .checked_sub(RELEASE_OFFSET_SECS)?;
+    let (month, day) = month_and_day(secs / SECS_PER_DAY);
+    (month == 12).then_some(day)
+}
+
+/// Day to run when none is given: the puzzle of the day in December if it is implemented, the
+/// latest implemented day otherwise. Outside of December, no day is selected.
+pub fn default_day(now: SystemTime, implemented: impl IntoIterator<Item = u8>) -> Option<u8> {
+    let today = december_day(now)?;
+    let mut latest = None;
+    for day in implemented {
+        if day == today {
+            return Some(day);
+        }
+        latest = latest.max(Some(day));
+    }
+    latest
+}
+
+/// Month and day of the month of a number of days since 1970-01-01, in the Gregorian calendar.
+fn month_and_day(days_since_epoch: u64) -> (u8, u8) {
+    let days = days_since_epoch + 719_468;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    (month as u8, day as u8)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::time::Duration;
+
+    /// 2022-12-01T00:00:00Z
+    const DECEMBER_FIRST: u64 = 1_669_852_800;
+
+    fn at(secs: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn day_changes_at_release_time() {
+        let release = DECEMBER_FIRST + RELEASE_OFFSET_SECS;
+
+        assert_eq!(december_day(at(release - 1)), None);
+        assert_eq!(december_day(at(release)), Some(1));
+        assert_eq!(december_day(at(release + 11 * SECS_PER_DAY)), Some(12));
+        assert_eq!(december_day(at(release + 31 * SECS_PER_DAY)), None);
+    }
+
+    #[test]
+    fn leap_years_are_handled() {
+        // 2024-02-29T12:00:00Z
+        assert_eq!(month_and_day(1_709_208_000 / SECS_PER_DAY), (2, 29));
+    }
+
+    #[test]
+    fn today_is_selected_when_implemented() {
+        let now = at(DECEMBER_FIRST + 7 * SECS_PER_DAY + 12 * 3600);
+
+        assert_eq!(default_day(now, 1..=15), Some(8));
+    }
+
+    #[test]
+    fn latest_day_is_selected_when_today_is_not_implemented() {
+        let now = at(DECEMBER_FIRST + 19 * SECS_PER_DAY + 12 * 3600);
+
+        assert_eq!(default_day(now, 1..=15), Some(15));
+    }
+
+    #[test]
+    fn no_day_is_selected_outside_of_december() {
+        let now = at(DECEMBER_FIRST - 10 * SECS_PER_DAY);
+
+        assert_eq!(default_day(now, 1..=15), None);
+    }
+}
@@ -0,0 +1,784 @@
+use clap::{ArgAction, Args, Parser, Subcommand};
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+use std::str::FromStr;
+#[cfg(feature = "progress")]
+use std::sync::Arc;
+use std::time::Duration;
+
+use aoc_core::cancel::CancellationToken;
+use aoc_core::input::InputSource;
+use aoc_core::report::Format;
+use aoc_core::{Options, Params, Part};
+
+use crate::config::Config;
+#[cfg(feature = "progress")]
+use crate::progress::ProgressBarSink;
+
+#[derive(Debug, Parser)]
+#[command(
+    about = "Solutions for the Advent of Code",
+    args_conflicts_with_subcommands = true
+)]
+pub struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+    #[command(flatten)]
+    run: RunArgs,
+    /// Log what the solutions do on stderr; repeat for more details (`-vv` debug, `-vvv` trace)
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+    /// Print without colors, as when the `NO_COLOR` environment variable is set
+    #[arg(long, global = true)]
+    no_color: bool,
+    /// Year of the puzzles; the latest solved year when omitted
+    #[arg(long, global = true)]
+    year: Option<u16>,
+}
+
+impl Cli {
+    pub fn no_color(&self) -> bool {
+        self.no_color
+    }
+
+    pub fn verbose(&self) -> u8 {
+        self.verbose
+    }
+
+    pub fn year(&self) -> Option<u16> {
+        self.year
+    }
+
+    pub fn command(self) -> Command {
+        self.command.unwrap_or(Command::Run(self.run))
+    }
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Run the puzzles (default)
+    Run(RunArgs),
+    /// Run the puzzles several times and report timing statistics
+    Bench(BenchArgs),
+    /// Check the answers against the expected ones stored in an answers file
+    Check(CheckArgs),
+    /// Run every implementation of the days that have several and check that they agree
+    Compare(CompareArgs),
+    /// Add, inspect and scramble the inputs of the inputs directory
+    Input(InputArgs),
+    /// List the implemented days with their titles
+    List,
+    /// Submit the answer of a part to adventofcode.com and record the response
+    #[cfg(feature = "net")]
+    Submit(SubmitArgs),
+    /// Run a part under a sampling profiler and write a flamegraph of where it spends its time
+    #[cfg(feature = "profile")]
+    Profile(ProfileArgs),
+    /// Interactive dashboard to run the days and watch their visualizations
+    #[cfg(feature = "tui")]
+    Tui,
+}
+
+#[derive(Debug, Args)]
+pub struct Selection {
+    /// Day or range of days to run (e.g. `5..10` or `5..=10`); every day is run when omitted, except
+    /// in December where `run` picks the puzzle of the day
+    #[arg(value_name = "DAYS", value_parser = parse_day_range)]
+    day_position: Option<Days>,
+    /// Part to run, or the name of an extra part of the day (e.g. `inspections` for day 11); both
+    /// parts are run when omitted
+    #[arg(value_name = "PART", requires = "day_position")]
+    part_position: Option<PartArg>,
+    /// Day to run
+    #[arg(
+        short,
+        long,
+        conflicts_with = "day_position",
+        value_parser = parse_day
+    )]
+    day: Option<u8>,
+    /// Days to run, as a list of days and ranges (e.g. `1,3,7-9`)
+    #[arg(
+        long,
+        conflicts_with_all = ["day_position", "day"],
+        value_parser = parse_days
+    )]
+    days: Option<Days>,
+    /// Part to run
+    #[arg(short, long, conflicts_with = "part_position")]
+    part: Option<Part>,
+}
+
+/// Part given on the command line: a part of the puzzle or the name of an extra part.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum PartArg {
+    Puzzle(Part),
+    Extra(String),
+}
+
+impl FromStr for PartArg {
+    type Err = String;
+
+    fn from_str(part: &str) -> Result<Self, Self::Err> {
+        Ok(match part.parse() {
+            Ok(part) => Self::Puzzle(part),
+            Err(_) => Self::Extra(part.to_string()),
+        })
+    }
+}
+
+/// Days selected on the command line, in increasing order.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Days(pub BTreeSet<u8>);
+
+impl Selection {
+    pub fn days(&self) -> Option<Vec<u8>> {
+        match (self.day, &self.days, &self.day_position) {
+            (Some(day), _, _) => Some(vec![day]),
+            (None, Some(Days(days)), _) | (None, None, Some(Days(days))) => {
+                Some(days.iter().copied().collect())
+            }
+            (None, None, None) => None,
+        }
+    }
+
+    pub fn part(&self) -> Option<Part> {
+        match &self.part_position {
+            Some(PartArg::Puzzle(part)) => Some(*part),
+            _ => self.part,
+        }
+    }
+
+    /// Name of the extra part selected instead of the parts of the puzzle.
+    pub fn extra_part(&self) -> Option<&str> {
+        match &self.part_position {
+            Some(PartArg::Extra(name)) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// Parts of the puzzle selected for the commands that do not run the extra parts.
+    pub fn puzzle_parts(&self) -> Result<Vec<Part>, String> {
+        match self.extra_part() {
+            Some(name) => Err(format!("The extra part {name} can only be run with `run`")),
+            None => Ok(Part::selected(self.part())),
+        }
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct RunArgs {
+    #[command(flatten)]
+    selection: Selection,
+    /// Print additional statistics for the days that support them
+    #[arg(long)]
+    stats: bool,
+    /// Parameter passed to the statistics, e.g. `rows=10..20` for day 15
+    #[arg(long = "param", value_name = "KEY=VALUE", value_parser = parse_param)]
+    params: Vec<(String, String)>,
+    /// Replace the answers, and the values of the statistics, with `***`
+    #[arg(long)]
+    mask_answers: bool,
+    /// Only check that the inputs of the selected days are available and can be parsed
+    #[arg(long)]
+    dry_run: bool,
+    /// Write a Markdown table of the answers and timings to this file after the run
+    #[arg(long, value_name = "PATH")]
+    emit_markdown: Option<PathBuf>,
+    /// Time budget of each part in milliseconds; slower parts are aborted and fail the run
+    #[arg(long, value_name = "MS")]
+    max_time: Option<u64>,
+    /// Read the input of the selected day from this file, URL or `-` for the standard input,
+    /// instead of the one of the inputs directory
+    #[arg(long, value_name = "PATH|URL|-")]
+    input: Option<InputSource>,
+    /// Session cookie of adventofcode.com sent when downloading the input or the missing inputs of
+    /// the selected days, read from the configuration when missing
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+    /// Read the inputs from the `dayN.txt` files of this directory, when they exist
+    #[arg(long, value_name = "DIR")]
+    input_dir: Option<PathBuf>,
+    /// Run the selected days on their sample input and compare with the sample answers
+    #[arg(long, conflicts_with = "input")]
+    example: bool,
+    /// Implementation solving the selected days that have several, e.g. `perimeter` for day 15
+    #[arg(long = "impl", value_name = "NAME")]
+    implementation: Option<String>,
+    /// Run every day, even in December
+    #[arg(long, conflicts_with_all = ["day_position", "day", "days"])]
+    all: bool,
+    /// Output format [default: text]
+    #[arg(long, value_enum)]
+    format: Option<Format>,
+}
+
+impl RunArgs {
+    pub fn days(&self) -> Option<Vec<u8>> {
+        self.selection.days()
+    }
+
+    pub fn all(&self) -> bool {
+        self.all
+    }
+
+    /// Options of the run, taking the values missing from the command line from the configuration.
+    pub fn options(self, config: &Config) -> Options {
+        let session = self.session.or_else(|| config.session.clone());
+        Options {
+            part: self.selection.part(),
+            extra_part: self.selection.extra_part().map(str::to_string),
+            stats: self.stats,
+            mask_answers: self.mask_answers,
+            dry_run: self.dry_run,
+            emit_markdown: self.emit_markdown,
+            input: self
+                .input
+                .map(|source| source.with_session(|| session.clone())),
+            session,
+            input_dir: self.input_dir.or_else(|| config.input_dir.clone()),
+            example: self.example,
+            implementation: self.implementation,
+            max_time: self.max_time.map(Duration::from_millis),
+            format: self.format.or(config.format).unwrap_or_default(),
+            params: self.params.into_iter().collect::<Params>(),
+            day_params: config.day_params.clone(),
+            cancellation: CancellationToken::default(),
+            #[cfg(feature = "progress")]
+            progress: Some(Arc::new(ProgressBarSink::default())),
+            #[cfg(not(feature = "progress"))]
+            progress: None,
+        }
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct BenchArgs {
+    #[command(flatten)]
+    pub selection: Selection,
+    /// Number of measured runs of each part
+    #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    pub iterations: u32,
+    /// Number of unmeasured runs of each part before measuring
+    #[arg(short, long, default_value_t = 2)]
+    pub warmup: u32,
+    /// Implementation solving the selected days that have several, e.g. `perimeter` for day 15
+    #[arg(long = "impl", value_name = "NAME")]
+    pub implementation: Option<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct CheckArgs {
+    #[command(flatten)]
+    pub selection: Selection,
+    /// TOML file with the expected answer of each part, indexed by day then part
+    #[arg(long, default_value = "answers.toml")]
+    pub answers: PathBuf,
+}
+
+#[derive(Debug, Args)]
+pub struct CompareArgs {
+    #[command(flatten)]
+    pub selection: Selection,
+}
+
+#[derive(Debug, Args)]
+pub struct InputArgs {
+    #[command(subcommand)]
+    pub command: InputCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum InputCommand {
+    /// Copy or download the input of a day to the inputs directory and record its checksum
+    Add(InputAddArgs),
+    /// Record the checksums of the inputs of the inputs directory, e.g. after recording answers
+    Record {
+        /// Days whose input is recorded, as a list of days and ranges (e.g. `1,3,7-9`); every day
+        /// with an input when omitted
+        #[arg(value_parser = parse_days)]
+        days: Option<Days>,
+    },
+    /// Print the size, the number of lines and sections and the longest line of the input of a day
+    Stats {
+        #[arg(value_parser = parse_day)]
+        day: u8,
+    },
+    /// Print a shareable variant of the input of a day, with the same structure but other values
+    Scramble(InputScrambleArgs),
+    /// Compare the input of a day with another one: their sizes, their sections and the changed
+    /// lines with their numbers, e.g. to debug an input the answers are wrong on
+    Diff {
+        #[arg(value_parser = parse_day)]
+        day: u8,
+        /// File, URL or `-` for the standard input compared with the input of the day
+        #[arg(value_name = "PATH|URL|-")]
+        other: InputSource,
+    },
+}
+
+#[derive(Debug, Args)]
+pub struct InputAddArgs {
+    /// Day of the input
+    #[arg(value_parser = parse_day)]
+    pub day: u8,
+    /// File, URL or `-` for the standard input
+    #[arg(value_name = "PATH|URL|-")]
+    pub source: InputSource,
+    /// Session cookie of adventofcode.com sent when downloading the input, read from the
+    /// configuration when missing
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    pub session: Option<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct InputScrambleArgs {
+    /// Day whose input is scrambled, which picks the scrambler knowing its format
+    #[arg(value_parser = parse_day)]
+    pub day: u8,
+    /// File, URL or `-` for the standard input to scramble instead of the input of the day
+    #[arg(value_name = "PATH|URL|-")]
+    pub source: Option<InputSource>,
+    /// Seed of the random transformations, to reproduce a previous output
+    #[arg(long)]
+    pub seed: Option<u64>,
+}
+
+#[cfg(feature = "profile")]
+#[derive(Debug, Args)]
+pub struct ProfileArgs {
+    /// Day of the profiled part
+    #[arg(value_parser = parse_day)]
+    pub day: u8,
+    /// Profiled part
+    pub part: Part,
+    /// Number of runs of the part, to collect more samples from fast parts
+    #[arg(short = 'n', long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    pub iterations: u32,
+    /// Number of samples per second
+    #[arg(long, default_value_t = 1000)]
+    pub frequency: i32,
+    /// SVG file of the flamegraph [default: flamegraph-<day>-<part>.svg]
+    #[arg(short, long, value_name = "PATH")]
+    pub output: Option<PathBuf>,
+}
+
+#[cfg(feature = "profile")]
+impl ProfileArgs {
+    pub fn output(&self) -> PathBuf {
+        self.output
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(format!("flamegraph-{}-{}.svg", self.day, self.part)))
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct SubmitArgs {
+    /// Day of the submitted answer
+    #[arg(value_parser = parse_day)]
+    pub day: u8,
+    /// Part of the submitted answer
+    pub part: Part,
+    /// Session cookie of adventofcode.com, read from the configuration when missing
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    pub session: Option<String>,
+    /// TOML file where the submissions and their responses are recorded
+    #[arg(long, default_value = "submissions.toml")]
+    pub submissions: PathBuf,
+}
+
+fn parse_day(day: &str) -> Result<u8, String> {
+    match day.parse::<u32>() {
+        Ok(number @ 1..=25) => Ok(number as u8),
+        Ok(number) => Err(format!("day {number} is out of range, expected 1 to 25")),
+        Err(_) => Err(format!("'{day}' is not a day number")),
+    }
+}
+
+fn parse_day_range(days: &str) -> Result<Days, String> {
+    let range = if let Some((start, end)) = days.split_once("..=") {
+        parse_day(start)?..=parse_day(end)?
+    } else if let Some((start, end)) = days.split_once("..") {
+        let end = parse_day(end)?;
+        parse_day(start)?..=end.saturating_sub(1)
+    } else {
+        let day = parse_day(days)?;
+        day..=day
+    };
+    if range.is_empty() {
+        Err(format!("Empty range of days: {days}"))
+    } else {
+        Ok(Days(range.collect()))
+    }
+}
+
+fn parse_days(days: &str) -> Result<Days, String> {
+    let mut selected = BTreeSet::new();
+    for item in days.split(',') {
+        let Days(range) = match item.trim().split_once('-') {
+            Some((start, end)) => parse_day_range(&format!("{start}..={end}"))?,
+            None => parse_day_range(item.trim())?,
+        };
+        selected.extend(range);
+    }
+    Ok(Days(selected))
+}
+
+fn parse_param(param: &str) -> Result<(String, String), String> {
+    param
+        .split_once('=')
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .ok_or_else(|| format!("Invalid parameter, expected KEY=VALUE: {param}"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Command {
+        Cli::try_parse_from(["advent2022"].iter().chain(args))
+            .unwrap()
+            .command()
+    }
+
+    #[test]
+    fn positional_day_and_part() {
+        let Command::Run(args) = parse(&["9", "2"]) else {
+            panic!("expected run command");
+        };
+
+        assert_eq!(args.days(), Some(vec![9]));
+        assert_eq!(args.options(&Config::default()).part, Some(Part::Two));
+    }
+
+    #[test]
+    fn extra_part_by_name() {
+        let Command::Run(args) = parse(&["11", "inspections"]) else {
+            panic!("expected run command");
+        };
+
+        let options = args.options(&Config::default());
+        assert_eq!(options.part, None);
+        assert_eq!(options.extra_part.as_deref(), Some("inspections"));
+        assert!(options.parts().is_empty());
+
+        let Command::Bench(args) = parse(&["bench", "11", "inspections"]) else {
+            panic!("expected bench command");
+        };
+        assert!(args.selection.puzzle_parts().is_err());
+    }
+
+    #[test]
+    fn run_subcommand_with_flags() {
+        let Command::Run(args) = parse(&["run", "--day", "15", "--stats", "--param", "rows=1..3"])
+        else {
+            panic!("expected run command");
+        };
+
+        assert_eq!(args.days(), Some(vec![15]));
+        let options = args.options(&Config::default());
+        assert!(options.stats);
+        assert_eq!(options.params.get("rows"), Some("1..3"));
+    }
+
+    #[test]
+    fn no_arguments_runs_every_day() {
+        let Command::Run(args) = parse(&[]) else {
+            panic!("expected run command");
+        };
+
+        assert_eq!(args.days(), None);
+    }
+
+    #[test]
+    fn downloaded_input_uses_the_session_of_the_configuration() {
+        let url = "https://adventofcode.com/2022/day/1/input";
+        let Command::Run(args) = parse(&["1", "--input", url]) else {
+            panic!("expected run command");
+        };
+        let config = Config {
+            session: Some("abc".to_string()),
+            ..Config::default()
+        };
+
+        assert_eq!(
+            args.options(&config).input,
+            Some(InputSource::Url {
+                url: url.to_string(),
+                session: Some("abc".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn input_add_subcommand() {
+        let Command::Input(InputArgs {
+            command: InputCommand::Add(args),
+        }) = parse(&["input", "add", "5", "-"])
+        else {
+            panic!("expected input add command");
+        };
+
+        assert_eq!(args.day, 5);
+        assert_eq!(args.source, InputSource::Stdin);
+    }
+
+    #[test]
+    fn input_stats_subcommand() {
+        assert!(matches!(
+            parse(&["input", "stats", "8"]),
+            Command::Input(InputArgs {
+                command: InputCommand::Stats { day: 8 },
+            })
+        ));
+        assert!(Cli::try_parse_from(["advent2022", "input", "stats", "26"]).is_err());
+    }
+
+    #[test]
+    fn input_override() {
+        let Command::Run(args) = parse(&["3", "--input", "inputs/day3.txt"]) else {
+            panic!("expected run command");
+        };
+
+        assert_eq!(
+            args.options(&Config::default()).input,
+            Some(InputSource::File(PathBuf::from("inputs/day3.txt")))
+        );
+    }
+
+    #[test]
+    fn flags_override_configuration() {
+        let config = Config {
+            input_dir: Some(PathBuf::from("inputs")),
+            format: Some(Format::Json),
+            day_params: [(
+                9,
+                [("knots", "5"), ("rule", "lazy")]
+                    .map(|(key, value)| (key.to_string(), value.to_string()))
+                    .into_iter()
+                    .collect(),
+            )]
+            .into(),
+            ..Config::default()
+        };
+        let Command::Run(args) = parse(&["9", "--param", "knots=3"]) else {
+            panic!("expected run command");
+        };
+
+        let options = args.options(&config);
+
+        assert_eq!(options.format, Format::Json);
+        assert_eq!(options.input_dir, Some(PathBuf::from("inputs")));
+        assert_eq!(options.params(9).get("knots"), Some("3"));
+        assert_eq!(options.params(9).get("rule"), Some("lazy"));
+        assert_eq!(options.params(10).get("rule"), None);
+
+        let Command::Run(args) = parse(&["9", "--format", "text", "--input-dir", "other"]) else {
+            panic!("expected run command");
+        };
+
+        let options = args.options(&config);
+
+        assert_eq!(options.format, Format::Text);
+        assert_eq!(options.input_dir, Some(PathBuf::from("other")));
+    }
+
+    #[test]
+    fn all_conflicts_with_day_selection() {
+        let Command::Run(args) = parse(&["--all"]) else {
+            panic!("expected run command");
+        };
+
+        assert!(args.all());
+        assert!(Cli::try_parse_from(["advent2022", "--all", "--days", "1-3"]).is_err());
+    }
+
+    #[test]
+    fn verbose_is_counted_before_and_after_subcommands() {
+        let cli = Cli::try_parse_from(["advent2022", "-vv", "11"]).unwrap();
+        assert_eq!(cli.verbose(), 2);
+
+        let cli = Cli::try_parse_from(["advent2022", "bench", "12", "-v"]).unwrap();
+        assert_eq!(cli.verbose(), 1);
+    }
+
+    #[test]
+    fn year_is_global() {
+        let cli = Cli::try_parse_from(["advent2022", "--year", "2021", "5"]).unwrap();
+        assert_eq!(cli.year(), Some(2021));
+
+        let cli = Cli::try_parse_from(["advent2022", "check", "--year", "2021"]).unwrap();
+        assert_eq!(cli.year(), Some(2021));
+
+        let cli = Cli::try_parse_from(["advent2022", "list"]).unwrap();
+        assert_eq!(cli.year(), None);
+    }
+
+    #[test]
+    fn example_conflicts_with_input() {
+        let Command::Run(args) = parse(&["3..5", "--example"]) else {
+            panic!("expected run command");
+        };
+
+        assert!(args.options(&Config::default()).example);
+        assert!(
+            Cli::try_parse_from(["advent2022", "3", "--example", "--input", "day3.txt"]).is_err()
+        );
+    }
+
+    #[test]
+    fn max_time_in_milliseconds() {
+        let Command::Run(args) = parse(&["15", "--max-time", "500"]) else {
+            panic!("expected run command");
+        };
+
+        assert_eq!(
+            args.options(&Config::default()).max_time,
+            Some(Duration::from_millis(500))
+        );
+    }
+
+    #[test]
+    fn implementation_of_the_run() {
+        let Command::Run(args) = parse(&["15", "--impl", "perimeter"]) else {
+            panic!("expected run command");
+        };
+
+        assert_eq!(
+            args.options(&Config::default()).implementation.as_deref(),
+            Some("perimeter")
+        );
+    }
+
+    #[test]
+    fn bench_subcommand() {
+        let Command::Bench(args) = parse(&["bench", "11", "2", "-n", "5"]) else {
+            panic!("expected bench command");
+        };
+
+        assert_eq!(args.selection.days(), Some(vec![11]));
+        assert_eq!(args.selection.part(), Some(Part::Two));
+        assert_eq!(args.iterations, 5);
+        assert_eq!(args.warmup, 2);
+    }
+
+    #[test]
+    fn compare_subcommand() {
+        let Command::Compare(args) = parse(&["compare", "12..=15", "2"]) else {
+            panic!("expected compare command");
+        };
+
+        assert_eq!(args.selection.days(), Some(vec![12, 13, 14, 15]));
+        assert_eq!(args.selection.part(), Some(Part::Two));
+    }
+
+    #[test]
+    fn check_subcommand() {
+        let Command::Check(args) = parse(&["check", "7"]) else {
+            panic!("expected check command");
+        };
+
+        assert_eq!(args.selection.days(), Some(vec![7]));
+        assert_eq!(args.answers, PathBuf::from("answers.toml"));
+    }
+
+    #[test]
+    #[cfg(feature = "net")]
+    fn submit_subcommand() {
+        let Command::Submit(args) = parse(&["submit", "4", "2", "--session", "cookie"]) else {
+            panic!("expected submit command");
+        };
+
+        assert_eq!(args.day, 4);
+        assert_eq!(args.part, Part::Two);
+        assert_eq!(args.submissions, PathBuf::from("submissions.toml"));
+    }
+
+    #[test]
+    #[cfg(feature = "profile")]
+    fn profile_subcommand() {
+        let Command::Profile(args) = parse(&["profile", "15", "2", "-n", "3"]) else {
+            panic!("expected profile command");
+        };
+
+        assert_eq!((args.day, args.part, args.iterations), (15, Part::Two, 3));
+        assert_eq!(args.output(), PathBuf::from("flamegraph-15-2.svg"));
+    }
+
+    #[test]
+    fn input_scramble_subcommand() {
+        let Command::Input(InputArgs {
+            command: InputCommand::Scramble(args),
+        }) = parse(&["input", "scramble", "3", "--seed", "12"])
+        else {
+            panic!("expected input scramble command");
+        };
+
+        assert_eq!(args.day, 3);
+        assert_eq!(args.source, None);
+        assert_eq!(args.seed, Some(12));
+    }
+
+    #[test]
+    fn input_diff_subcommand() {
+        let Command::Input(InputArgs {
+            command: InputCommand::Diff { day, other },
+        }) = parse(&["input", "diff", "5", "other.txt"])
+        else {
+            panic!("expected input diff command");
+        };
+
+        assert_eq!(day, 5);
+        assert_eq!(other, InputSource::File(PathBuf::from("other.txt")));
+        assert!(Cli::try_parse_from(["advent2022", "input", "diff", "5"]).is_err());
+    }
+
+    #[test]
+    fn day_ranges() {
+        let Command::Run(exclusive) = parse(&["5..8"]) else {
+            panic!("expected run command");
+        };
+        let Command::Run(inclusive) = parse(&["run", "5..=8", "2"]) else {
+            panic!("expected run command");
+        };
+
+        assert_eq!(exclusive.days(), Some(vec![5, 6, 7]));
+        assert_eq!(inclusive.days(), Some(vec![5, 6, 7, 8]));
+        assert_eq!(inclusive.options(&Config::default()).part, Some(Part::Two));
+    }
+
+    #[test]
+    fn days_list() {
+        let Command::Check(args) = parse(&["check", "--days", "1,3,7-9,3"]) else {
+            panic!("expected check command");
+        };
+
+        assert_eq!(args.selection.days(), Some(vec![1, 3, 7, 8, 9]));
+    }
+
+    #[test]
+    fn invalid_day_ranges_are_rejected() {
+        assert!(parse_day_range("8..5").is_err());
+        assert!(parse_day_range("5..5").is_err());
+        assert!(parse_days("1,24-26").is_err());
+        assert!(Cli::try_parse_from(["advent2022", "3", "--days", "4"]).is_err());
+    }
+
+    #[test]
+    fn invalid_day_is_rejected() {
+        assert!(Cli::try_parse_from(["advent2022", "26"]).is_err());
+        assert!(Cli::try_parse_from(["advent2022", "one"]).is_err());
+    }
+
+    #[test]
+    fn day_parse_errors() {
+        assert_eq!(
+            parse_day("26"),
+            Err("day 26 is out of range, expected 1 to 25".to_string())
+        );
+        assert_eq!(
+            parse_day("one"),
+            Err("'one' is not a day number".to_string())
+        );
+    }
+}
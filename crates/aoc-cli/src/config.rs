@@ -0,0 +1,168 @@
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc_core::report::Format;
+use aoc_core::Params;
+
+/// Configuration file read from the working directory, after the one of the user.
+const LOCAL_CONFIG: &str = "advent.toml";
+
+/// Defaults of the command line, overridden by its flags.
+#[derive(Debug, Default)]
+pub struct Config {
+    pub input_dir: Option<PathBuf>,
+    pub session: Option<String>,
+    pub format: Option<Format>,
+    pub day_params: HashMap<u8, Params>,
+}
+
+impl Config {
+    /// Loads `~/.config/advent2022/config.toml` then `./advent.toml`, whose values take precedence.
+    pub fn load() -> Result<Self, String> {
+        let mut file = ConfigFile::default();
+        for path in user_config_path()
+            .into_iter()
+            .chain([PathBuf::from(LOCAL_CONFIG)])
+        {
+            if path.exists() {
+                file.merge(ConfigFile::read(&path)?);
+            }
+        }
+        file.try_into()
+    }
+}
+
+/// Content of a configuration file, e.g.
+///
+/// ```toml
+/// input_dir = "inputs"
+/// format = "json"
+///
+/// [day.9]
+/// knots = 5
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    input_dir: Option<PathBuf>,
+    session: Option<String>,
+    format: Option<Format>,
+    #[serde(default)]
+    day: BTreeMap<String, BTreeMap<String, toml::Value>>,
+}
+
+impl ConfigFile {
+    fn read(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|error| format!("Cannot read {}: {error}", path.display()))?;
+        toml::from_str(&content)
+            .map_err(|error| format!("Invalid configuration {}: {error}", path.display()))
+    }
+
+    fn merge(&mut self, other: ConfigFile) {
+        self.input_dir = other.input_dir.or(self.input_dir.take());
+        self.session = other.session.or(self.session.take());
+        self.format = other.format.or(self.format);
+        for (day, params) in other.day {
+            self.day.entry(day).or_default().extend(params);
+        }
+    }
+}
+
+impl TryFrom<ConfigFile> for Config {
+    type Error = String;
+
+    fn try_from(file: ConfigFile) -> Result<Self, Self::Error> {
+        let day_params = file
+            .day
+            .into_iter()
+            .map(|(day, params)| {
+                let day = day
+                    .parse()
+                    .ok()
+                    .filter(|day| (1..=25).contains(day))
+                    .ok_or_else(|| format!("Invalid day in configuration: {day}"))?;
+                let params = params
+                    .into_iter()
+                    .map(|(key, value)| (key, param_value(value)))
+                    .collect();
+                Ok((day, params))
+            })
+            .collect::<Result<_, String>>()?;
+        Ok(Self {
+            input_dir: file.input_dir,
+            session: file.session,
+            format: file.format,
+            day_params,
+        })
+    }
+}
+
+fn param_value(value: toml::Value) -> String {
+    match value {
+        toml::Value::String(value) => value,
+        value => value.to_string(),
+    }
+}
+
+fn user_config_path() -> Option<PathBuf> {
+    let config_dir = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(config_dir.join("advent2022").join("config.toml"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(content: &str) -> ConfigFile {
+        toml::from_str(content).unwrap()
+    }
+
+    #[test]
+    fn day_params_are_converted_to_strings() {
+        let file = parse(
+            r#"
+format = "json"
+
+[day.9]
+knots = 5
+rule = "lazy"
+
+[day.10]
+crt = "20x12"
+"#,
+        );
+
+        let config = Config::try_from(file).unwrap();
+
+        assert_eq!(config.format, Some(Format::Json));
+        assert_eq!(config.day_params[&9].get("knots"), Some("5"));
+        assert_eq!(config.day_params[&9].get("rule"), Some("lazy"));
+        assert_eq!(config.day_params[&10].get("crt"), Some("20x12"));
+    }
+
+    #[test]
+    fn local_file_overrides_user_file() {
+        let mut file =
+            parse("input_dir = \"~/aoc\"\nsession = \"abc\"\n[day.9]\nknots = 5\nrule = \"lazy\"");
+        file.merge(parse("input_dir = \"inputs\"\n[day.9]\nknots = 3"));
+
+        let config = Config::try_from(file).unwrap();
+
+        assert_eq!(config.input_dir, Some(PathBuf::from("inputs")));
+        assert_eq!(config.session.as_deref(), Some("abc"));
+        assert_eq!(config.day_params[&9].get("knots"), Some("3"));
+        assert_eq!(config.day_params[&9].get("rule"), Some("lazy"));
+    }
+
+    #[test]
+    fn invalid_configurations_are_rejected() {
+        assert!(toml::from_str::<ConfigFile>("colour = true").is_err());
+        assert!(Config::try_from(parse("[day.26]\nknots = 5")).is_err());
+    }
+}
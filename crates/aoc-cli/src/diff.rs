@@ -0,0 +1,129 @@
+use similar::{capture_diff_slices, Algorithm, DiffOp, DiffTag};
+
+use aoc_core::input::{byte_lines, BlocksExt};
+
+/// Structural comparison of two inputs of a day: their lines, their sections separated by blank
+/// lines and the lines that one has and not the other.
+pub struct InputDiff<'a> {
+    old: Vec<&'a [u8]>,
+    new: Vec<&'a [u8]>,
+    ops: Vec<DiffOp>,
+}
+
+impl<'a> InputDiff<'a> {
+    pub fn new(old: &'a [u8], new: &'a [u8]) -> Self {
+        let old = byte_lines(old).collect::<Vec<_>>();
+        let new = byte_lines(new).collect::<Vec<_>>();
+        let ops = capture_diff_slices(Algorithm::Myers, &old, &new);
+        Self { old, new, ops }
+    }
+
+    pub fn is_same(&self) -> bool {
+        self.ops.iter().all(|op| op.tag() == DiffTag::Equal)
+    }
+
+    /// Sizes of the inputs, then the changed lines numbered in the input they belong to, e.g.
+    /// `-    12 | move 1 from 2 to 1` for a line 12 of the first input missing in the second one.
+    pub fn lines(&self) -> Vec<String> {
+        let (removed, added) = self.ops.iter().fold((0, 0), |(removed, added), op| {
+            let (_, old, new) = op.as_tag_tuple();
+            match op.tag() {
+                DiffTag::Equal => (removed, added),
+                _ => (removed + old.len(), added + new.len()),
+            }
+        });
+        let mut lines = vec![format!(
+            "Lines: {} → {} ({added} added, {removed} removed)",
+            self.old.len(),
+            self.new.len()
+        )];
+        let old_sections = section_sizes(&self.old);
+        let new_sections = section_sizes(&self.new);
+        lines.push(format!(
+            "Sections: {} → {}",
+            old_sections.len(),
+            new_sections.len()
+        ));
+        for section in 0..old_sections.len().max(new_sections.len()) {
+            let size =
+                |sizes: &[usize]| sizes.get(section).map_or("-".to_string(), usize::to_string);
+            lines.push(format!(
+                "  Section {}: {} → {} lines",
+                section + 1,
+                size(&old_sections),
+                size(&new_sections)
+            ));
+        }
+        for op in &self.ops {
+            let (tag, old, new) = op.as_tag_tuple();
+            if tag == DiffTag::Equal {
+                continue;
+            }
+            lines.push(format!(
+                "@@ -{},{} +{},{} @@",
+                old.start + 1,
+                old.len(),
+                new.start + 1,
+                new.len()
+            ));
+            lines.extend(old.map(|index| numbered_line('-', index, self.old[index])));
+            lines.extend(new.map(|index| numbered_line('+', index, self.new[index])));
+        }
+        lines
+    }
+}
+
+fn section_sizes(lines: &[&[u8]]) -> Vec<usize> {
+    lines.iter().blocks().map(|block| block.len()).collect()
+}
+
+fn numbered_line(sign: char, index: usize, line: &[u8]) -> String {
+    format!("{sign}{:>6} | {}", index + 1, String::from_utf8_lossy(line))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn changed_lines_are_numbered() {
+        let diff = InputDiff::new(b"1\n2\n\n3\n4\n", b"1\n2\n\n3\n5\n6\n");
+
+        assert!(!diff.is_same());
+        assert_eq!(
+            diff.lines(),
+            vec![
+                "Lines: 5 → 6 (2 added, 1 removed)",
+                "Sections: 2 → 2",
+                "  Section 1: 2 → 2 lines",
+                "  Section 2: 2 → 3 lines",
+                "@@ -5,1 +5,2 @@",
+                "-     5 | 4",
+                "+     5 | 5",
+                "+     6 | 6",
+            ],
+        );
+    }
+
+    #[test]
+    fn missing_sections_are_shown() {
+        let diff = InputDiff::new(b"1\n\n2\n", b"1\n");
+
+        assert_eq!(
+            &diff.lines()[1..4],
+            [
+                "Sections: 2 → 1",
+                "  Section 1: 1 → 1 lines",
+                "  Section 2: 1 → - lines"
+            ],
+        );
+    }
+
+    #[test]
+    fn same_inputs() {
+        let diff = InputDiff::new(b"1\n2\n", b"1\r\n2\r\n");
+
+        assert!(diff.is_same());
+        assert_eq!(diff.lines().len(), 3);
+    }
+}
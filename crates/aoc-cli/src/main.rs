@@ -0,0 +1,1006 @@
+extern crate core;
+
+use answers::Answers;
+use aoc_core::anonymize::Rng;
+use aoc_core::cancel::CancellationToken;
+use aoc_core::input::{self, InputSource, ParseError};
+use aoc_core::memory::TrackingAllocator;
+use aoc_core::puzzle::{self, Puzzles};
+use aoc_core::report::{self, format_duration, DayReport, Format, Reporter, Verdict};
+use aoc_core::style::{self, Color};
+use aoc_core::{execute_with_budget, panic_message, parse_timed, Example, Options, Solution};
+use aoc_days_2022::{latest_year, scramblers, solutions_of};
+use clap::Parser;
+use config::Config;
+use diff::InputDiff;
+use manifest::{InputCheck, Manifest};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process::{self, ExitCode};
+use std::sync::Arc;
+#[cfg(feature = "net")]
+use std::thread;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+use tracing::Level;
+use tracing_subscriber::fmt::format::FmtSpan;
+
+#[global_allocator]
+static ALLOCATOR: TrackingAllocator = TrackingAllocator;
+
+mod answers;
+mod bench;
+mod calendar;
+mod cli;
+mod config;
+mod diff;
+mod manifest;
+#[cfg(feature = "profile")]
+mod profile;
+#[cfg(feature = "progress")]
+mod progress;
+#[cfg(feature = "net")]
+mod submit;
+#[cfg(feature = "tui")]
+mod tui;
+
+/// The solved days of the year, by day.
+type Solutions = BTreeMap<u8, Arc<dyn Solution>>;
+
+fn run(mut solutions: Solutions, days: Option<Vec<u8>>, options: &Options) -> ExitCode {
+    if let Some(dir) = &options.input_dir {
+        use_input_dir(&mut solutions, days.as_deref(), dir);
+    }
+    #[cfg(feature = "net")]
+    if let (None, None, false, false, Some(session)) = (
+        &options.input,
+        &options.input_dir,
+        options.example,
+        options.dry_run,
+        &options.session,
+    ) {
+        download_missing_inputs(&mut solutions, days.as_deref(), session);
+    }
+    if let Some(source) = &options.input {
+        if let Err(error) = override_input(&mut solutions, days.as_deref(), source) {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    }
+    if let Some(name) = &options.implementation {
+        if let Err(error) = use_implementation(&mut solutions, days.as_deref(), name) {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    }
+    let mut selected = match select(&solutions, days.as_deref()) {
+        Ok(selected) => selected,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Some(name) = &options.extra_part {
+        if let Err(error) = keep_extra_part(&mut selected, name) {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    }
+    let mut runs = if options.example {
+        match example_runs(&selected) {
+            Ok(runs) => runs,
+            Err(error) => {
+                eprintln!("{error}");
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        selected
+            .iter()
+            .map(|&solution| (Arc::clone(solution), None))
+            .collect()
+    };
+    if options.dry_run {
+        return dry_run(runs.iter().map(|(solution, _)| solution).collect());
+    }
+    if options.input.is_none() && options.input_dir.is_none() && !options.example {
+        for solution in &selected {
+            if let Some(warning) = changed_input(solution.day()) {
+                tracing::warn!("{warning}");
+            }
+        }
+    }
+    // The runs are the only owners of their solutions, which lets them parse their input.
+    drop(solutions);
+    let mut reporter = Reporter::new(options.format, options.mask_answers);
+    cancel_on_interrupt(&options.cancellation);
+    let start = Instant::now();
+    let mut example_results = Vec::new();
+    let mut skipped = Vec::new();
+    for (solution, example) in &mut runs {
+        if options.cancellation.is_cancelled() {
+            break;
+        }
+        // A panic of the parsing panics again in the parts, which report it.
+        let parse = Arc::get_mut(solution).and_then(|solution| {
+            panic::catch_unwind(AssertUnwindSafe(|| parse_timed(solution))).ok()
+        });
+        let mut report = match options.max_time {
+            Some(budget) => execute_with_budget(solution, options, budget),
+            None => solution.execute(options),
+        };
+        report.parse = parse;
+        if let Some(example) = example {
+            example_results.extend(compare_with_example(&report, example));
+        }
+        let day = report.day;
+        skipped.extend(report.skipped.iter().map(|error| (day, error.clone())));
+        reporter.report(report);
+    }
+    reporter.finish(start.elapsed());
+    report_skipped(&skipped);
+    if options.cancellation.is_cancelled() {
+        eprintln!("Interrupted");
+        return ExitCode::from(INTERRUPTED);
+    }
+    if options.example {
+        for result in &example_results {
+            let line = result.as_ref().unwrap_or_else(|line| line);
+            if options.format == Format::Text {
+                println!("{line}");
+            } else {
+                eprintln!("{line}");
+            }
+        }
+        if example_results.iter().any(Result::is_err) {
+            return ExitCode::FAILURE;
+        }
+    }
+    if let Some(path) = &options.emit_markdown {
+        if let Err(error) = reporter.write_markdown(path) {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    }
+    if reporter.has_slow_parts() || reporter.has_failed_parts() || reporter.has_wrong_parts() {
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+/// Exit code of a run stopped by Ctrl-C, as for a process killed by SIGINT.
+const INTERRUPTED: u8 = 130;
+
+/// Makes Ctrl-C stop the running part, so that the timings of the parts already run are still
+/// reported. A second Ctrl-C exits at once.
+fn cancel_on_interrupt(token: &CancellationToken) {
+    let token = token.clone();
+    let result = ctrlc::set_handler(move || {
+        if token.is_cancelled() {
+            process::exit(INTERRUPTED.into());
+        }
+        token.cancel();
+    });
+    if let Err(error) = result {
+        tracing::warn!("Ctrl-C will not stop the parts: {error}");
+    }
+}
+
+/// Lists the lines that the days skipped because they could not be parsed.
+fn report_skipped(skipped: &[(u8, ParseError)]) {
+    if skipped.is_empty() {
+        return;
+    }
+    eprintln!(
+        "{}",
+        Color::Yellow.paint(format!("Skipped {} malformed input lines:", skipped.len())),
+    );
+    for (day, error) in skipped {
+        eprintln!("  Day {day} — {error}");
+    }
+}
+
+/// Days selected on the command line or, in December, the puzzle of the day.
+fn run_days(solutions: &Solutions, args: &cli::RunArgs) -> Option<Vec<u8>> {
+    if args.all() {
+        return None;
+    }
+    args.days().or_else(|| {
+        let day = calendar::default_day(SystemTime::now(), solutions.keys().copied())?;
+        eprintln!("Running day {day}, use --all to run every day");
+        Some(vec![day])
+    })
+}
+
+/// Replaces the inputs of the selected days by the `dayN.txt` files of a directory, keeping the
+/// default input of the days without a file.
+fn use_input_dir(solutions: &mut Solutions, days: Option<&[u8]>, dir: &Path) {
+    for (&day, solution) in solutions.iter_mut() {
+        if days.is_some_and(|days| !days.contains(&day)) {
+            continue;
+        }
+        let path = dir.join(input::file_name(day));
+        if !path.is_file() {
+            tracing::debug!(
+                "No {}, using the default input of day {day}",
+                path.display()
+            );
+            continue;
+        }
+        *solution = Arc::from(solution.with_input(InputSource::File(path)));
+    }
+}
+
+/// Downloads the inputs missing from the inputs directory of the selected days, all at once on
+/// their own threads rather than one after the other when each day reads its input, and saves them
+/// like `input add`. The days whose download fails are run anyway and report the missing input.
+#[cfg(feature = "net")]
+fn download_missing_inputs(solutions: &mut Solutions, days: Option<&[u8]>, session: &str) {
+    let missing = solutions
+        .iter()
+        .filter(|&(day, _)| days.is_none_or(|days| days.contains(day)))
+        .filter(|&(&day, _)| !input::path_for_day(day).is_file())
+        .map(|(&day, solution)| (day, solution.year()))
+        .collect::<Vec<_>>();
+    if missing.is_empty() {
+        return;
+    }
+    eprintln!(
+        "Downloading the inputs of days {}",
+        format_days(missing.iter().map(|&(day, _)| day))
+    );
+    let downloads = thread::scope(|scope| {
+        missing
+            .iter()
+            .map(|&(day, year)| {
+                let source = InputSource::Url {
+                    url: format!("https://adventofcode.com/{year}/day/{day}/input"),
+                    session: Some(session.to_string()),
+                };
+                (day, scope.spawn(move || fetch_input(day, &source)))
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|(day, download)| (day, download.join().expect("download panicked")))
+            .collect::<Vec<_>>()
+    });
+    for (day, download) in downloads {
+        let saved = download.and_then(|(input, title)| save_input(day, &input, title));
+        match saved {
+            Ok(path) => {
+                let solution = solutions.get_mut(&day).expect("selected day");
+                *solution = Arc::from(solution.with_input(InputSource::File(path)));
+            }
+            Err(error) => tracing::warn!("Cannot download the input of day {day}: {error}"),
+        }
+    }
+}
+
+/// Replaces the input of the selected day. The standard input and the URLs are read before the run
+/// so that it is not timed with the first part, and the files are mapped when the day needs them.
+fn override_input(
+    solutions: &mut Solutions,
+    days: Option<&[u8]>,
+    source: &InputSource,
+) -> Result<(), String> {
+    let &[day] = days.unwrap_or_default() else {
+        return Err("A single day must be selected to run it on another input".to_string());
+    };
+    let available = format_days(solutions.keys().copied());
+    let solution = solutions
+        .get_mut(&day)
+        .ok_or_else(|| format!("day {day} not implemented, available: {available}"))?;
+    let source = match source {
+        InputSource::Embedded(_) => source.clone(),
+        InputSource::File(path) => {
+            input::map_file(path)?;
+            source.clone()
+        }
+        InputSource::Stdin | InputSource::Url { .. } => {
+            InputSource::Embedded(source.read()?.into_owned())
+        }
+    };
+    *solution = Arc::from(solution.with_input(source));
+    Ok(())
+}
+
+/// Replaces the selected days that have an implementation with this name by it, failing when
+/// none of them has one.
+fn use_implementation(
+    solutions: &mut Solutions,
+    days: Option<&[u8]>,
+    name: &str,
+) -> Result<(), String> {
+    let mut found = false;
+    let mut available = Vec::new();
+    for (day, solution) in solutions.iter_mut() {
+        if days.is_some_and(|days| !days.contains(day)) {
+            continue;
+        }
+        match solution.with_implementation(name) {
+            Some(implementation) => {
+                *solution = Arc::from(implementation);
+                found = true;
+            }
+            None if !solution.implementations().is_empty() => available.push(format!(
+                "{} for day {day}",
+                solution.implementations().join(", ")
+            )),
+            None => {}
+        }
+    }
+    match (found, available.is_empty()) {
+        (true, _) => Ok(()),
+        (false, true) => Err(format!(
+            "No selected day has an implementation named {name}"
+        )),
+        (false, false) => Err(format!(
+            "No selected day has an implementation named {name}, available: {}",
+            available.join("; ")
+        )),
+    }
+}
+
+/// Keeps the selected days that have an extra part with this name, failing when none of them has
+/// one.
+fn keep_extra_part(selected: &mut Vec<&Arc<dyn Solution>>, name: &str) -> Result<(), String> {
+    let available = selected
+        .iter()
+        .flat_map(|solution| {
+            let day = solution.day();
+            solution
+                .extra_parts()
+                .into_iter()
+                .map(move |extra| format!("{} for day {day}", extra.name))
+        })
+        .collect::<Vec<_>>();
+    selected.retain(|solution| {
+        solution
+            .extra_parts()
+            .iter()
+            .any(|extra| extra.name == name)
+    });
+    match (selected.is_empty(), available.is_empty()) {
+        (false, _) => Ok(()),
+        (true, true) => Err(format!("No selected day has an extra part named {name}")),
+        (true, false) => Err(format!(
+            "No selected day has an extra part named {name}, available: {}",
+            available.join(", ")
+        )),
+    }
+}
+
+/// A solution to run, with the sample it works on in `--example` mode.
+type Run = (Arc<dyn Solution>, Option<Example>);
+
+/// Solutions of the selected days working on each of their samples, with the samples.
+fn example_runs(selected: &[&Arc<dyn Solution>]) -> Result<Vec<Run>, String> {
+    let mut runs = Vec::new();
+    for solution in selected {
+        let examples = solution.examples();
+        if examples.is_empty() {
+            return Err(format!("day {} has no sample input", solution.day()));
+        }
+        for example in examples {
+            runs.push((
+                Arc::from(example.solution(solution.as_ref())),
+                Some(example),
+            ));
+        }
+    }
+    Ok(runs)
+}
+
+/// Compares the answers of a sample run with the sample answers, one line per part, in error
+/// when the answer differs.
+fn compare_with_example(report: &DayReport, example: &Example) -> Vec<Result<String, String>> {
+    report
+        .parts
+        .iter()
+        .map(|part| {
+            let verdict = Verdict::of(part, Some(example.answers.answer(part.part)));
+            let line = verdict.line(part, Some(&example.label()));
+            if verdict == Verdict::Pass {
+                Ok(line)
+            } else {
+                Err(line)
+            }
+        })
+        .collect()
+}
+
+/// Solutions of the selected days in order, or of every day when none is selected.
+fn select<'a>(
+    solutions: &'a Solutions,
+    days: Option<&[u8]>,
+) -> Result<Vec<&'a Arc<dyn Solution>>, String> {
+    let Some(days) = days else {
+        return Ok(solutions.values().collect());
+    };
+    let missing = days
+        .iter()
+        .copied()
+        .filter(|day| !solutions.contains_key(day))
+        .collect::<Vec<_>>();
+    match missing.as_slice() {
+        [] => Ok(days.iter().map(|day| &solutions[day]).collect()),
+        [day] => Err(format!(
+            "day {day} not implemented, available: {}",
+            format_days(solutions.keys().copied())
+        )),
+        _ => Err(format!(
+            "days {} not implemented, available: {}",
+            format_days(missing.into_iter()),
+            format_days(solutions.keys().copied())
+        )),
+    }
+}
+
+/// Formats increasing days as ranges, e.g. `1–15` or `1–3, 5`.
+fn format_days(days: impl Iterator<Item = u8>) -> String {
+    let mut ranges: Vec<(u8, u8)> = Vec::new();
+    for day in days {
+        match ranges.last_mut() {
+            Some((_, end)) if *end + 1 == day => *end = day,
+            _ => ranges.push((day, day)),
+        }
+    }
+    ranges
+        .into_iter()
+        .map(|(start, end)| {
+            if start == end {
+                start.to_string()
+            } else {
+                format!("{start}–{end}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn dry_run(solutions: Vec<&Arc<dyn Solution>>) -> ExitCode {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let mut failures = 0;
+    for solution in solutions {
+        let result = panic::catch_unwind(AssertUnwindSafe(|| solution.validate()))
+            .unwrap_or_else(|panic| Err(panic_message(panic.as_ref())));
+        match result {
+            Ok(()) => println!("{}: {}", solution.day(), Color::Green.paint("OK")),
+            Err(error) => {
+                failures += 1;
+                println!(
+                    "{}: {} — {error}",
+                    solution.day(),
+                    Color::Red.paint("FAILED")
+                );
+            }
+        }
+    }
+    panic::set_hook(default_hook);
+    if failures == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn bench(mut solutions: Solutions, args: &cli::BenchArgs) -> ExitCode {
+    if let Some(name) = &args.implementation {
+        if let Err(error) =
+            use_implementation(&mut solutions, args.selection.days().as_deref(), name)
+        {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    }
+    let selected = match select(&solutions, args.selection.days().as_deref()) {
+        Ok(selected) => selected,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let parts = match args.selection.puzzle_parts() {
+        Ok(parts) => parts,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    for solution in selected {
+        for &part in &parts {
+            let summary = bench::measure(args.warmup, args.iterations, || solution.part(part));
+            println!(
+                "{}:{part} — min {}, mean {}, median {}, stddev {}",
+                solution.day(),
+                format_duration(summary.min),
+                format_duration(summary.mean),
+                format_duration(summary.median),
+                format_duration(summary.stddev),
+            );
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn check(solutions: Solutions, args: &cli::CheckArgs) -> ExitCode {
+    let answers = match Answers::load(&args.answers) {
+        Ok(answers) => answers,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let selected = match select(&solutions, args.selection.days().as_deref()) {
+        Ok(selected) => selected,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Err(error) = args.selection.puzzle_parts() {
+        eprintln!("{error}");
+        return ExitCode::FAILURE;
+    }
+    let options = Options {
+        part: args.selection.part(),
+        ..Options::default()
+    };
+    let mut failures = 0;
+    let mut errors = 0;
+    for solution in selected {
+        if let Some(warning) = changed_input(solution.day()) {
+            println!(
+                "{} — {}, {warning}",
+                solution.day(),
+                Color::Yellow.paint("WARN")
+            );
+        }
+        for part in solution.execute(&options).parts {
+            let verdict = Verdict::of(&part, answers.expected(part.day, part.part));
+            match verdict {
+                Verdict::Fail { .. } => failures += 1,
+                Verdict::Error(_) | Verdict::Aborted => errors += 1,
+                Verdict::Pass | Verdict::Missing => {}
+            }
+            println!("{}", verdict.line(&part, None));
+        }
+    }
+    if failures > 0 || errors > 0 {
+        println!("{failures} wrong answers, {errors} errors");
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Runs the parts of the selected days with each of their implementations and checks that they
+/// give the same answers, skipping the days with a single implementation.
+fn compare(solutions: Solutions, args: &cli::CompareArgs) -> ExitCode {
+    let days = args.selection.days();
+    let selected = match select(&solutions, days.as_deref()) {
+        Ok(selected) => selected,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Err(error) = args.selection.puzzle_parts() {
+        eprintln!("{error}");
+        return ExitCode::FAILURE;
+    }
+    let options = Options {
+        part: args.selection.part(),
+        ..Options::default()
+    };
+    let mut compared = 0;
+    let mut disagreements = 0;
+    for solution in selected {
+        let mut implementations = solution
+            .implementations()
+            .into_iter()
+            .filter_map(|name| Some((name, solution.with_implementation(name)?)))
+            .collect::<Vec<_>>();
+        if implementations.len() < 2 {
+            if days.is_some() {
+                println!(
+                    "{} — {}, a single implementation",
+                    solution.day(),
+                    Color::Yellow.paint("SKIP")
+                );
+            }
+            continue;
+        }
+        compared += 1;
+        let reports = implementations
+            .iter_mut()
+            .map(|(name, implementation)| {
+                parse_timed(implementation.as_mut());
+                (*name, implementation.execute(&options))
+            })
+            .collect::<Vec<_>>();
+        for (index, part) in reports[0].1.parts.iter().enumerate() {
+            let answers = reports
+                .iter()
+                .map(|(name, report)| {
+                    let part = &report.parts[index];
+                    println!("{}", report::implementation_line(name, part));
+                    part.answer.as_ref()
+                })
+                .collect::<Vec<_>>();
+            let verdict = if answers
+                .iter()
+                .all(|answer| answer.is_some() && *answer == answers[0])
+            {
+                Color::Green.paint("AGREE")
+            } else {
+                disagreements += 1;
+                Color::Red.paint("DISAGREE")
+            };
+            println!("{}:{} — {verdict}", part.day, part.part);
+        }
+    }
+    if compared == 0 {
+        eprintln!("No selected day has several implementations");
+        return ExitCode::FAILURE;
+    }
+    if disagreements > 0 {
+        println!("{disagreements} parts without agreement");
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(feature = "profile")]
+fn profile(solutions: Solutions, args: &cli::ProfileArgs) -> ExitCode {
+    let solution = match select(&solutions, Some(&[args.day])) {
+        Ok(selected) => selected[0],
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Err(error) = solution.validate() {
+        eprintln!("{error}");
+        return ExitCode::FAILURE;
+    }
+    let output = args.output();
+    let result = profile::flamegraph(args.frequency, &output, || {
+        for _ in 0..args.iterations {
+            let _ = solution.part(args.part);
+        }
+    });
+    match result {
+        Ok(samples) => {
+            println!(
+                "Flamegraph of {}:{} written to {} ({samples} samples)",
+                args.day,
+                args.part,
+                output.display()
+            );
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Describes how the input of a day differs from the one recorded in the manifest of its directory,
+/// if it does.
+fn changed_input(day: u8) -> Option<String> {
+    match manifest::check_input(day) {
+        Ok(InputCheck::Changed { recorded, actual }) => Some(format!(
+            "the input of day {day} differs from the one the answers were recorded against \
+             (sha256 {actual}, recorded {recorded})"
+        )),
+        Ok(InputCheck::Same | InputCheck::Unrecorded) => None,
+        Err(error) => {
+            tracing::debug!("Cannot check the input of day {day}: {error}");
+            None
+        }
+    }
+}
+
+fn input_command(year: u16, args: &cli::InputArgs) -> ExitCode {
+    let result = match &args.command {
+        cli::InputCommand::Add(args) => add_input(args).map(|path| {
+            println!("Input of day {} saved to {}", args.day, path.display());
+        }),
+        cli::InputCommand::Record { days } => {
+            record_inputs(days.as_ref().map(|days| &days.0)).map(|(count, path)| {
+                println!("Checksums of {count} inputs recorded in {}", path.display());
+            })
+        }
+        cli::InputCommand::Stats { day } => input_stats(*day),
+        cli::InputCommand::Scramble(args) => scramble_input(year, args),
+        cli::InputCommand::Diff { day, other } => diff_inputs(*day, other),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// The directory where the inputs are added, the first one searched for them.
+fn inputs_dir() -> Result<PathBuf, String> {
+    let dir = input::input_dirs(None).remove(0);
+    fs::create_dir_all(&dir)
+        .map_err(|error| format!("Cannot create {}: {error}", dir.display()))?;
+    Ok(dir)
+}
+
+fn add_input(args: &cli::InputAddArgs) -> Result<PathBuf, String> {
+    let session = match &args.session {
+        Some(session) => Some(session.clone()),
+        None => Config::load()?.session,
+    };
+    let source = args.source.clone().with_session(|| session);
+    let (input, title) = fetch_input(args.day, &source)?;
+    if let Some(title) = &title {
+        println!("Day {}: {title}", args.day);
+    }
+    save_input(args.day, &input, title)
+}
+
+/// Reads an input with the title of its puzzle when it is downloaded from adventofcode.com.
+fn fetch_input(day: u8, source: &InputSource) -> Result<(Vec<u8>, Option<String>), String> {
+    let input = source.read()?.to_vec();
+    let title = puzzle::fetch_title(source).unwrap_or_else(|error| {
+        tracing::warn!("Cannot fetch the title of day {day}: {error}");
+        None
+    });
+    Ok((input, title))
+}
+
+/// Saves the input of a day to the inputs directory, with its checksum and its title.
+fn save_input(day: u8, input: &[u8], title: Option<String>) -> Result<PathBuf, String> {
+    let dir = inputs_dir()?;
+    let path = dir.join(input::file_name(day));
+    fs::write(&path, input).map_err(|error| format!("Cannot write {}: {error}", path.display()))?;
+    let mut manifest = Manifest::load(&dir)?;
+    manifest.record(day, input);
+    manifest.save(&dir)?;
+    if let Some(title) = title {
+        let mut puzzles = Puzzles::load()?;
+        puzzles.set_title(day, title);
+        puzzles.save()?;
+    }
+    Ok(path)
+}
+
+fn input_stats(day: u8) -> Result<(), String> {
+    let stats = input::Stats::of(&input::read_bytes(day)?);
+    println!("Input of day {day}: {}", input::path_for_day(day).display());
+    println!("Bytes: {}", stats.bytes);
+    println!("Lines: {}", stats.lines);
+    println!("Sections: {}", stats.sections);
+    println!("Longest line: {}", stats.longest_line);
+    Ok(())
+}
+
+fn record_inputs(days: Option<&BTreeSet<u8>>) -> Result<(usize, PathBuf), String> {
+    let dir = inputs_dir()?;
+    let mut manifest = Manifest::load(&dir)?;
+    let mut count = 0;
+    for day in 1..=25 {
+        let path = dir.join(input::file_name(day));
+        if days.is_some_and(|days| !days.contains(&day)) || !path.is_file() {
+            continue;
+        }
+        manifest.record(day, &input::map_file(&path)?);
+        count += 1;
+    }
+    manifest.save(&dir)?;
+    Ok((count, Manifest::path(&dir)))
+}
+
+fn diff_inputs(day: u8, other: &InputSource) -> Result<(), String> {
+    let input = input::read_bytes(day)?;
+    let other_input = other
+        .clone()
+        .with_session(|| Config::load().ok()?.session)
+        .read()?;
+    let diff = InputDiff::new(&input, &other_input);
+    println!("--- {}", input::path_for_day(day).display());
+    println!("+++ {other}");
+    for line in diff.lines() {
+        match line.chars().next() {
+            Some('-') => println!("{}", Color::Red.paint(&line)),
+            Some('+') => println!("{}", Color::Green.paint(&line)),
+            Some('@') => println!("{}", Color::Cyan.paint(&line)),
+            _ => println!("{line}"),
+        }
+    }
+    if diff.is_same() {
+        println!("The inputs have the same lines");
+    }
+    Ok(())
+}
+
+fn scramble_input(year: u16, args: &cli::InputScrambleArgs) -> Result<(), String> {
+    let seed = args.seed.unwrap_or_else(|| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_nanos() as u64)
+    });
+    let source = match &args.source {
+        Some(source) => source.clone().with_session(|| Config::load().ok()?.session),
+        None => InputSource::for_day(args.day),
+    };
+    let scramblers = scramblers(year);
+    let scrambled = scramblers.scramble(args.day, &source.read()?, &mut Rng::new(seed))?;
+    print!("{scrambled}");
+    if scramblers.knows_format(args.day) {
+        eprintln!("Scrambled with seed {seed}");
+    } else {
+        eprintln!(
+            "Scrambled the numbers with seed {seed}, no scrambler knows the format of day {}",
+            args.day
+        );
+    }
+    Ok(())
+}
+
+#[cfg(feature = "net")]
+fn submit(solutions: Solutions, args: &cli::SubmitArgs) -> ExitCode {
+    use submit::Outcome;
+
+    match submit_answer(&solutions, args) {
+        Ok((answer, outcome)) => {
+            let (day, part) = (args.day, args.part);
+            match outcome {
+                Outcome::Right => println!("{day}:{part} — {answer} is the right answer"),
+                Outcome::Wrong => println!("{day}:{part} — {answer} is not the right answer"),
+                Outcome::TooHigh => println!("{day}:{part} — {answer} is too high"),
+                Outcome::TooLow => println!("{day}:{part} — {answer} is too low"),
+                Outcome::TooRecently => println!("{day}:{part} — submitted too recently"),
+                Outcome::AlreadySolved => println!("{day}:{part} — already solved"),
+                Outcome::Unknown => println!("{day}:{part} — unexpected response"),
+            }
+            if outcome == Outcome::Right {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(feature = "net")]
+fn submit_answer(
+    solutions: &Solutions,
+    args: &cli::SubmitArgs,
+) -> Result<(String, submit::Outcome), String> {
+    use submit::{parse_response, post_answer, Submission, Submissions};
+
+    let session = match &args.session {
+        Some(session) => session.clone(),
+        None => Config::load()?.session.ok_or(
+            "No session cookie, give it with --session, AOC_SESSION or in the configuration",
+        )?,
+    };
+    let (day, part) = (args.day, args.part);
+    let solution = select(solutions, Some(&[day]))?[0];
+    let answer = solution.part(part).map_err(|error| error.to_string())?;
+    if answer.is_multiline() {
+        return Err(format!(
+            "The answer of {day}:{part} spans several lines and must be submitted by hand"
+        ));
+    }
+    let answer = answer.to_string();
+    let mut submissions = Submissions::load(&args.submissions)?;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs());
+    submissions.can_submit(day, part, &answer, now)?;
+    let (outcome, wait) =
+        parse_response(&post_answer(solution.year(), day, part, &answer, &session)?);
+    submissions.record(Submission {
+        day,
+        part: part.into(),
+        answer: answer.clone(),
+        outcome,
+        submitted_at: now,
+        retry_at: wait.map(|wait| now + wait),
+    });
+    submissions.save(&args.submissions)?;
+    Ok((answer, outcome))
+}
+
+fn list(solutions: &Solutions) {
+    for solution in solutions.values() {
+        let parts = match solution.implemented_parts().as_slice() {
+            [] => "no part".to_string(),
+            [part] => format!("part {part} only"),
+            _ => "both parts".to_string(),
+        };
+        let extras = solution
+            .extra_parts()
+            .iter()
+            .map(|extra| extra.name)
+            .collect::<Vec<_>>();
+        let parts = if extras.is_empty() {
+            parts
+        } else {
+            format!("{parts}, extra: {}", extras.join(", "))
+        };
+        match solution.title() {
+            "" => println!("{} ({parts})", solution.day()),
+            title => println!("{} — {title} ({parts})", solution.day()),
+        }
+    }
+}
+
+fn init_tracing(verbose: u8) {
+    let ansi = style::enabled();
+    let level = match verbose {
+        0 => Level::WARN,
+        1 => Level::INFO,
+        2 => Level::DEBUG,
+        _ => Level::TRACE,
+    };
+    tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_ansi(ansi)
+        .with_writer(std::io::stderr)
+        .init();
+}
+
+fn main() -> ExitCode {
+    let cli = cli::Cli::parse();
+    style::init(cli.no_color());
+    init_tracing(cli.verbose());
+    let Some(year) = cli.year().or_else(latest_year) else {
+        eprintln!("No day is solved");
+        return ExitCode::FAILURE;
+    };
+    let solutions = solutions_of(year);
+    if solutions.is_empty() {
+        eprintln!("No day of {year} is solved");
+        return ExitCode::FAILURE;
+    }
+    match cli.command() {
+        cli::Command::Run(args) => match Config::load() {
+            Ok(config) => {
+                let days = run_days(&solutions, &args);
+                run(solutions, days, &args.options(&config))
+            }
+            Err(error) => {
+                eprintln!("{error}");
+                ExitCode::FAILURE
+            }
+        },
+        cli::Command::Bench(args) => bench(solutions, &args),
+        cli::Command::Check(args) => check(solutions, &args),
+        cli::Command::Compare(args) => compare(solutions, &args),
+        cli::Command::Input(args) => input_command(year, &args),
+        cli::Command::List => {
+            list(&solutions);
+            ExitCode::SUCCESS
+        }
+        #[cfg(feature = "net")]
+        cli::Command::Submit(args) => submit(solutions, &args),
+        #[cfg(feature = "profile")]
+        cli::Command::Profile(args) => profile(solutions, &args),
+        #[cfg(feature = "tui")]
+        cli::Command::Tui => match tui::run(solutions) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(error) => {
+                eprintln!("{error}");
+                ExitCode::FAILURE
+            }
+        },
+    }
+}
@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc_core::input;
+
+/// File of a directory of inputs holding their checksums.
+const MANIFEST: &str = "manifest.toml";
+
+/// SHA-256 of the inputs of a directory, recorded when they are added, to warn when an input is
+/// replaced by one that the answers were not recorded against.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Manifest {
+    #[serde(default)]
+    sha256: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum InputCheck {
+    Same,
+    Changed { recorded: String, actual: String },
+    Unrecorded,
+}
+
+impl Manifest {
+    pub fn path(dir: &Path) -> PathBuf {
+        dir.join(MANIFEST)
+    }
+
+    pub fn load(dir: &Path) -> Result<Self, String> {
+        let path = Self::path(dir);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(&path)
+            .map_err(|error| format!("Cannot read {}: {error}", path.display()))?;
+        toml::from_str(&content)
+            .map_err(|error| format!("Invalid manifest {}: {error}", path.display()))
+    }
+
+    pub fn save(&self, dir: &Path) -> Result<(), String> {
+        let path = Self::path(dir);
+        let content = toml::to_string(self)
+            .map_err(|error| format!("Cannot serialize the manifest: {error}"))?;
+        fs::write(&path, content)
+            .map_err(|error| format!("Cannot write {}: {error}", path.display()))
+    }
+
+    pub fn record(&mut self, day: u8, input: &[u8]) {
+        self.sha256.insert(day.to_string(), sha256(input));
+    }
+
+    pub fn check(&self, day: u8, input: &[u8]) -> InputCheck {
+        let Some(recorded) = self.sha256.get(&day.to_string()) else {
+            return InputCheck::Unrecorded;
+        };
+        let actual = sha256(input);
+        if *recorded == actual {
+            InputCheck::Same
+        } else {
+            InputCheck::Changed {
+                recorded: recorded.clone(),
+                actual,
+            }
+        }
+    }
+}
+
+/// Compares the input file of a day with the checksum recorded in the manifest of its directory.
+pub fn check_input(day: u8) -> Result<InputCheck, String> {
+    let path = input::path_for_day(day);
+    let manifest = Manifest::load(path.parent().unwrap_or(Path::new(".")))?;
+    Ok(manifest.check(day, &input::map_file(&path)?))
+}
+
+pub fn sha256(input: &[u8]) -> String {
+    Sha256::digest(input)
+        .iter()
+        .fold(String::new(), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sha256_of_input() {
+        assert_eq!(
+            sha256(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+        );
+    }
+
+    #[test]
+    fn changed_inputs_are_detected() {
+        let mut manifest = Manifest::default();
+        manifest.record(1, b"1\n2\n");
+
+        assert_eq!(manifest.check(1, b"1\n2\n"), InputCheck::Same);
+        assert!(matches!(
+            manifest.check(1, b"1\n3\n"),
+            InputCheck::Changed { .. }
+        ));
+        assert_eq!(manifest.check(2, b"1\n2\n"), InputCheck::Unrecorded);
+    }
+}
@@ -0,0 +1,28 @@
+use std::fs::File;
+use std::path::Path;
+
+use pprof::ProfilerGuardBuilder;
+
+/// Libraries whose frames are not sampled, as they cannot be unwound safely.
+const BLOCKLIST: [&str; 4] = ["libc", "libgcc", "pthread", "vdso"];
+
+/// Samples the stacks while running `run` and writes them as a flamegraph SVG to `output`.
+/// Returns the number of samples.
+pub fn flamegraph(frequency: i32, output: &Path, run: impl FnOnce()) -> Result<isize, String> {
+    let guard = ProfilerGuardBuilder::default()
+        .frequency(frequency)
+        .blocklist(&BLOCKLIST)
+        .build()
+        .map_err(|error| format!("Cannot start the profiler: {error}"))?;
+    run();
+    let report = guard
+        .report()
+        .build()
+        .map_err(|error| format!("Cannot build the profile: {error}"))?;
+    let file = File::create(output)
+        .map_err(|error| format!("Cannot create {}: {error}", output.display()))?;
+    report
+        .flamegraph(file)
+        .map_err(|error| format!("Cannot write the flamegraph: {error}"))?;
+    Ok(report.data.values().sum())
+}
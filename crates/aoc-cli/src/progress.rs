@@ -0,0 +1,41 @@
+use std::sync::Mutex;
+
+use aoc_core::progress::ProgressSink;
+use indicatif::{ProgressBar, ProgressStyle};
+
+/// Shows the long loops of the parts as a progress bar on stderr, cleared at the end of each loop.
+/// Nothing is drawn when stderr is not a terminal.
+#[derive(Debug, Default)]
+pub struct ProgressBarSink(Mutex<Option<ProgressBar>>);
+
+impl ProgressSink for ProgressBarSink {
+    fn start(&self, label: &str, total: Option<u64>) {
+        let bar = match total {
+            Some(total) => ProgressBar::new(total).with_style(
+                ProgressStyle::with_template("{msg} [{bar:40}] {pos}/{len} ({eta})")
+                    .expect("Invalid progress bar template")
+                    .progress_chars("=> "),
+            ),
+            None => ProgressBar::new_spinner().with_style(
+                ProgressStyle::with_template("{spinner} {msg}: {pos}")
+                    .expect("Invalid progress bar template"),
+            ),
+        };
+        bar.set_message(label.to_string());
+        if let Some(previous) = self.0.lock().unwrap().replace(bar) {
+            previous.finish_and_clear();
+        }
+    }
+
+    fn advance(&self, steps: u64) {
+        if let Some(bar) = &*self.0.lock().unwrap() {
+            bar.inc(steps);
+        }
+    }
+
+    fn finish(&self) {
+        if let Some(bar) = self.0.lock().unwrap().take() {
+            bar.finish_and_clear();
+        }
+    }
+}
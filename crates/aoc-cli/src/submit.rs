@@ -0,0 +1,257 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use aoc_core::Part;
+
+const USER_AGENT: &str = "github.com/alightgoesout/advent2022 submit command";
+
+/// Response of adventofcode.com to a submitted answer.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    TooRecently,
+    AlreadySolved,
+    Unknown,
+}
+
+impl Outcome {
+    fn is_wrong(self) -> bool {
+        matches!(self, Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow)
+    }
+}
+
+/// A submitted answer, as recorded in the submissions file.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Submission {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// Unix timestamp of the submission, in seconds.
+    pub submitted_at: u64,
+    /// Unix timestamp before which adventofcode.com refuses new answers, in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_at: Option<u64>,
+}
+
+/// History of the submitted answers, stored in `submissions.toml`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Submissions {
+    #[serde(default, rename = "submission")]
+    submissions: Vec<Submission>,
+}
+
+impl Submissions {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        fs::read_to_string(path)
+            .map_err(|error| format!("Cannot read {}: {error}", path.display()))?
+            .parse()
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let content = toml::to_string(self)
+            .map_err(|error| format!("Cannot serialize submissions: {error}"))?;
+        fs::write(path, content)
+            .map_err(|error| format!("Cannot write {}: {error}", path.display()))
+    }
+
+    pub fn record(&mut self, submission: Submission) {
+        self.submissions.push(submission);
+    }
+
+    /// Checks locally whether an answer can be submitted at `now`, to avoid hitting the rate limit
+    /// or sending an answer that is already known.
+    pub fn can_submit(&self, day: u8, part: Part, answer: &str, now: u64) -> Result<(), String> {
+        let part = u8::from(part);
+        if let Some(retry_at) = self
+            .submissions
+            .iter()
+            .filter_map(|submission| submission.retry_at)
+            .max()
+            .filter(|&retry_at| retry_at > now)
+        {
+            return Err(format!(
+                "Submitted too recently, wait {}s before trying again",
+                retry_at - now
+            ));
+        }
+        let previous = self
+            .submissions
+            .iter()
+            .filter(|submission| submission.day == day && submission.part == part);
+        for submission in previous {
+            if submission.outcome == Outcome::Right {
+                return Err(format!(
+                    "{day}:{part} is already solved with {}",
+                    submission.answer
+                ));
+            }
+            if submission.outcome.is_wrong() && submission.answer == answer {
+                return Err(format!("{answer} was already rejected for {day}:{part}"));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Submissions {
+    type Err = String;
+
+    fn from_str(content: &str) -> Result<Self, Self::Err> {
+        toml::from_str(content).map_err(|error| format!("Invalid submissions file: {error}"))
+    }
+}
+
+/// Reads the outcome of a submission, and how long to wait before the next one, from the HTML
+/// page returned by adventofcode.com.
+pub fn parse_response(page: &str) -> (Outcome, Option<u64>) {
+    let text = page
+        .split_once("<article>")
+        .map_or(page, |(_, article)| article);
+    let outcome = if text.contains("That's the right answer") {
+        Outcome::Right
+    } else if text.contains("You gave an answer too recently") {
+        Outcome::TooRecently
+    } else if text.contains("You don't seem to be solving the right level") {
+        Outcome::AlreadySolved
+    } else if text.contains("your answer is too high") {
+        Outcome::TooHigh
+    } else if text.contains("your answer is too low") {
+        Outcome::TooLow
+    } else if text.contains("That's not the right answer") {
+        Outcome::Wrong
+    } else {
+        Outcome::Unknown
+    };
+    (outcome, parse_wait(text))
+}
+
+/// Parses `You have 1m 20s left to wait` or `please wait 5 minutes before trying again`.
+fn parse_wait(text: &str) -> Option<u64> {
+    if let Some((before, _)) = text.split_once(" left to wait") {
+        let duration = before.rsplit_once("You have ")?.1;
+        return duration
+            .split_whitespace()
+            .map(|component| {
+                let (value, unit) =
+                    component.split_at(component.find(|c: char| !c.is_ascii_digit())?);
+                let value = value.parse::<u64>().ok()?;
+                match unit {
+                    "h" => Some(value * 3600),
+                    "m" => Some(value * 60),
+                    "s" => Some(value),
+                    _ => None,
+                }
+            })
+            .sum();
+    }
+    let (_, after) = text.split_once("wait ")?;
+    let (amount, rest) = after.split_once(' ')?;
+    let amount = match amount {
+        "one" => 1,
+        amount => amount.parse().ok()?,
+    };
+    rest.starts_with("minute").then_some(amount * 60)
+}
+
+/// Posts an answer to adventofcode.com and returns the response page.
+pub fn post_answer(
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &str,
+    session: &str,
+) -> Result<String, String> {
+    let url = format!("https://adventofcode.com/{year}/day/{day}/answer");
+    ureq::post(&url)
+        .set("Cookie", &format!("session={session}"))
+        .set("User-Agent", USER_AGENT)
+        .send_form(&[("level", &part.to_string()), ("answer", answer)])
+        .map_err(|error| format!("Cannot submit the answer: {error}"))?
+        .into_string()
+        .map_err(|error| format!("Cannot read the response: {error}"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn submission(part: u8, answer: &str, outcome: Outcome, retry_at: Option<u64>) -> Submission {
+        Submission {
+            day: 4,
+            part,
+            answer: answer.to_string(),
+            outcome,
+            submitted_at: 1000,
+            retry_at,
+        }
+    }
+
+    #[test]
+    fn parse_right_answer() {
+        let page = "<main><article><p>That's the right answer! You are one gold star closer.</p></article></main>";
+
+        assert_eq!(parse_response(page), (Outcome::Right, None));
+    }
+
+    #[test]
+    fn parse_wrong_answer_with_wait() {
+        let page = "<article><p>That's not the right answer; your answer is too low. Please wait one minute before trying again.</p></article>";
+
+        assert_eq!(parse_response(page), (Outcome::TooLow, Some(60)));
+    }
+
+    #[test]
+    fn parse_too_recently() {
+        let page = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 20s left to wait.</p></article>";
+
+        assert_eq!(parse_response(page), (Outcome::TooRecently, Some(80)));
+    }
+
+    #[test]
+    fn parse_already_solved() {
+        let page = "<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>";
+
+        assert_eq!(parse_response(page), (Outcome::AlreadySolved, None));
+    }
+
+    #[test]
+    fn submissions_round_trip() {
+        let mut submissions = Submissions::default();
+        submissions.record(submission(1, "477", Outcome::Right, None));
+        submissions.record(submission(2, "12", Outcome::TooLow, Some(1060)));
+
+        let content = toml::to_string(&submissions).unwrap();
+        let result = content.parse::<Submissions>().unwrap();
+
+        assert_eq!(result.submissions, submissions.submissions);
+    }
+
+    #[test]
+    fn rate_limit_is_enforced_locally() {
+        let mut submissions = Submissions::default();
+        submissions.record(submission(2, "12", Outcome::TooLow, Some(1060)));
+
+        assert!(submissions.can_submit(4, Part::Two, "830", 1030).is_err());
+        assert!(submissions.can_submit(4, Part::Two, "12", 1090).is_err());
+        assert_eq!(submissions.can_submit(4, Part::Two, "830", 1090), Ok(()));
+    }
+
+    #[test]
+    fn solved_parts_are_not_submitted_again() {
+        let mut submissions = Submissions::default();
+        submissions.record(submission(1, "477", Outcome::Right, None));
+
+        assert!(submissions.can_submit(4, Part::One, "478", 2000).is_err());
+        assert_eq!(submissions.can_submit(4, Part::Two, "830", 2000), Ok(()));
+    }
+}
@@ -0,0 +1,226 @@
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+use std::sync::Arc;
+
+use aoc_core::geometry::GridPos;
+use aoc_core::plot::plot;
+use aoc_core::report::{format_duration, DayReport};
+use aoc_core::{Answer, Options, Part, Solution};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Paragraph, Row, Table, TableState};
+use ratatui::{DefaultTerminal, Frame};
+
+const HELP: &str = "↑/↓ select · enter run · a run all · v toggle visualization · q quit";
+
+/// Dashboard listing the days with their latest answers and timings.
+struct Dashboard {
+    solutions: BTreeMap<u8, Arc<dyn Solution>>,
+    reports: BTreeMap<u8, DayReport>,
+    table: TableState,
+    visualization: bool,
+    status: String,
+}
+
+impl Dashboard {
+    fn new(solutions: BTreeMap<u8, Arc<dyn Solution>>) -> Self {
+        Self {
+            solutions,
+            reports: BTreeMap::new(),
+            table: TableState::default().with_selected(Some(0)),
+            visualization: false,
+            status: String::new(),
+        }
+    }
+
+    fn selected(&self) -> Option<&dyn Solution> {
+        let index = self.table.selected()?;
+        self.solutions.values().nth(index).map(Arc::as_ref)
+    }
+
+    fn run_selected(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        let Some(solution) = self.selected() else {
+            return Ok(());
+        };
+        let day = solution.day();
+        if self.visualization && solution.can_visualize() {
+            ratatui::restore();
+            let answer = solution.visualize();
+            print!("\nPress enter to go back to the dashboard");
+            io::stdout().flush()?;
+            io::stdin().read_line(&mut String::new())?;
+            *terminal = ratatui::init();
+            self.status = match answer {
+                Some(answer) => format!("Day {day} visualized — {answer}"),
+                None => format!("Day {day} cannot be visualized"),
+            };
+        } else {
+            self.reports
+                .insert(day, solution.execute(&Options::default()));
+            self.status = format!("Day {day} done");
+        }
+        Ok(())
+    }
+
+    fn run_all(&mut self) {
+        for solution in self.solutions.values() {
+            self.reports
+                .insert(solution.day(), solution.execute(&Options::default()));
+        }
+        self.status = "All days done".to_string();
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [table_area, details_area, help_area] = Layout::vertical([
+            Constraint::Min(5),
+            Constraint::Length(10),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        let rows = self.solutions.values().map(|solution| {
+            let report = self.reports.get(&solution.day());
+            let mut cells = vec![
+                solution.day().to_string(),
+                solution.title().to_string(),
+                if solution.can_visualize() { "✓" } else { "" }.to_string(),
+            ];
+            for part in Part::ALL {
+                let part_report =
+                    report.and_then(|report| report.parts.iter().find(|p| p.part == part));
+                cells.push(part_report.map_or(String::new(), |p| {
+                    match (&p.answer, &p.error) {
+                        (Some(answer), _) => answer
+                            .to_string()
+                            .lines()
+                            .next()
+                            .unwrap_or_default()
+                            .to_string(),
+                        (None, Some(_)) => "ERROR".to_string(),
+                        (None, None) => "aborted".to_string(),
+                    }
+                }));
+                cells.push(part_report.map_or(String::new(), |p| format_duration(p.duration)));
+            }
+            Row::new(cells)
+        });
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(3),
+                Constraint::Length(24),
+                Constraint::Length(3),
+                Constraint::Fill(1),
+                Constraint::Length(10),
+                Constraint::Fill(1),
+                Constraint::Length(10),
+            ],
+        )
+        .header(
+            Row::new([
+                "Day", "Title", "Vis", "Part one", "Time", "Part two", "Time",
+            ])
+            .style(Style::new().add_modifier(Modifier::BOLD)),
+        )
+        .row_highlight_style(Style::new().add_modifier(Modifier::REVERSED))
+        .block(Block::bordered().title(format!(
+            " Advent of Code{} — visualization {} ",
+            self.solutions
+                .values()
+                .next()
+                .map_or(String::new(), |solution| format!(" {}", solution.year())),
+            if self.visualization { "on" } else { "off" }
+        )));
+        frame.render_stateful_widget(table, table_area, &mut self.table);
+
+        let details = self
+            .selected()
+            .and_then(|solution| self.reports.get(&solution.day()))
+            .map(|report| {
+                report
+                    .parts
+                    .iter()
+                    .flat_map(|part| {
+                        let text = match (&part.answer, &part.error) {
+                            (Some(answer), _) if answer.is_multiline() => {
+                                let picture = fit(
+                                    answer,
+                                    usize::from(details_area.width.saturating_sub(2)),
+                                    usize::from(details_area.height.saturating_sub(3)),
+                                );
+                                format!("{}:\n{picture}", part.label)
+                            }
+                            (Some(answer), _) => format!("{}: {answer}", part.label),
+                            (None, Some(error)) => error.clone(),
+                            (None, None) => "aborted".to_string(),
+                        };
+                        text.lines()
+                            .map(|line| Line::raw(line.to_string()))
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        frame.render_widget(
+            Paragraph::new(details).block(Block::bordered().title(format!(" {} ", self.status))),
+            details_area,
+        );
+        frame.render_widget(Line::raw(HELP), help_area);
+    }
+}
+
+/// The picture of `answer`, or a map of its marks, any character but `.` and spaces, scaled down
+/// to `width`×`height` characters when it does not fit.
+fn fit(answer: &Answer, width: usize, height: usize) -> String {
+    let picture = answer.to_string();
+    let rows = picture.lines().collect::<Vec<_>>();
+    if rows.len() <= height && rows.iter().all(|row| row.chars().count() <= width) {
+        return picture;
+    }
+    let marks = rows.iter().enumerate().flat_map(|(row, line)| {
+        line.chars()
+            .enumerate()
+            .filter(|(_, mark)| !matches!(mark, '.' | ' '))
+            .map(move |(column, _)| GridPos::new(row, column))
+    });
+    // Room for the scale, the axis and the labels of the map.
+    let margin = rows.len().to_string().len() + 2;
+    plot(
+        marks,
+        width.saturating_sub(margin),
+        height.saturating_sub(3),
+    )
+    .trim_end()
+    .to_string()
+}
+
+pub fn run(solutions: BTreeMap<u8, Arc<dyn Solution>>) -> io::Result<()> {
+    let mut dashboard = Dashboard::new(solutions);
+    let mut terminal = ratatui::init();
+    let result = event_loop(&mut dashboard, &mut terminal);
+    ratatui::restore();
+    result
+}
+
+fn event_loop(dashboard: &mut Dashboard, terminal: &mut DefaultTerminal) -> io::Result<()> {
+    loop {
+        terminal.draw(|frame| dashboard.draw(frame))?;
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+            KeyCode::Up | KeyCode::Char('k') => dashboard.table.select_previous(),
+            KeyCode::Down | KeyCode::Char('j') => dashboard.table.select_next(),
+            KeyCode::Enter | KeyCode::Char('r') => dashboard.run_selected(terminal)?,
+            KeyCode::Char('a') => dashboard.run_all(),
+            KeyCode::Char('v') => dashboard.visualization = !dashboard.visualization,
+            _ => {}
+        }
+    }
+}
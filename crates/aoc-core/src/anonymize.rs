@@ -0,0 +1,166 @@
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+
+/// Small SplitMix64 generator, enough to scramble inputs reproducibly from a seed.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    pub fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    pub fn in_range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let size = (range.end() - range.start() + 1) as u64;
+        range.start() + (self.next_u64() % size) as i64
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// Rewrites an input into a shareable variant with the same structure, or tells why it cannot.
+pub type Scrambler = fn(&[u8], &mut Rng) -> Result<String, String>;
+
+/// Scramblers of the days that know the format of their input, the other days falling back to
+/// `scramble_numbers`.
+#[derive(Debug, Default)]
+pub struct Scramblers(BTreeMap<u8, Scrambler>);
+
+impl Scramblers {
+    pub fn register(&mut self, day: u8, scrambler: Scrambler) -> &mut Self {
+        self.0.insert(day, scrambler);
+        self
+    }
+
+    pub fn knows_format(&self, day: u8) -> bool {
+        self.0.contains_key(&day)
+    }
+
+    pub fn scramble(&self, day: u8, input: &[u8], rng: &mut Rng) -> Result<String, String> {
+        match self.0.get(&day) {
+            Some(scrambler) => scrambler(input, rng),
+            None => Ok(scramble_numbers(input, rng)),
+        }
+    }
+}
+
+/// Replaces every number with a random one of as many digits, keeping the rest of the input. The
+/// answers change but the shape of the input does not.
+pub fn scramble_numbers(input: &[u8], rng: &mut Rng) -> String {
+    let input = String::from_utf8_lossy(input);
+    let mut scrambled = String::with_capacity(input.len());
+    let mut previous_is_digit = false;
+    for c in input.chars() {
+        let is_digit = c.is_ascii_digit();
+        if !is_digit {
+            scrambled.push(c);
+        } else if previous_is_digit {
+            scrambled.push(*rng.choose(&DIGITS));
+        } else {
+            // A leading zero would change the length of the number once parsed
+            scrambled.push(if c == '0' {
+                c
+            } else {
+                *rng.choose(&DIGITS[1..])
+            });
+        }
+        previous_is_digit = is_digit;
+    }
+    scrambled
+}
+
+const DIGITS: [char; 10] = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_values() {
+        let mut rng1 = Rng::new(42);
+        let mut rng2 = Rng::new(42);
+
+        let result1 = (0..5).map(|_| rng1.next_u64()).collect::<Vec<_>>();
+        let result2 = (0..5).map(|_| rng2.next_u64()).collect::<Vec<_>>();
+
+        assert_eq!(result1, result2);
+    }
+
+    #[test]
+    fn shuffle_keeps_items() {
+        let mut items = (0..20).collect::<Vec<_>>();
+
+        Rng::new(7).shuffle(&mut items);
+
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn scrambled_numbers_keep_their_digit_count() {
+        let input = b"Sensor at x=2, y=18: closest beacon is at x=-2, y=150\n0,10\n";
+
+        let scrambled = scramble_numbers(input, &mut Rng::new(4));
+
+        let shape = |input: &str| {
+            input
+                .chars()
+                .map(|c| if c.is_ascii_digit() { '#' } else { c })
+                .collect::<String>()
+        };
+        assert_ne!(scrambled.as_bytes(), input);
+        assert_eq!(shape(&scrambled), shape(&String::from_utf8_lossy(input)));
+        let last_line = scrambled.lines().last().unwrap();
+        assert!(last_line.starts_with("0,") && !last_line.starts_with("0,0"));
+    }
+
+    #[test]
+    fn registered_scramblers_take_precedence() {
+        let mut scramblers = Scramblers::default();
+        scramblers.register(2, |_, _| Ok("A X\n".to_string()));
+        scramblers.register(4, |_, _| Err("Line 1: Invalid pair".to_string()));
+
+        assert!(scramblers.knows_format(2));
+        assert!(!scramblers.knows_format(3));
+        assert_eq!(
+            scramblers.scramble(2, b"B Y\n", &mut Rng::new(1)),
+            Ok("A X\n".to_string())
+        );
+        assert_eq!(
+            scramblers.scramble(3, b"abc\n", &mut Rng::new(1)),
+            Ok("abc\n".to_string())
+        );
+        assert_eq!(
+            scramblers.scramble(4, b"hello\n", &mut Rng::new(1)),
+            Err("Line 1: Invalid pair".to_string())
+        );
+    }
+
+    #[test]
+    fn in_range_stays_in_range() {
+        let mut rng = Rng::new(3);
+
+        assert!((0..100).all(|_| (-2..=2).contains(&rng.in_range(-2..=2))));
+    }
+}
@@ -0,0 +1,88 @@
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use crate::SolutionError;
+
+/// Asks the parts running with it to stop, e.g. on Ctrl-C or when they exceed their time budget.
+/// The long loops of the solvers call `checkpoint` to notice it.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+    parent: Option<Arc<CancellationToken>>,
+}
+
+impl CancellationToken {
+    /// A token cancelled with this one, but which can also be cancelled alone.
+    pub fn child(&self) -> Self {
+        Self {
+            cancelled: Arc::default(),
+            parent: Some(Arc::new(self.clone())),
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+            || self
+                .parent
+                .as_ref()
+                .is_some_and(|parent| parent.is_cancelled())
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancellationToken>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` on this thread with the token that its `checkpoint` calls check.
+pub fn with_token<R>(token: &CancellationToken, f: impl FnOnce() -> R) -> R {
+    let previous = CURRENT.replace(Some(token.clone()));
+    let result = f();
+    CURRENT.set(previous);
+    result
+}
+
+/// Fails with `SolutionError::Cancelled` when the part running on this thread was cancelled.
+pub fn checkpoint() -> Result<(), SolutionError> {
+    let cancelled =
+        CURRENT.with_borrow(|token| token.as_ref().is_some_and(|token| token.is_cancelled()));
+    if cancelled {
+        Err(SolutionError::Cancelled)
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn checkpoints_fail_once_the_token_is_cancelled() {
+        let token = CancellationToken::default();
+
+        with_token(&token, || {
+            assert_eq!(checkpoint(), Ok(()));
+            token.cancel();
+            assert_eq!(checkpoint(), Err(SolutionError::Cancelled));
+        });
+        assert_eq!(checkpoint(), Ok(()));
+    }
+
+    #[test]
+    fn children_are_cancelled_with_their_parent() {
+        let parent = CancellationToken::default();
+        let child = parent.child();
+
+        child.cancel();
+        assert!(!parent.is_cancelled());
+
+        let other_child = parent.child();
+        parent.cancel();
+        assert!(other_child.is_cancelled());
+    }
+}
@@ -0,0 +1,265 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+/// Partition of the elements `0..len` into disjoint sets, merged by `union`, e.g. to group the
+/// cubes of a droplet or the pockets of air around it.
+///
+/// Each set is a tree whose root represents it: `find` compresses the path to the root and `union`
+/// hangs the smaller tree under the larger one, which keeps the trees flat.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DisjointSet {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+    sets: usize,
+}
+
+impl DisjointSet {
+    /// Puts each of the elements `0..len` in a set of its own.
+    pub fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+            sets: len,
+        }
+    }
+
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Number of disjoint sets.
+    pub fn sets(&self) -> usize {
+        self.sets
+    }
+
+    /// The element representing the set of `element`.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        let mut current = element;
+        while current != root {
+            current = std::mem::replace(&mut self.parents[current], root);
+        }
+        root
+    }
+
+    /// Merges the sets of `a` and `b`, returning whether they were disjoint.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        let (larger, smaller) = if self.sizes[a] < self.sizes[b] {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        self.parents[smaller] = larger;
+        self.sizes[larger] += self.sizes[smaller];
+        self.sets -= 1;
+        true
+    }
+
+    pub fn same_set(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Number of elements in the set of `element`.
+    pub fn size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.sizes[root]
+    }
+}
+
+/// Priority queue popping the key with the lowest priority first, whose priorities can be lowered
+/// once pushed, e.g. the positions to expand next in a search by their distance.
+///
+/// Lowering a priority pushes the key again rather than moving it in the heap: the entries left
+/// behind no longer match the priority of their key and are skipped when popped.
+#[derive(Debug, Clone)]
+pub struct MinHeap<K, P> {
+    heap: BinaryHeap<Reverse<Entry<K, P>>>,
+    priorities: HashMap<K, P>,
+}
+
+#[derive(Debug, Clone)]
+struct Entry<K, P> {
+    priority: P,
+    key: K,
+}
+
+impl<K, P: Ord> Ord for Entry<K, P> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority.cmp(&other.priority)
+    }
+}
+
+impl<K, P: Ord> PartialOrd for Entry<K, P> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K, P: Ord> PartialEq for Entry<K, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<K, P: Ord> Eq for Entry<K, P> {}
+
+impl<K, P> Default for MinHeap<K, P> {
+    fn default() -> Self {
+        Self {
+            heap: BinaryHeap::new(),
+            priorities: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Eq + Hash + Clone, P: Ord + Clone> MinHeap<K, P> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of keys in the queue.
+    pub fn len(&self) -> usize {
+        self.priorities.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.priorities.is_empty()
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.priorities.contains_key(key)
+    }
+
+    pub fn priority(&self, key: &K) -> Option<&P> {
+        self.priorities.get(key)
+    }
+
+    /// Adds `key` with `priority`, or lowers its priority when it is in the queue with a higher
+    /// one, returning whether the queue changed.
+    pub fn push(&mut self, key: K, priority: P) -> bool {
+        if self
+            .priorities
+            .get(&key)
+            .is_some_and(|current| *current <= priority)
+        {
+            return false;
+        }
+        self.priorities.insert(key.clone(), priority.clone());
+        self.heap.push(Reverse(Entry { priority, key }));
+        true
+    }
+
+    /// Removes the key with the lowest priority, any of them on ties.
+    pub fn pop(&mut self) -> Option<(K, P)> {
+        while let Some(Reverse(Entry { priority, key })) = self.heap.pop() {
+            if self.priorities.get(&key) == Some(&priority) {
+                self.priorities.remove(&key);
+                return Some((key, priority));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn elements_start_alone() {
+        let mut set = DisjointSet::new(3);
+
+        assert_eq!(set.sets(), 3);
+        assert_eq!(set.find(1), 1);
+        assert_eq!(set.size(2), 1);
+        assert!(!set.same_set(0, 2));
+    }
+
+    #[test]
+    fn unions_merge_the_sets() {
+        let mut set = DisjointSet::new(6);
+
+        assert!(set.union(0, 1));
+        assert!(set.union(2, 3));
+        assert!(set.union(1, 3));
+        assert!(!set.union(0, 2));
+
+        assert!(set.same_set(0, 3));
+        assert!(!set.same_set(0, 4));
+        assert_eq!(set.size(2), 4);
+        assert_eq!(set.size(5), 1);
+        assert_eq!(set.sets(), 3);
+        assert_eq!(set.len(), 6);
+    }
+
+    #[test]
+    fn find_flattens_long_chains() {
+        let mut set = DisjointSet::new(100);
+        for element in 1..100 {
+            set.parents[element] = element - 1;
+        }
+
+        let root = set.find(99);
+
+        assert_eq!(root, 0);
+        assert!(set.parents.iter().all(|&parent| parent == 0));
+    }
+
+    #[test]
+    fn min_heaps_pop_the_lowest_priorities_first() {
+        let mut heap = MinHeap::new();
+        heap.push('a', 5);
+        heap.push('b', 2);
+        heap.push('c', 9);
+
+        assert_eq!(heap.len(), 3);
+        assert_eq!(heap.pop(), Some(('b', 2)));
+        assert_eq!(heap.pop(), Some(('a', 5)));
+        assert_eq!(heap.pop(), Some(('c', 9)));
+        assert_eq!(heap.pop(), None);
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn min_heaps_only_lower_priorities() {
+        let mut heap = MinHeap::new();
+        heap.push('a', 5);
+        heap.push('b', 3);
+
+        assert!(heap.push('a', 1));
+        assert!(!heap.push('b', 4));
+        assert!(!heap.push('b', 3));
+
+        assert_eq!(heap.len(), 2);
+        assert_eq!(heap.priority(&'b'), Some(&3));
+        assert_eq!(heap.pop(), Some(('a', 1)));
+        assert_eq!(heap.pop(), Some(('b', 3)));
+        assert_eq!(heap.pop(), None);
+    }
+
+    #[test]
+    fn popped_keys_can_come_back() {
+        let mut heap = MinHeap::new();
+        heap.push("start", 4);
+        heap.push("start", 1);
+
+        assert_eq!(heap.pop(), Some(("start", 1)));
+        assert!(!heap.contains(&"start"));
+        assert!(heap.push("start", 4));
+        assert_eq!(heap.pop(), Some(("start", 4)));
+        assert_eq!(heap.pop(), None);
+    }
+}
@@ -0,0 +1,102 @@
+/// The subsets of `k` elements of `0..n`, each sorted, in lexicographic order.
+pub fn k_subsets(n: usize, k: usize) -> KSubsets {
+    KSubsets {
+        n,
+        next: (k <= n).then(|| (0..k).collect()),
+    }
+}
+
+/// Iterator of `k_subsets`.
+#[derive(Debug, Clone)]
+pub struct KSubsets {
+    n: usize,
+    next: Option<Vec<usize>>,
+}
+
+impl Iterator for KSubsets {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let subset = self.next.take()?;
+        let k = subset.len();
+        // The last element that can still move right, the ones after it following it closely.
+        if let Some(index) = (0..k)
+            .rev()
+            .find(|&index| subset[index] < self.n - k + index)
+        {
+            let mut next = subset.clone();
+            next[index] += 1;
+            for following in index + 1..k {
+                next[following] = next[following - 1] + 1;
+            }
+            self.next = Some(next);
+        }
+        Some(subset)
+    }
+}
+
+/// The subsets of the bits of `mask` as masks, from `mask` itself down to 0, e.g. the valves
+/// that one worker opens among those worth opening.
+pub fn submasks(mask: u64) -> impl Iterator<Item = u64> {
+    let mut next = Some(mask);
+    std::iter::from_fn(move || {
+        let submask = next?;
+        next = submask.checked_sub(1).map(|previous| previous & mask);
+        Some(submask)
+    })
+}
+
+/// The ways to split the bits of `mask` between two workers when they are interchangeable, each
+/// split once: the first part holds the lowest bit of `mask` and the second one may be empty.
+pub fn pair_partitions(mask: u64) -> impl Iterator<Item = (u64, u64)> {
+    let lowest = mask & mask.wrapping_neg();
+    submasks(mask & !lowest).map(move |others| (others | lowest, mask & !(others | lowest)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn k_subsets_in_order() {
+        let subsets = k_subsets(4, 2).collect::<Vec<_>>();
+
+        assert_eq!(
+            subsets,
+            [[0, 1], [0, 2], [0, 3], [1, 2], [1, 3], [2, 3]].map(Vec::from)
+        );
+    }
+
+    #[test]
+    fn k_subsets_count_binomial_coefficients() {
+        assert_eq!(k_subsets(10, 4).count(), 210);
+        assert_eq!(k_subsets(5, 0).collect::<Vec<_>>(), [Vec::<usize>::new()]);
+        assert_eq!(k_subsets(5, 5).count(), 1);
+        assert_eq!(k_subsets(3, 4).count(), 0);
+    }
+
+    #[test]
+    fn submasks_of_a_mask() {
+        assert_eq!(
+            submasks(0b1010).collect::<Vec<_>>(),
+            [0b1010, 0b1000, 0b0010, 0]
+        );
+        assert_eq!(submasks(0).collect::<Vec<_>>(), [0]);
+        assert_eq!(submasks(0b1011_0110).count(), 32);
+    }
+
+    #[test]
+    fn pair_partitions_split_each_way_once() {
+        let mask = 0b1101_0100;
+
+        let partitions = pair_partitions(mask).collect::<Vec<_>>();
+
+        assert_eq!(partitions.len(), 8);
+        for &(first, second) in &partitions {
+            assert_eq!(first | second, mask);
+            assert_eq!(first & second, 0);
+            assert!(!partitions.contains(&(second, first)));
+        }
+        assert_eq!(pair_partitions(0).collect::<Vec<_>>(), [(0, 0)]);
+    }
+}
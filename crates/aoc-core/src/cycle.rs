@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Steps from `initial` until a state comes back, returning the step where the states start
+/// repeating and the number of steps between two repetitions: the state after `offset + period`
+/// steps is the one after `offset` steps. The states must be finitely many for this to return.
+pub fn find_cycle<S, F>(initial: S, step: F) -> (usize, usize)
+where
+    S: Eq + Hash + Clone,
+    F: FnMut(&S) -> S,
+{
+    find_cycle_by(initial, step, S::clone)
+}
+
+/// Like `find_cycle`, comparing the states by `key`, e.g. the shape of the top of a tower rather
+/// than its growing height.
+pub fn find_cycle_by<S, K, F, G>(initial: S, mut step: F, key: G) -> (usize, usize)
+where
+    K: Eq + Hash,
+    F: FnMut(&S) -> S,
+    G: Fn(&S) -> K,
+{
+    let mut seen = HashMap::new();
+    let mut state = initial;
+    for index in 0.. {
+        if let Some(offset) = seen.insert(key(&state), index) {
+            return (offset, index - offset);
+        }
+        state = step(&state);
+    }
+    unreachable!("The steps outnumber the integers")
+}
+
+/// The first step with the same state as step `n` in a sequence repeating from `offset` every
+/// `period` steps, to get the state after a number of steps too large to simulate.
+pub fn equivalent_step(offset: usize, period: usize, n: usize) -> usize {
+    if n < offset {
+        n
+    } else {
+        offset + (n - offset) % period
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn nth<S>(initial: S, step: impl Fn(&S) -> S, n: usize) -> S {
+        (0..n).fold(initial, |state, _| step(&state))
+    }
+
+    #[test]
+    fn sequences_repeating_from_the_start() {
+        assert_eq!(find_cycle(0, |n| (n + 1) % 7), (0, 7));
+        assert_eq!(find_cycle('a', |_| 'a'), (0, 1));
+    }
+
+    #[test]
+    fn sequences_with_a_tail() {
+        // 3, 10, 5, 16, 8, 4, 2, 1, 4, 2, 1…
+        let collatz = |&n: &u32| if n % 2 == 0 { n / 2 } else { 3 * n + 1 };
+
+        assert_eq!(find_cycle(3, collatz), (5, 3));
+    }
+
+    #[test]
+    fn equivalent_steps_reach_the_same_state() {
+        let step = |&n: &u64| (n * n + 1) % 1009;
+        let (offset, period) = find_cycle(2, step);
+
+        for n in [0, offset, offset + 1, 3 * period + offset + 2, 5000] {
+            let equivalent = equivalent_step(offset, period, n);
+
+            assert!(equivalent < offset + period);
+            assert_eq!(nth(2, step, equivalent), nth(2, step, n), "step {n}");
+        }
+    }
+
+    #[test]
+    fn states_compared_by_key() {
+        // The height grows forever but the shape of the top repeats every 3 steps.
+        let step = |&(height, shape): &(u64, u8)| (height + u64::from(shape), (shape + 1) % 3);
+
+        assert_eq!(find_cycle_by((0, 0), step, |&(_, shape)| shape), (0, 3));
+    }
+}
@@ -0,0 +1,196 @@
+use crate::input::{Input, InputSource, ParseError};
+use crate::{Answer, Example, IntoAnswer, Part, Solution, SolutionError};
+
+type SolvePart<T> = fn(&T) -> Result<Answer, SolutionError>;
+
+/// Solution made of the parse and solve functions of a day, as generated by the `day!` macro of
+/// the days.
+pub struct Day<T> {
+    day: u8,
+    title: &'static str,
+    input: Input<T>,
+    parse: fn(&[u8]) -> T,
+    parts: [SolvePart<T>; 2],
+    examples: fn() -> Vec<Example>,
+    expected: [Option<Answer>; 2],
+    labels: Option<[&'static str; 2]>,
+    validate: fn(&[u8]) -> Result<(), String>,
+}
+
+impl<T> Day<T> {
+    pub fn new(
+        day: u8,
+        title: &'static str,
+        parse: fn(&[u8]) -> T,
+        part_one: SolvePart<T>,
+        part_two: SolvePart<T>,
+    ) -> Self {
+        Self {
+            day,
+            title,
+            input: Input::for_day(day, parse),
+            parse,
+            parts: [part_one, part_two],
+            examples: Vec::new,
+            expected: [None, None],
+            labels: None,
+            validate: |_| Ok(()),
+        }
+    }
+
+    /// Sets the samples checked by `Solution::examples`.
+    pub fn with_examples(self, examples: fn() -> Vec<Example>) -> Self {
+        Self { examples, ..self }
+    }
+
+    /// Sets the answers checked on the input of the day, e.g. `with_expected(477_u32, 830_u32)`.
+    pub fn with_expected(self, part_one: impl Into<Answer>, part_two: impl Into<Answer>) -> Self {
+        Self {
+            expected: [Some(part_one.into()), Some(part_two.into())],
+            ..self
+        }
+    }
+
+    /// Sets what the answers of the parts are, see `Solution::label`.
+    pub fn with_labels(self, labels: [&'static str; 2]) -> Self {
+        Self {
+            labels: Some(labels),
+            ..self
+        }
+    }
+
+    /// Validates the raw input further than reading and parsing it, e.g. with
+    /// `input::validate_lines` for the days whose parsing skips the malformed lines.
+    pub fn with_validation(self, validate: fn(&[u8]) -> Result<(), String>) -> Self {
+        Self { validate, ..self }
+    }
+}
+
+/// Answers a part with the value returned by a solve function, or with its error.
+pub fn answer<A: IntoAnswer>(answer: A) -> Result<Answer, SolutionError> {
+    answer.into_answer()
+}
+
+impl<T: Send + Sync + 'static> Solution for Day<T> {
+    fn day(&self) -> u8 {
+        self.day
+    }
+
+    fn title(&self) -> &'static str {
+        self.title
+    }
+
+    fn with_input(&self, source: InputSource) -> Box<dyn Solution> {
+        Box::new(Self {
+            input: Input::new(source, self.parse),
+            expected: self.expected.clone(),
+            ..*self
+        })
+    }
+
+    fn parse(&mut self) {
+        self.input.parse();
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        (self.examples)()
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        self.input.validate()?;
+        (self.validate)(self.input.raw())
+    }
+
+    fn skipped_lines(&self) -> Vec<ParseError> {
+        self.input.skipped().to_vec()
+    }
+
+    fn part_one(&self) -> Result<Answer, SolutionError> {
+        (self.parts[0])(self.input.try_get()?)
+    }
+
+    fn part_two(&self) -> Result<Answer, SolutionError> {
+        (self.parts[1])(self.input.try_get()?)
+    }
+
+    fn expected_part_one(&self) -> Option<Answer> {
+        self.expected[0].clone()
+    }
+
+    fn expected_part_two(&self) -> Option<Answer> {
+        self.expected[1].clone()
+    }
+
+    fn label(&self, part: Part) -> String {
+        match (self.labels, part) {
+            (Some([label, _]), Part::One) | (Some([_, label]), Part::Two) => label.to_string(),
+            (None, _) => format!("Part {part}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse_input(input: &[u8]) -> Vec<u32> {
+        String::from_utf8_lossy(input)
+            .split_whitespace()
+            .filter_map(|number| number.parse().ok())
+            .collect()
+    }
+
+    fn day() -> Day<Vec<u32>> {
+        Day::new(
+            16,
+            "Sums",
+            parse_input,
+            |numbers| answer(numbers.iter().sum::<u32>()),
+            |numbers| {
+                answer(
+                    numbers
+                        .iter()
+                        .max()
+                        .copied()
+                        .ok_or_else(|| SolutionError::Unexpected("No numbers".to_string())),
+                )
+            },
+        )
+    }
+
+    #[test]
+    fn parts_solve_the_parsed_input() {
+        let day = day().with_input(InputSource::from(b"1 5\n3\n".to_vec()));
+
+        assert_eq!(day.title(), "Sums");
+        assert_eq!(day.part_one(), Ok(Answer::Unsigned(9)));
+        assert_eq!(day.part_two(), Ok(Answer::Unsigned(5)));
+    }
+
+    #[test]
+    fn errors_of_the_solve_functions_are_reported() {
+        let day = day().with_input(InputSource::from(b"\n".to_vec()));
+
+        assert_eq!(
+            day.part_two(),
+            Err(SolutionError::Unexpected("No numbers".to_string()))
+        );
+    }
+
+    #[test]
+    fn expected_answers_labels_and_validation_are_kept_on_other_inputs() {
+        let day = day()
+            .with_expected(9_u32, 5_u32)
+            .with_labels(["Sum", "Maximum"])
+            .with_validation(crate::input::validate_lines::<u32>)
+            .with_input(InputSource::from(b"1 5\n3\n".to_vec()));
+
+        assert_eq!(day.expected(Part::One), Some(Answer::Unsigned(9)));
+        assert_eq!(day.expected(Part::Two), Some(Answer::Unsigned(5)));
+        assert_eq!(day.label(Part::Two), "Maximum");
+        assert_eq!(
+            day.validate(),
+            Err("Line 1: invalid digit found in string".to_string())
+        );
+    }
+}
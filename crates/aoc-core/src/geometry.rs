@@ -0,0 +1,1037 @@
+use std::collections::hash_map::{self, HashMap};
+use std::collections::hash_set::{self, HashSet};
+use std::fmt::{Display, Formatter};
+use std::hash::Hash;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, RangeInclusive, Sub, SubAssign};
+
+/// A position in a grid: `row` 0 is the top row and rows grow downwards, `column` 0 is the left
+/// column and columns grow rightwards.
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default, Hash, Ord, PartialOrd)]
+pub struct GridPos {
+    pub row: usize,
+    pub column: usize,
+}
+
+impl GridPos {
+    pub const fn new(row: usize, column: usize) -> Self {
+        Self { row, column }
+    }
+
+    pub fn up(&self) -> Option<Self> {
+        self.row
+            .checked_sub(1)
+            .map(|row| Self::new(row, self.column))
+    }
+
+    pub fn down(&self) -> Self {
+        Self::new(self.row + 1, self.column)
+    }
+
+    pub fn left(&self) -> Option<Self> {
+        self.column
+            .checked_sub(1)
+            .map(|column| Self::new(self.row, column))
+    }
+
+    pub fn right(&self) -> Self {
+        Self::new(self.row, self.column + 1)
+    }
+
+    /// The position reached by moving by `offset`, whose `y` grows upwards as for any `Vec2`, or
+    /// `None` when it falls before the first row or column.
+    pub fn offset(&self, offset: Vec2) -> Option<Self> {
+        Some(Self::new(
+            self.row.checked_add_signed(-offset.y)?,
+            self.column.checked_add_signed(offset.x)?,
+        ))
+    }
+
+    pub fn neighbors(&self, rows: usize, columns: usize) -> impl Iterator<Item = GridPos> {
+        let position = *self;
+        Vec2::ORTHOGONAL
+            .into_iter()
+            .filter_map(move |offset| position.offset(offset))
+            .filter(move |position| position.row < rows && position.column < columns)
+    }
+
+    /// Converts to a vector from the origin, with the bottom row of a grid of `rows` rows on `y = 0`.
+    pub fn to_vec2(self, rows: usize) -> Option<Vec2> {
+        let y = rows.checked_sub(self.row + 1)?;
+        Some(Vec2::new(self.column.try_into().ok()?, y.try_into().ok()?))
+    }
+}
+
+/// A vector of the plane, or the position it leads to from the origin: `x` grows rightwards and
+/// `y` grows upwards.
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default, Hash, Ord, PartialOrd)]
+pub struct Vec2 {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Vec2 {
+    pub const UP: Self = Self::new(0, 1);
+    pub const DOWN: Self = Self::new(0, -1);
+    pub const LEFT: Self = Self::new(-1, 0);
+    pub const RIGHT: Self = Self::new(1, 0);
+
+    /// Offsets of the four neighbors sharing a side.
+    pub const ORTHOGONAL: [Self; 4] = [Self::UP, Self::DOWN, Self::LEFT, Self::RIGHT];
+
+    /// Offsets of the eight neighbors sharing a side or a corner, clockwise from the upper left.
+    pub const ADJACENT: [Self; 8] = [
+        Self::new(-1, 1),
+        Self::UP,
+        Self::new(1, 1),
+        Self::RIGHT,
+        Self::new(1, -1),
+        Self::DOWN,
+        Self::new(-1, -1),
+        Self::LEFT,
+    ];
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn up(&self) -> Self {
+        *self + Self::UP
+    }
+
+    pub fn down(&self) -> Self {
+        *self + Self::DOWN
+    }
+
+    pub fn left(&self) -> Self {
+        *self + Self::LEFT
+    }
+
+    pub fn right(&self) -> Self {
+        *self + Self::RIGHT
+    }
+
+    pub fn neighbors(&self) -> impl Iterator<Item = Vec2> {
+        let position = *self;
+        Self::ORTHOGONAL
+            .into_iter()
+            .map(move |offset| position + offset)
+    }
+
+    /// Whether the positions touch, sharing a side or a corner, or are the same.
+    pub fn is_adjacent(&self, other: &Vec2) -> bool {
+        let Vec2 { x, y } = *self - *other;
+        x.abs() <= 1 && y.abs() <= 1
+    }
+
+    pub fn manhattan(&self, other: &Vec2) -> isize {
+        let Vec2 { x, y } = *self - *other;
+        x.abs() + y.abs()
+    }
+
+    /// The vector of the signs of the components, i.e. one step towards its direction, diagonally
+    /// if needed.
+    pub fn signum(&self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Converts to a position in a grid of `rows` rows whose bottom row is on `y = 0`.
+    pub fn to_grid(self, rows: usize) -> Option<GridPos> {
+        let column = self.x.try_into().ok()?;
+        let y: usize = self.y.try_into().ok()?;
+        let row = rows.checked_sub(y + 1)?;
+        Some(GridPos::new(row, column))
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Vec2 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<isize> for Vec2 {
+    type Output = Self;
+
+    fn mul(self, factor: isize) -> Self {
+        Self::new(self.x * factor, self.y * factor)
+    }
+}
+
+/// Positions with a column and a row, whichever way their axes go.
+pub trait Planar: Copy + Eq + Hash {
+    /// Whether the rows grow down the screen, as those of a grid, rather than up as `y`.
+    const ROWS_GROW_DOWN: bool;
+
+    /// The column and the row of the position, e.g. its `x` and `y`.
+    fn axes(&self) -> (isize, isize);
+
+    /// The position at a column and a row, `None` when there is none there, e.g. in a negative
+    /// row of a grid.
+    fn from_axes(column: isize, row: isize) -> Option<Self>;
+}
+
+impl Planar for GridPos {
+    const ROWS_GROW_DOWN: bool = true;
+
+    fn axes(&self) -> (isize, isize) {
+        (self.column as isize, self.row as isize)
+    }
+
+    fn from_axes(column: isize, row: isize) -> Option<Self> {
+        Some(Self::new(row.try_into().ok()?, column.try_into().ok()?))
+    }
+}
+
+impl Planar for Vec2 {
+    const ROWS_GROW_DOWN: bool = false;
+
+    fn axes(&self) -> (isize, isize) {
+        (self.x, self.y)
+    }
+
+    fn from_axes(column: isize, row: isize) -> Option<Self> {
+        Some(Self::new(column, row))
+    }
+}
+
+/// Smallest rectangle holding some positions, both corners included.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Bounds {
+    /// The first column and the first row.
+    pub min: (isize, isize),
+    /// The last column and the last row.
+    pub max: (isize, isize),
+}
+
+impl Bounds {
+    /// Bounds of the positions, `None` when there are none.
+    pub fn of<P: Planar>(positions: impl IntoIterator<Item = P>) -> Option<Self> {
+        positions.into_iter().map(|position| position.axes()).fold(
+            None,
+            |bounds: Option<Self>, (column, row)| {
+                Some(match bounds {
+                    Some(Self { min, max }) => Self {
+                        min: (min.0.min(column), min.1.min(row)),
+                        max: (max.0.max(column), max.1.max(row)),
+                    },
+                    None => Self {
+                        min: (column, row),
+                        max: (column, row),
+                    },
+                })
+            },
+        )
+    }
+
+    pub fn columns(&self) -> RangeInclusive<isize> {
+        self.min.0..=self.max.0
+    }
+
+    pub fn rows(&self) -> RangeInclusive<isize> {
+        self.min.1..=self.max.1
+    }
+
+    pub fn width(&self) -> usize {
+        self.max.0.abs_diff(self.min.0) + 1
+    }
+
+    pub fn height(&self) -> usize {
+        self.max.1.abs_diff(self.min.1) + 1
+    }
+
+    /// Number of positions in the rectangle.
+    pub fn area(&self) -> usize {
+        self.width() * self.height()
+    }
+}
+
+/// Occupied positions of a grid without bounds, e.g. the rocks of day 14 or the elves of day 23.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PositionSet<P: Planar>(HashSet<P>);
+
+impl<P: Planar> PositionSet<P> {
+    pub fn new() -> Self {
+        Self(HashSet::new())
+    }
+
+    pub fn contains(&self, position: &P) -> bool {
+        self.0.contains(position)
+    }
+
+    /// Adds a position, returning whether it was free.
+    pub fn insert(&mut self, position: P) -> bool {
+        self.0.insert(position)
+    }
+
+    /// Removes a position, returning whether it was occupied.
+    pub fn remove(&mut self, position: &P) -> bool {
+        self.0.remove(position)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> hash_set::Iter<'_, P> {
+        self.0.iter()
+    }
+
+    /// Smallest rectangle holding the positions, `None` when there are none.
+    pub fn bounds(&self) -> Option<Bounds> {
+        Bounds::of(self.0.iter().copied())
+    }
+}
+
+impl<P: Planar> Default for PositionSet<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: Planar> FromIterator<P> for PositionSet<P> {
+    fn from_iter<T: IntoIterator<Item = P>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<P: Planar> Extend<P> for PositionSet<P> {
+    fn extend<T: IntoIterator<Item = P>>(&mut self, iter: T) {
+        self.0.extend(iter);
+    }
+}
+
+impl<'a, P: Planar> IntoIterator for &'a PositionSet<P> {
+    type Item = &'a P;
+    type IntoIter = hash_set::Iter<'a, P>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Values at some positions of a grid without bounds, e.g. the rocks and the sand of the cave of
+/// day 14.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SparseGrid<P: Planar, T>(HashMap<P, T>);
+
+impl<P: Planar, T> SparseGrid<P, T> {
+    pub fn new() -> Self {
+        Self(HashMap::new())
+    }
+
+    pub fn get(&self, position: &P) -> Option<&T> {
+        self.0.get(position)
+    }
+
+    pub fn get_mut(&mut self, position: &P) -> Option<&mut T> {
+        self.0.get_mut(position)
+    }
+
+    pub fn contains(&self, position: &P) -> bool {
+        self.0.contains_key(position)
+    }
+
+    /// Puts a value at a position, returning the one it replaces.
+    pub fn insert(&mut self, position: P, value: T) -> Option<T> {
+        self.0.insert(position, value)
+    }
+
+    pub fn remove(&mut self, position: &P) -> Option<T> {
+        self.0.remove(position)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> hash_map::Iter<'_, P, T> {
+        self.0.iter()
+    }
+
+    /// Smallest rectangle holding the positions with a value, `None` when there are none.
+    pub fn bounds(&self) -> Option<Bounds> {
+        Bounds::of(self.0.keys().copied())
+    }
+
+    /// Picture of the rectangle `bounds`, a line per row from the top of the screen, where `cell`
+    /// draws each position from its value, if any.
+    pub fn render<D: Display>(&self, bounds: Bounds, cell: impl Fn(P, Option<&T>) -> D) -> String {
+        let mut rows = bounds.rows().collect::<Vec<_>>();
+        if !P::ROWS_GROW_DOWN {
+            rows.reverse();
+        }
+        let mut picture = String::with_capacity((bounds.width() + 1) * bounds.height());
+        for row in rows {
+            for column in bounds.columns() {
+                match P::from_axes(column, row) {
+                    Some(position) => picture += &cell(position, self.get(&position)).to_string(),
+                    None => picture.push(' '),
+                }
+            }
+            picture.push('\n');
+        }
+        picture
+    }
+}
+
+impl<P: Planar, T> Default for SparseGrid<P, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: Planar, T> FromIterator<(P, T)> for SparseGrid<P, T> {
+    fn from_iter<I: IntoIterator<Item = (P, T)>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<P: Planar, T> Extend<(P, T)> for SparseGrid<P, T> {
+    fn extend<I: IntoIterator<Item = (P, T)>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl<'a, P: Planar, T> IntoIterator for &'a SparseGrid<P, T> {
+    type Item = (&'a P, &'a T);
+    type IntoIter = hash_map::Iter<'a, P, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Values at every position of a rectangle, stored row by row, which turns and mirrors into new
+/// grids, e.g. to scan each of its sides as the top row.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
+    rows: usize,
+    columns: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid of the given rows, from the top one, which must all have the same length.
+    pub fn from_rows<R: IntoIterator<Item = T>>(
+        rows: impl IntoIterator<Item = R>,
+    ) -> Result<Self, String> {
+        let mut grid = Self {
+            rows: 0,
+            columns: 0,
+            cells: Vec::new(),
+        };
+        for row in rows {
+            grid.cells.extend(row);
+            if grid.rows == 0 {
+                grid.columns = grid.cells.len();
+            }
+            grid.rows += 1;
+            if grid.cells.len() != grid.rows * grid.columns {
+                return Err(format!(
+                    "Row {} does not have {} columns like the first one",
+                    grid.rows, grid.columns
+                ));
+            }
+        }
+        Ok(grid)
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn get(&self, position: &GridPos) -> Option<&T> {
+        self.index(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: &GridPos) -> Option<&mut T> {
+        self.index(position).map(|index| &mut self.cells[index])
+    }
+
+    /// The values of a row, from the left.
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.columns..(row + 1) * self.columns]
+    }
+
+    /// The values with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (GridPos, &T)> {
+        self.cells.iter().enumerate().map(|(index, value)| {
+            (
+                GridPos::new(index / self.columns, index % self.columns),
+                value,
+            )
+        })
+    }
+
+    fn index(&self, position: &GridPos) -> Option<usize> {
+        (position.row < self.rows && position.column < self.columns)
+            .then_some(position.row * self.columns + position.column)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Grid with `rows` and `columns` taking at each position the value at `source(position)`.
+    fn rearranged(&self, rows: usize, columns: usize, source: impl Fn(GridPos) -> GridPos) -> Self {
+        let cells = (0..rows)
+            .flat_map(|row| (0..columns).map(move |column| GridPos::new(row, column)))
+            .map(|position| self[source(position)].clone())
+            .collect();
+        Self {
+            rows,
+            columns,
+            cells,
+        }
+    }
+
+    /// Rows become columns: the value at row `r` and column `c` moves to row `c` and column `r`.
+    pub fn transpose(&self) -> Self {
+        self.rearranged(self.columns, self.rows, |position| {
+            GridPos::new(position.column, position.row)
+        })
+    }
+
+    /// Quarter turn clockwise: the left column becomes the top row.
+    pub fn rotate_clockwise(&self) -> Self {
+        self.rearranged(self.columns, self.rows, |position| {
+            GridPos::new(self.rows - 1 - position.column, position.row)
+        })
+    }
+
+    /// Quarter turn counterclockwise: the right column becomes the top row.
+    pub fn rotate_counterclockwise(&self) -> Self {
+        self.rearranged(self.columns, self.rows, |position| {
+            GridPos::new(position.column, self.columns - 1 - position.row)
+        })
+    }
+
+    /// Reverses each row, swapping the left and the right.
+    pub fn mirror_horizontally(&self) -> Self {
+        self.rearranged(self.rows, self.columns, |position| {
+            GridPos::new(position.row, self.columns - 1 - position.column)
+        })
+    }
+
+    /// Reverses the order of the rows, swapping the top and the bottom.
+    pub fn mirror_vertically(&self) -> Self {
+        self.rearranged(self.rows, self.columns, |position| {
+            GridPos::new(self.rows - 1 - position.row, position.column)
+        })
+    }
+}
+
+impl<T> Index<GridPos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: GridPos) -> &T {
+        self.get(&position)
+            .unwrap_or_else(|| panic!("{position:?} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<GridPos> for Grid<T> {
+    fn index_mut(&mut self, position: GridPos) -> &mut T {
+        self.get_mut(&position)
+            .unwrap_or_else(|| panic!("{position:?} is outside of the grid"))
+    }
+}
+
+/// A line per row, each value drawn next to the previous one.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in 0..self.rows {
+            if row > 0 {
+                writeln!(f)?;
+            }
+            for value in self.row(row) {
+                write!(f, "{value}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A vector of space, or the position it leads to from the origin, e.g. a cube of the lava
+/// droplet of day 18 or a direction of the cube folded on day 22.
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default, Hash, Ord, PartialOrd)]
+pub struct Vec3 {
+    pub x: isize,
+    pub y: isize,
+    pub z: isize,
+}
+
+/// An axis of space, around which `Vec3::rotate` turns.
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Hash)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+impl Vec3 {
+    pub const X: Self = Self::new(1, 0, 0);
+    pub const Y: Self = Self::new(0, 1, 0);
+    pub const Z: Self = Self::new(0, 0, 1);
+
+    /// Offsets of the six neighbors sharing a face.
+    pub const ORTHOGONAL: [Self; 6] = [
+        Self::X,
+        Self::new(-1, 0, 0),
+        Self::Y,
+        Self::new(0, -1, 0),
+        Self::Z,
+        Self::new(0, 0, -1),
+    ];
+
+    pub const fn new(x: isize, y: isize, z: isize) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn neighbors(&self) -> impl Iterator<Item = Vec3> {
+        let position = *self;
+        Self::ORTHOGONAL
+            .into_iter()
+            .map(move |offset| position + offset)
+    }
+
+    pub fn dot(&self, other: &Vec3) -> isize {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn cross(&self, other: &Vec3) -> Vec3 {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    pub fn manhattan(&self, other: &Vec3) -> isize {
+        let Vec3 { x, y, z } = *self - *other;
+        x.abs() + y.abs() + z.abs()
+    }
+
+    /// Turns the vector by `quarter_turns` quarters of a turn around `axis`, counterclockwise when
+    /// the axis points towards the viewer; negative turns go clockwise.
+    pub fn rotate(&self, axis: Axis, quarter_turns: i32) -> Self {
+        (0..quarter_turns.rem_euclid(4)).fold(*self, |Vec3 { x, y, z }, _| match axis {
+            Axis::X => Self::new(x, -z, y),
+            Axis::Y => Self::new(z, y, -x),
+            Axis::Z => Self::new(-y, x, z),
+        })
+    }
+}
+
+impl Add for Vec3 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl AddAssign for Vec3 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Vec3 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl SubAssign for Vec3 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Vec3 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Mul<isize> for Vec3 {
+    type Output = Self;
+
+    fn mul(self, factor: isize) -> Self {
+        Self::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+}
+
+/// Smallest box holding some positions of space, both corners included.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Bounds3 {
+    pub min: Vec3,
+    pub max: Vec3,
+}
+
+impl Bounds3 {
+    /// Bounds of the positions, `None` when there are none.
+    pub fn of(positions: impl IntoIterator<Item = Vec3>) -> Option<Self> {
+        positions
+            .into_iter()
+            .fold(None, |bounds: Option<Self>, position| {
+                Some(match bounds {
+                    Some(Self { min, max }) => Self {
+                        min: Vec3::new(
+                            min.x.min(position.x),
+                            min.y.min(position.y),
+                            min.z.min(position.z),
+                        ),
+                        max: Vec3::new(
+                            max.x.max(position.x),
+                            max.y.max(position.y),
+                            max.z.max(position.z),
+                        ),
+                    },
+                    None => Self {
+                        min: position,
+                        max: position,
+                    },
+                })
+            })
+    }
+
+    pub fn contains(&self, position: &Vec3) -> bool {
+        (self.min.x..=self.max.x).contains(&position.x)
+            && (self.min.y..=self.max.y).contains(&position.y)
+            && (self.min.z..=self.max.z).contains(&position.z)
+    }
+
+    /// The box grown by `margin` on every side, e.g. to leave room around a droplet to flood.
+    pub fn expand(&self, margin: isize) -> Self {
+        let margin = Vec3::new(margin, margin, margin);
+        Self {
+            min: self.min - margin,
+            max: self.max + margin,
+        }
+    }
+
+    /// Number of positions in the box.
+    pub fn volume(&self) -> usize {
+        let Vec3 { x, y, z } = self.max - self.min;
+        (x as usize + 1) * (y as usize + 1) * (z as usize + 1)
+    }
+}
+
+/// The positions reached from `start`, itself included, by steps to the positions that `steps`
+/// gives for each of them: the 4 `neighbors` of a `Vec2` or a `GridPos` that are open on a map,
+/// or the 6 of a `Vec3` around a lava droplet.
+pub fn flood_fill<P, S, I>(start: P, mut steps: S) -> HashSet<P>
+where
+    P: Eq + Hash + Clone,
+    S: FnMut(&P) -> I,
+    I: IntoIterator<Item = P>,
+{
+    let mut filled = HashSet::from([start.clone()]);
+    let mut to_visit = vec![start];
+    while let Some(position) = to_visit.pop() {
+        for next in steps(&position) {
+            if filled.insert(next.clone()) {
+                to_visit.push(next);
+            }
+        }
+    }
+    filled
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    #[test]
+    fn grid_up_is_vector_up() {
+        let position = GridPos::new(3, 2);
+
+        let result = position.up().unwrap().to_vec2(5);
+
+        assert_eq!(result, position.to_vec2(5).map(|p| p.up()));
+    }
+
+    #[test]
+    fn grid_to_vec2() {
+        assert_eq!(GridPos::new(0, 0).to_vec2(5), Some(Vec2::new(0, 4)));
+        assert_eq!(GridPos::new(4, 3).to_vec2(5), Some(Vec2::new(3, 0)));
+        assert_eq!(GridPos::new(5, 3).to_vec2(5), None);
+    }
+
+    #[test]
+    fn vec2_to_grid() {
+        assert_eq!(Vec2::new(0, 4).to_grid(5), Some(GridPos::new(0, 0)));
+        assert_eq!(Vec2::new(3, 0).to_grid(5), Some(GridPos::new(4, 3)));
+        assert_eq!(Vec2::new(-1, 0).to_grid(5), None);
+        assert_eq!(Vec2::new(0, -1).to_grid(5), None);
+        assert_eq!(Vec2::new(0, 5).to_grid(5), None);
+    }
+
+    #[test]
+    fn vector_arithmetic() {
+        let a = Vec2::new(3, -2);
+        let b = Vec2::new(-1, 4);
+
+        assert_eq!(a + b, Vec2::new(2, 2));
+        assert_eq!(a - b, Vec2::new(4, -6));
+        assert_eq!(-a * 2, Vec2::new(-6, 4));
+        assert_eq!((a - b).signum(), Vec2::new(1, -1));
+        assert_eq!(a.manhattan(&b), 10);
+    }
+
+    #[test]
+    fn grid_offsets_go_the_way_of_vectors() {
+        let position = GridPos::new(3, 2);
+
+        for offset in Vec2::ADJACENT {
+            let result = position.offset(offset).and_then(|p| p.to_vec2(5));
+
+            assert_eq!(result, position.to_vec2(5).map(|p| p + offset));
+        }
+        assert_eq!(GridPos::new(0, 2).offset(Vec2::UP), None);
+    }
+
+    #[test]
+    fn grid_neighbors_stay_within_bounds() {
+        let result = GridPos::new(0, 0).neighbors(2, 2).collect::<Vec<_>>();
+
+        assert_eq!(result, vec![GridPos::new(1, 0), GridPos::new(0, 1)]);
+    }
+
+    #[test]
+    fn bounds_of_a_position_set() {
+        let positions = [Vec2::new(2, -1), Vec2::new(-3, 4), Vec2::new(0, 0)]
+            .into_iter()
+            .collect::<PositionSet<_>>();
+
+        let bounds = positions.bounds().unwrap();
+
+        assert_eq!(bounds.columns(), -3..=2);
+        assert_eq!(bounds.rows(), -1..=4);
+        assert_eq!(bounds.area(), 36);
+        assert_eq!(PositionSet::<GridPos>::new().bounds(), None);
+    }
+
+    #[test]
+    fn sparse_grids_render_their_bounds() {
+        let mut grid = [(GridPos::new(1, 2), '#'), (GridPos::new(3, 4), 'o')]
+            .into_iter()
+            .collect::<SparseGrid<_, _>>();
+        grid.insert(GridPos::new(2, 3), '#');
+
+        let bounds = grid.bounds().unwrap();
+        let picture = grid.render(bounds, |_, cell| cell.copied().unwrap_or('.'));
+
+        assert_eq!(picture, "#..\n.#.\n..o\n");
+        assert_eq!(grid.remove(&GridPos::new(3, 4)), Some('o'));
+        assert_eq!(grid.bounds().map(|bounds| bounds.area()), Some(4));
+    }
+
+    #[test]
+    fn sparse_grids_of_vectors_render_y_upwards() {
+        let grid = [(Vec2::new(0, 0), 'a'), (Vec2::new(1, 1), 'b')]
+            .into_iter()
+            .collect::<SparseGrid<_, _>>();
+        let bounds = Bounds {
+            min: (-1, 0),
+            max: (1, 1),
+        };
+
+        let picture = grid.render(bounds, |position, cell| match cell {
+            Some(cell) => cell.to_string(),
+            None => format!("{}", position.x + 1),
+        });
+
+        assert_eq!(picture, "01b\n0a2\n");
+    }
+
+    #[test]
+    fn quarter_turns_follow_the_right_hand() {
+        assert_eq!(Vec3::X.rotate(Axis::Z, 1), Vec3::Y);
+        assert_eq!(Vec3::Y.rotate(Axis::X, 1), Vec3::Z);
+        assert_eq!(Vec3::Z.rotate(Axis::Y, 1), Vec3::X);
+        assert_eq!(Vec3::X.rotate(Axis::Z, -1), -Vec3::Y);
+    }
+
+    #[test]
+    fn rotations_keep_lengths_and_come_back_after_a_full_turn() {
+        let vector = Vec3::new(1, -2, 3);
+
+        for axis in [Axis::X, Axis::Y, Axis::Z] {
+            assert_eq!(vector.rotate(axis, 4), vector);
+            assert_eq!(vector.rotate(axis, 3), vector.rotate(axis, -1));
+            assert_eq!(vector.rotate(axis, 1).dot(&vector.rotate(axis, 1)), 14);
+        }
+        assert_eq!(Vec3::X.cross(&Vec3::Y), Vec3::Z);
+    }
+
+    #[test]
+    fn bounds_of_positions_in_space() {
+        let cubes = [Vec3::new(2, 2, 2), Vec3::new(1, 2, 5), Vec3::new(3, 0, 2)];
+
+        let bounds = Bounds3::of(cubes).unwrap();
+
+        assert_eq!(bounds.min, Vec3::new(1, 0, 2));
+        assert_eq!(bounds.max, Vec3::new(3, 2, 5));
+        assert_eq!(bounds.volume(), 36);
+        assert!(!bounds.contains(&Vec3::new(0, 0, 2)));
+        assert!(bounds.expand(1).contains(&Vec3::new(0, 0, 2)));
+        assert_eq!(Vec3::new(2, 2, 2).neighbors().count(), 6);
+        assert_eq!(Bounds3::of([]), None);
+    }
+
+    #[test]
+    fn flood_fills_stop_at_walls() {
+        let map = ["..#..", "..#..", "###..", "....."];
+        let is_open = |position: &GridPos| map[position.row].as_bytes()[position.column] == b'.';
+
+        let region = flood_fill(GridPos::new(0, 0), |position| {
+            position.neighbors(4, 5).filter(is_open).collect::<Vec<_>>()
+        });
+        let outside = flood_fill(GridPos::new(3, 0), |position| {
+            position.neighbors(4, 5).filter(is_open).collect::<Vec<_>>()
+        });
+
+        assert_eq!(region.len(), 4);
+        assert_eq!(outside.len(), 11);
+        assert!(region.is_disjoint(&outside));
+    }
+
+    #[test]
+    fn flood_fills_in_space_find_the_outside_of_a_droplet() {
+        let droplet = [
+            (2, 2, 2),
+            (1, 2, 2),
+            (3, 2, 2),
+            (2, 1, 2),
+            (2, 3, 2),
+            (2, 2, 1),
+            (2, 2, 3),
+            (2, 2, 4),
+            (2, 2, 6),
+            (1, 2, 5),
+            (3, 2, 5),
+            (2, 1, 5),
+            (2, 3, 5),
+        ]
+        .map(|(x, y, z)| Vec3::new(x, y, z));
+        let lava = droplet.into_iter().collect::<HashSet<_>>();
+        let bounds = Bounds3::of(droplet).unwrap().expand(1);
+
+        let air = flood_fill(bounds.min, |cube| {
+            cube.neighbors()
+                .filter(|neighbor| bounds.contains(neighbor) && !lava.contains(neighbor))
+                .collect::<Vec<_>>()
+        });
+        let exterior_faces = droplet
+            .iter()
+            .flat_map(Vec3::neighbors)
+            .filter(|neighbor| air.contains(neighbor))
+            .count();
+
+        assert_eq!(exterior_faces, 58);
+        assert_eq!(air.len() + lava.len(), bounds.volume() - 1);
+    }
+
+    #[test]
+    fn grids_turn_and_mirror() {
+        let grid = Grid::from_rows(["abc".chars(), "def".chars()]).unwrap();
+
+        assert_eq!(grid.to_string(), "abc\ndef");
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.mirror_horizontally().to_string(), "cba\nfed");
+        assert_eq!(grid.mirror_vertically().to_string(), "def\nabc");
+        assert_eq!(grid[GridPos::new(1, 0)], 'd');
+        assert_eq!(grid.get(&GridPos::new(0, 3)), None);
+        assert!(Grid::from_rows(["abc".chars(), "de".chars()]).is_err());
+    }
+
+    fn grids() -> impl Strategy<Value = Grid<u8>> {
+        (0..6usize, 0..6usize).prop_flat_map(|(rows, columns)| {
+            vec(any::<u8>(), rows * columns).prop_map(move |cells| Grid {
+                rows,
+                columns,
+                cells,
+            })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn four_rotations_come_back(grid in grids()) {
+            let turned = grid
+                .rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise();
+            let turned_back = grid
+                .rotate_counterclockwise()
+                .rotate_counterclockwise()
+                .rotate_counterclockwise()
+                .rotate_counterclockwise();
+
+            prop_assert_eq!(&turned, &grid);
+            prop_assert_eq!(&turned_back, &grid);
+            prop_assert_eq!(&grid.rotate_clockwise().rotate_counterclockwise(), &grid);
+        }
+
+        #[test]
+        fn rotations_are_a_transpose_and_a_mirror(grid in grids()) {
+            prop_assert_eq!(grid.rotate_clockwise(), grid.transpose().mirror_horizontally());
+            prop_assert_eq!(grid.rotate_counterclockwise(), grid.transpose().mirror_vertically());
+            prop_assert_eq!(&grid.transpose().transpose(), &grid);
+            prop_assert_eq!(&grid.mirror_horizontally().mirror_horizontally(), &grid);
+            prop_assert_eq!(&grid.mirror_vertically().mirror_vertically(), &grid);
+        }
+    }
+}
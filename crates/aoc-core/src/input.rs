@@ -0,0 +1,1357 @@
+use memmap2::Mmap;
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::env;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Read};
+use std::iter::Filter;
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use crate::parse::parse_line;
+use crate::SolutionError;
+
+pub trait FilterNotEmpty: Iterator + Sized {
+    fn filter_not_empty(self) -> Filter<Self, fn(&String) -> bool>;
+}
+
+impl<I> FilterNotEmpty for I
+where
+    I: Iterator<Item = String>,
+{
+    fn filter_not_empty(self) -> Filter<Self, fn(&String) -> bool> {
+        self.filter(|s| !s.is_empty())
+    }
+}
+
+/// Groups the lines of an iterator in blocks separated by blank lines.
+pub struct Blocks<I> {
+    lines: I,
+    line: usize,
+}
+
+impl<I, U> Blocks<I>
+where
+    I: Iterator<Item = U>,
+    U: AsRef<[u8]>,
+{
+    /// Yields each block with the number of its first line, from 1, to report errors at their
+    /// line.
+    pub fn numbered(self) -> NumberedBlocks<I> {
+        NumberedBlocks(self)
+    }
+
+    fn next_numbered(&mut self) -> Option<(usize, Vec<U>)> {
+        let mut block = Vec::new();
+        let mut first_line = 0;
+        for line in self.lines.by_ref() {
+            self.line += 1;
+            if !line.as_ref().iter().all(u8::is_ascii_whitespace) {
+                if block.is_empty() {
+                    first_line = self.line;
+                }
+                block.push(line);
+            } else if !block.is_empty() {
+                return Some((first_line, block));
+            }
+        }
+        (!block.is_empty()).then_some((first_line, block))
+    }
+}
+
+impl<I, U> Iterator for Blocks<I>
+where
+    I: Iterator<Item = U>,
+    U: AsRef<[u8]>,
+{
+    type Item = Vec<U>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_numbered().map(|(_, block)| block)
+    }
+}
+
+/// Blocks with the number of their first line, see `Blocks::numbered`.
+pub struct NumberedBlocks<I>(Blocks<I>);
+
+impl<I, U> Iterator for NumberedBlocks<I>
+where
+    I: Iterator<Item = U>,
+    U: AsRef<[u8]>,
+{
+    type Item = (usize, Vec<U>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next_numbered()
+    }
+}
+
+pub trait BlocksExt: Iterator + Sized {
+    fn blocks(self) -> Blocks<Self>;
+}
+
+impl<I> BlocksExt for I
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+{
+    fn blocks(self) -> Blocks<Self> {
+        Blocks {
+            lines: self,
+            line: 0,
+        }
+    }
+}
+
+pub struct Parse<I, T>(I, PhantomData<T>);
+
+impl<I, U, T> Iterator for Parse<I, T>
+where
+    I: Iterator<Item = U>,
+    U: ToString,
+    T: FromStr,
+    T::Err: Debug,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|item| item.to_string().parse().unwrap())
+    }
+}
+
+/// A line of the input that could not be parsed, numbered from 1.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Line {}: {}", self.line, self.message)
+    }
+}
+
+impl From<ParseError> for String {
+    fn from(error: ParseError) -> Self {
+        error.to_string()
+    }
+}
+
+impl From<ParseError> for SolutionError {
+    fn from(error: ParseError) -> Self {
+        Self::Input(error.to_string())
+    }
+}
+
+/// Parses the lines of an iterator, skipping the blank ones but still counting them.
+pub struct TryParse<I, T> {
+    lines: I,
+    line: usize,
+    parsed: PhantomData<T>,
+}
+
+impl<I, T> TryParse<I, T> {
+    /// Numbers the lines from `line` instead of 1, when the iterator starts in the middle of the
+    /// input.
+    pub fn first_line(mut self, line: usize) -> Self {
+        self.line = line.saturating_sub(1);
+        self
+    }
+
+    pub fn or_log(self) -> ParseOrLog<I, T> {
+        ParseOrLog(self)
+    }
+
+    pub fn lossy(self) -> ParseLossy<I, T> {
+        ParseLossy(self)
+    }
+}
+
+impl<I, U, T> Iterator for TryParse<I, T>
+where
+    I: Iterator<Item = U>,
+    U: AsRef<[u8]>,
+    T: FromStr,
+    T::Err: Display,
+{
+    type Item = Result<T, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let item = self.lines.next()?;
+            self.line += 1;
+            let error = |message: String| ParseError {
+                line: self.line,
+                message,
+            };
+            let item = match std::str::from_utf8(item.as_ref()) {
+                Ok(item) => item,
+                Err(utf8_error) => return Some(Err(error(utf8_error.to_string()))),
+            };
+            if !item.trim().is_empty() {
+                return Some(
+                    item.parse()
+                        .map_err(|parse_error: T::Err| error(parse_error.to_string())),
+                );
+            }
+        }
+    }
+}
+
+/// Parses the lines of an iterator, logging and skipping the ones that cannot be parsed.
+pub struct ParseOrLog<I, T>(TryParse<I, T>);
+
+impl<I, U, T> Iterator for ParseOrLog<I, T>
+where
+    I: Iterator<Item = U>,
+    U: AsRef<[u8]>,
+    T: FromStr,
+    T::Err: Display,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.0.next()? {
+                Ok(item) => return Some(item),
+                Err(error) => tracing::warn!("Skipping malformed input: {error}"),
+            }
+        }
+    }
+}
+
+thread_local! {
+    /// Lines skipped by `parse_lossy` while an `Input` is parsed on this thread, `None` outside.
+    static SKIPPED: RefCell<Option<Vec<ParseError>>> = const { RefCell::new(None) };
+}
+
+/// Parses the lines of an iterator, skipping the ones that cannot be parsed: the `Input` being
+/// parsed keeps them for the diagnostics of its run, and they are logged otherwise.
+pub struct ParseLossy<I, T>(TryParse<I, T>);
+
+impl<I, U, T> Iterator for ParseLossy<I, T>
+where
+    I: Iterator<Item = U>,
+    U: AsRef<[u8]>,
+    T: FromStr,
+    T::Err: Display,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.0.next()? {
+                Ok(item) => return Some(item),
+                Err(error) => SKIPPED.with_borrow_mut(|skipped| match skipped {
+                    Some(skipped) => skipped.push(error),
+                    None => tracing::warn!("Skipping malformed input: {error}"),
+                }),
+            }
+        }
+    }
+}
+
+/// The lines skipped by an outer parse, put back when dropped, even when the inner parse panics.
+struct OuterSkipped(Option<Vec<ParseError>>);
+
+impl Drop for OuterSkipped {
+    fn drop(&mut self) {
+        SKIPPED.set(self.0.take());
+    }
+}
+
+/// Runs `parse` along with the lines that `parse_lossy` skips meanwhile on this thread.
+fn collecting_skipped<T>(parse: impl FnOnce() -> T) -> (T, Vec<ParseError>) {
+    let outer = OuterSkipped(SKIPPED.replace(Some(Vec::new())));
+    let parsed = parse();
+    let skipped = SKIPPED.take().unwrap_or_default();
+    drop(outer);
+    (parsed, skipped)
+}
+
+pub trait ParseExt<I> {
+    fn parse<T>(self) -> Parse<I, T>;
+
+    fn try_parse<T>(self) -> TryParse<I, T>;
+
+    fn parse_or_log<T>(self) -> ParseOrLog<I, T>;
+
+    fn parse_lossy<T>(self) -> ParseLossy<I, T>;
+}
+
+impl<I: Iterator> ParseExt<I> for I {
+    fn parse<T>(self) -> Parse<I, T> {
+        Parse(self, PhantomData)
+    }
+
+    fn try_parse<T>(self) -> TryParse<I, T> {
+        TryParse {
+            lines: self,
+            line: 0,
+            parsed: PhantomData,
+        }
+    }
+
+    fn parse_or_log<T>(self) -> ParseOrLog<I, T> {
+        self.try_parse().or_log()
+    }
+
+    fn parse_lossy<T>(self) -> ParseLossy<I, T> {
+        self.try_parse().lossy()
+    }
+}
+
+/// UTF-8 byte order mark, written at the start of the files by some Windows editors.
+const BOM: &[u8] = b"\xef\xbb\xbf";
+
+/// Lines of a reader without their `\n` or `\r\n` ending, nor the byte order mark of the first one.
+/// Invalid UTF-8 is replaced with `U+FFFD`, so that the line is reported by its parser rather than
+/// ending the input.
+pub fn read_lines<R: Read>(reader: R) -> impl Iterator<Item = String> {
+    let mut buf_reader = BufReader::new(reader);
+    if buf_reader.fill_buf().is_ok_and(|buf| buf.starts_with(BOM)) {
+        buf_reader.consume(BOM.len());
+    }
+    buf_reader.split(b'\n').map_while(Result::ok).map(|line| {
+        let line = line.strip_suffix(b"\r").unwrap_or(&line);
+        String::from_utf8_lossy(line).into_owned()
+    })
+}
+
+/// Types built from all the lines of an input, as `FromStr` types are built from a single one.
+pub trait FromLines: Sized {
+    fn from_lines(lines: impl Iterator<Item = String>) -> Self;
+}
+
+pub trait ParseAll {
+    /// Builds a `FromLines` type from the non blank lines.
+    fn parse_all<T: FromLines>(&self) -> T;
+}
+
+impl ParseAll for [u8] {
+    fn parse_all<T: FromLines>(&self) -> T {
+        T::from_lines(read_lines(self).filter_not_empty())
+    }
+}
+
+/// Lines of an input as slices of it, without allocating a `String` per line like `read_lines`.
+pub fn byte_lines(input: &[u8]) -> impl Iterator<Item = &[u8]> {
+    let input = input.strip_prefix(BOM).unwrap_or(input);
+    input
+        .strip_suffix(b"\n")
+        .unwrap_or(input)
+        .split(|&byte| byte == b'\n')
+        .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+}
+
+/// Part of an input between blank lines, e.g. the stacks or the instructions of day 5.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Section<'a> {
+    pub name: &'static str,
+    pub input: &'a [u8],
+    /// Line of the whole input where the section starts, numbered from 1.
+    pub first_line: usize,
+}
+
+impl<'a> Section<'a> {
+    pub fn lines(&self) -> impl Iterator<Item = &'a [u8]> {
+        byte_lines(self.input)
+    }
+
+    /// Parses the lines of the section, numbered by their line in the whole input.
+    pub fn try_parse<T>(&self) -> TryParse<impl Iterator<Item = &'a [u8]>, T> {
+        self.lines().try_parse().first_line(self.first_line)
+    }
+
+    pub fn require(&self) -> Result<(), String> {
+        if self.input.iter().all(u8::is_ascii_whitespace) {
+            Err(format!("Missing {} section", self.name))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Splits an input on its blank lines into the sections named by `names`, in order. The last
+/// section holds the rest of the input and the missing sections are empty.
+pub fn sections<'a, const N: usize>(input: &'a [u8], names: [&'static str; N]) -> [Section<'a>; N] {
+    let is_blank = |line: &[u8]| line.iter().all(u8::is_ascii_whitespace);
+    let mut rest = input.strip_prefix(BOM).unwrap_or(input);
+    let mut line = 1;
+    std::array::from_fn(|index| {
+        for blank_line in rest.split_inclusive(|&byte| byte == b'\n') {
+            if !is_blank(blank_line) {
+                break;
+            }
+            rest = &rest[blank_line.len()..];
+            line += 1;
+        }
+        let first_line = line;
+        let len = if index + 1 == N {
+            rest.len()
+        } else {
+            rest.split_inclusive(|&byte| byte == b'\n')
+                .take_while(|section_line| !is_blank(section_line))
+                .inspect(|_| line += 1)
+                .map(<[u8]>::len)
+                .sum()
+        };
+        let (section, tail) = rest.split_at(len);
+        rest = tail;
+        Section {
+            name: names[index],
+            input: section,
+            first_line,
+        }
+    })
+}
+
+/// Windows of consecutive bytes of a reader, read in chunks so that scanning an input does not
+/// hold it in memory. Windows are not an `Iterator` because they borrow the reader.
+pub struct WindowReader<R> {
+    reader: R,
+    size: usize,
+    buffer: Vec<u8>,
+    /// Bytes of the input before the start of the buffer.
+    offset: usize,
+    /// Start in the buffer of the next window.
+    next: usize,
+}
+
+impl<R: Read> WindowReader<R> {
+    const CHUNK_SIZE: usize = 8 * 1024;
+
+    pub fn new(reader: R, size: usize) -> Self {
+        Self {
+            reader,
+            size: size.max(1),
+            buffer: Vec::new(),
+            offset: 0,
+            next: 0,
+        }
+    }
+
+    /// The next window with the number of bytes read up to its end, `None` after the last one.
+    pub fn next_window(&mut self) -> io::Result<Option<(usize, &[u8])>> {
+        while self.next + self.size > self.buffer.len() {
+            self.buffer.drain(..self.next);
+            self.offset += self.next;
+            self.next = 0;
+            let len = self.buffer.len();
+            self.buffer.resize(len + Self::CHUNK_SIZE, 0);
+            let read = loop {
+                match self.reader.read(&mut self.buffer[len..]) {
+                    Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                    result => break result,
+                }
+            };
+            let read = read.inspect_err(|_| self.buffer.truncate(len))?;
+            self.buffer.truncate(len + read);
+            if read == 0 {
+                return Ok(None);
+            }
+        }
+        let start = self.next;
+        self.next += 1;
+        Ok(Some((
+            self.offset + start + self.size,
+            &self.buffer[start..start + self.size],
+        )))
+    }
+
+    /// Number of bytes read up to the end of the first window matching `predicate`.
+    pub fn position(
+        &mut self,
+        mut predicate: impl FnMut(&[u8]) -> bool,
+    ) -> io::Result<Option<usize>> {
+        while let Some((end, window)) = self.next_window()? {
+            if predicate(window) {
+                return Ok(Some(end));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Splits a line and parses its parts, which are trimmed, naming the part that cannot be parsed.
+pub trait SplitParse {
+    /// Parses the two sides of the first `delimiter`, e.g. `"2-4".split_on::<u32, u32>('-')`.
+    fn split_on<A, B>(&self, delimiter: char) -> Result<(A, B), String>
+    where
+        A: FromStr,
+        A::Err: Display,
+        B: FromStr,
+        B::Err: Display;
+
+    fn split_parsed<T>(&self, delimiter: char) -> Result<Vec<T>, String>
+    where
+        T: FromStr,
+        T::Err: Display;
+
+    fn split_whitespace_parsed<T>(&self) -> Result<Vec<T>, String>
+    where
+        T: FromStr,
+        T::Err: Display;
+}
+
+fn parse_part<T>(part: &str) -> Result<T, String>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let part = part.trim();
+    part.parse()
+        .map_err(|error| format!("Invalid value {part:?}: {error}"))
+}
+
+impl SplitParse for str {
+    fn split_on<A, B>(&self, delimiter: char) -> Result<(A, B), String>
+    where
+        A: FromStr,
+        A::Err: Display,
+        B: FromStr,
+        B::Err: Display,
+    {
+        let (first, second) = self
+            .split_once(delimiter)
+            .ok_or_else(|| format!("Missing {delimiter:?} in {self:?}"))?;
+        Ok((parse_part(first)?, parse_part(second)?))
+    }
+
+    fn split_parsed<T>(&self, delimiter: char) -> Result<Vec<T>, String>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.split(delimiter).map(parse_part).collect()
+    }
+
+    fn split_whitespace_parsed<T>(&self) -> Result<Vec<T>, String>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.split_whitespace().map(parse_part).collect()
+    }
+}
+
+/// Line of a shell transcript: a command after the `$` prompt, or an entry listed by a command.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Token {
+    Command { name: String, args: Vec<String> },
+    DirEntry(String),
+    FileEntry { size: u64, name: String },
+}
+
+impl FromStr for Token {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        if let Some(command) = line.strip_prefix('$') {
+            let mut words = command.split_whitespace().map(str::to_string);
+            let name = words.next().ok_or("Missing command after the prompt")?;
+            return Ok(Self::Command {
+                name,
+                args: words.collect(),
+            });
+        }
+        if let Ok(("", name)) = parse_line!("dir {String}")(line) {
+            Ok(Self::DirEntry(name))
+        } else if let Ok(("", (size, name))) = parse_line!("{u64} {String}")(line) {
+            Ok(Self::FileEntry { size, name })
+        } else {
+            Err(format!("Invalid entry: {line}"))
+        }
+    }
+}
+
+/// Environment variable naming a directory of `dayN.txt` files searched before `inputs/`.
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+pub(crate) fn workspace_dir() -> &'static Path {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    manifest_dir.ancestors().nth(2).unwrap_or(manifest_dir)
+}
+
+/// Directory holding the puzzle inputs, `inputs/` at the root of the workspace.
+pub fn input_dir() -> PathBuf {
+    workspace_dir().join("inputs")
+}
+
+/// Directory holding the sample inputs of the puzzles, `examples/` at the root of the workspace.
+pub fn examples_dir() -> PathBuf {
+    workspace_dir().join("examples")
+}
+
+/// Reads another sample input of a day than the one of the puzzle, e.g. `example(9, "large")`
+/// reads `examples/day9-large.txt`. Panics when the file cannot be read, as it is meant for tests.
+pub fn example(day: u8, name: &str) -> Vec<u8> {
+    let path = examples_dir().join(format!("day{day}-{name}.txt"));
+    fs::read(&path).unwrap_or_else(|error| panic!("Cannot read {}: {error}", path.display()))
+}
+
+/// Directories searched for the inputs, by precedence: `dir` given on the command line, the one
+/// of `AOC_INPUT_DIR`, then `inputs/` at the root of the workspace.
+pub fn input_dirs(dir: Option<&Path>) -> Vec<PathBuf> {
+    dir.map(Path::to_path_buf)
+        .into_iter()
+        .chain(env::var_os(INPUT_DIR_ENV).map(PathBuf::from))
+        .chain([input_dir()])
+        .collect()
+}
+
+pub fn file_name(day: u8) -> String {
+    format!("day{day}.txt")
+}
+
+/// Path of the input of a day in the first of `dirs` that has it, or in the last one when none
+/// has it.
+pub fn resolve(day: u8, dirs: &[PathBuf]) -> Option<PathBuf> {
+    dirs.iter()
+        .map(|dir| dir.join(file_name(day)))
+        .find(|path| path.is_file())
+        .or_else(|| dirs.last().map(|dir| dir.join(file_name(day))))
+}
+
+pub fn path_for_day(day: u8) -> PathBuf {
+    resolve(day, &input_dirs(None)).unwrap_or_else(|| input_dir().join(file_name(day)))
+}
+
+/// Reads the input of a day from the inputs directory, e.g. `inputs/day1.txt`.
+pub fn for_day(day: u8) -> Result<Vec<u8>, String> {
+    let path = path_for_day(day);
+    fs::read(&path).map_err(|error| {
+        format!(
+            "Cannot read input of day {day} from {}: {error}",
+            path.display()
+        )
+    })
+}
+
+/// Reads a whole input from any reader, normalized like the input files, for the `solve_part1`
+/// and `solve_part2` functions of the days.
+pub fn read_all(mut reader: impl Read) -> Result<Bytes, SolutionError> {
+    let mut raw = Vec::new();
+    reader
+        .read_to_end(&mut raw)
+        .map_err(|error| SolutionError::Input(format!("Cannot read the input: {error}")))?;
+    Ok(normalize(Bytes::Embedded(Cow::Owned(raw))))
+}
+
+/// Maps the input of a day from the inputs directory in memory instead of copying it.
+pub fn read_bytes(day: u8) -> Result<Bytes, String> {
+    map_file(&path_for_day(day)).map(normalize)
+}
+
+pub fn map_file(path: &Path) -> Result<Bytes, String> {
+    let error = |error| format!("Cannot read input file {}: {error}", path.display());
+    let file = File::open(path).map_err(error)?;
+    // Mapping an empty file fails on some platforms
+    if file.metadata().map_err(error)?.len() == 0 {
+        return Ok(Bytes::Embedded(Cow::Borrowed(&[])));
+    }
+    // SAFETY: the inputs are not modified while the puzzles are solved
+    unsafe { Mmap::map(&file) }
+        .map(Bytes::Mapped)
+        .map_err(error)
+}
+
+/// Bytes of an input, memory mapped when read from a file.
+#[derive(Debug)]
+pub enum Bytes {
+    Embedded(Cow<'static, [u8]>),
+    Mapped(Mmap),
+}
+
+impl Bytes {
+    pub fn into_owned(self) -> Cow<'static, [u8]> {
+        match self {
+            Self::Embedded(raw) => raw,
+            Self::Mapped(raw) => Cow::Owned(raw.to_vec()),
+        }
+    }
+}
+
+/// Strips the byte order mark and turns the `\r\n` and `\r` line endings into `\n`, so that the
+/// inputs saved on Windows parse like the others. The input is only copied when it has either.
+pub fn normalize(bytes: Bytes) -> Bytes {
+    let raw = bytes.strip_prefix(BOM).unwrap_or(&bytes);
+    if raw.len() == bytes.len() && !raw.contains(&b'\r') {
+        return bytes;
+    }
+    let mut normalized = Vec::with_capacity(raw.len());
+    let mut raw = raw.iter().copied().peekable();
+    while let Some(byte) = raw.next() {
+        if byte != b'\r' {
+            normalized.push(byte);
+        } else if raw.peek() != Some(&b'\n') {
+            normalized.push(b'\n');
+        }
+    }
+    Bytes::Embedded(Cow::Owned(normalized))
+}
+
+impl Deref for Bytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            Self::Embedded(raw) => raw,
+            Self::Mapped(raw) => raw,
+        }
+    }
+}
+
+/// Shape of an input, to size the structures that hold it (e.g. the grids of days 8 and 12).
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct Stats {
+    pub bytes: usize,
+    /// Non blank lines.
+    pub lines: usize,
+    /// Blocks of lines separated by blank lines.
+    pub sections: usize,
+    pub longest_line: usize,
+}
+
+impl Stats {
+    pub fn of(input: &[u8]) -> Self {
+        Self {
+            bytes: input.len(),
+            lines: byte_lines(input).filter(|line| !line.is_empty()).count(),
+            sections: byte_lines(input).blocks().count(),
+            longest_line: byte_lines(input).map(<[u8]>::len).max().unwrap_or(0),
+        }
+    }
+}
+
+pub fn validate_input(input: &[u8]) -> Result<(), String> {
+    let input =
+        std::str::from_utf8(input).map_err(|error| format!("Input is not valid UTF-8: {error}"))?;
+    if input.trim().is_empty() {
+        Err("Input is empty".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+/// Validates an input whose non blank lines all parse as `T`, reporting the first line that does
+/// not.
+pub fn validate_lines<T>(input: &[u8]) -> Result<(), String>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    validate_input(input)?;
+    byte_lines(input)
+        .try_parse::<T>()
+        .try_for_each(|item| item.map(drop))
+        .map_err(String::from)
+}
+
+/// Where the input of a day comes from, read when the input is first used.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum InputSource {
+    /// Bytes built in the program, such as the puzzle examples.
+    Embedded(Cow<'static, [u8]>),
+    File(PathBuf),
+    Stdin,
+    /// A page downloaded over HTTP, with the cookie of an adventofcode.com session if given.
+    Url {
+        url: String,
+        session: Option<String>,
+    },
+}
+
+impl InputSource {
+    /// The `dayN.txt` file of the inputs directory.
+    pub fn for_day(day: u8) -> Self {
+        Self::File(path_for_day(day))
+    }
+
+    /// Sets the session cookie of a URL that has none.
+    pub fn with_session(self, session: impl FnOnce() -> Option<String>) -> Self {
+        match self {
+            Self::Url { url, session: None } => Self::Url {
+                url,
+                session: session(),
+            },
+            source => source,
+        }
+    }
+
+    /// Reads the input, normalized by `normalize`.
+    pub fn read(&self) -> Result<Bytes, String> {
+        self.read_raw().map(normalize)
+    }
+
+    fn read_raw(&self) -> Result<Bytes, String> {
+        match self {
+            Self::Embedded(raw) => Ok(Bytes::Embedded(raw.clone())),
+            Self::File(path) => map_file(path),
+            Self::Stdin => {
+                let mut raw = Vec::new();
+                io::stdin()
+                    .read_to_end(&mut raw)
+                    .map_err(|error| format!("Cannot read {self}: {error}"))?;
+                Ok(Bytes::Embedded(Cow::Owned(raw)))
+            }
+            Self::Url { url, session } => {
+                download(url, session.as_deref()).map(|raw| Bytes::Embedded(Cow::Owned(raw)))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "net")]
+fn download(url: &str, session: Option<&str>) -> Result<Vec<u8>, String> {
+    let mut request = ureq::get(url).set(
+        "User-Agent",
+        "github.com/alightgoesout/advent2022 input download",
+    );
+    if let Some(session) = session {
+        request = request.set("Cookie", &format!("session={session}"));
+    }
+    let mut raw = Vec::new();
+    request
+        .call()
+        .map_err(|error| format!("Cannot download {url}: {error}"))?
+        .into_reader()
+        .read_to_end(&mut raw)
+        .map_err(|error| format!("Cannot download {url}: {error}"))?;
+    Ok(raw)
+}
+
+#[cfg(not(feature = "net"))]
+fn download(url: &str, _session: Option<&str>) -> Result<Vec<u8>, String> {
+    Err(format!(
+        "Cannot download {url}: built without the net feature"
+    ))
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Embedded(_) => write!(f, "embedded input"),
+            Self::File(path) => write!(f, "input file {}", path.display()),
+            Self::Stdin => write!(f, "standard input"),
+            Self::Url { url, .. } => write!(f, "{url}"),
+        }
+    }
+}
+
+/// `-` is the standard input, the values starting with `http://` or `https://` are downloaded and
+/// the other ones are files.
+impl FromStr for InputSource {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(if value == "-" {
+            Self::Stdin
+        } else if value.starts_with("http://") || value.starts_with("https://") {
+            Self::Url {
+                url: value.to_string(),
+                session: None,
+            }
+        } else {
+            Self::File(PathBuf::from(value))
+        })
+    }
+}
+
+impl From<&'static [u8]> for InputSource {
+    fn from(raw: &'static [u8]) -> Self {
+        Self::Embedded(Cow::Borrowed(raw))
+    }
+}
+
+impl From<Vec<u8>> for InputSource {
+    fn from(raw: Vec<u8>) -> Self {
+        Self::Embedded(Cow::Owned(raw))
+    }
+}
+
+/// The input of a day, read from its source and parsed on first use.
+pub struct Input<T> {
+    source: InputSource,
+    raw: OnceLock<Result<Bytes, String>>,
+    parse: fn(&[u8]) -> T,
+    parsed: OnceLock<T>,
+    skipped: OnceLock<Vec<ParseError>>,
+}
+
+impl<T> Input<T> {
+    pub fn new(source: impl Into<InputSource>, parse: fn(&[u8]) -> T) -> Self {
+        Self {
+            source: source.into(),
+            raw: OnceLock::new(),
+            parse,
+            parsed: OnceLock::new(),
+            skipped: OnceLock::new(),
+        }
+    }
+
+    /// Input read from the inputs directory.
+    pub fn for_day(day: u8, parse: fn(&[u8]) -> T) -> Self {
+        Self::new(InputSource::for_day(day), parse)
+    }
+
+    pub fn source(&self) -> &InputSource {
+        &self.source
+    }
+
+    fn load(&self) -> Result<&[u8], &str> {
+        match self.raw.get_or_init(|| self.source.read()) {
+            Ok(raw) => Ok(raw),
+            Err(error) => Err(error),
+        }
+    }
+
+    pub fn raw(&self) -> &[u8] {
+        self.load().unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Parses the input ahead of the parts, which otherwise parse it on first use. An input that
+    /// cannot be read is left for the parts to report.
+    pub fn parse(&mut self) {
+        let _ = self.try_get();
+    }
+
+    pub fn get(&self) -> &T {
+        self.parsed.get_or_init(|| {
+            let raw = self.raw();
+            let (parsed, skipped) = tracing::debug_span!("parse", bytes = raw.len())
+                .in_scope(|| collecting_skipped(|| (self.parse)(raw)));
+            let _ = self.skipped.set(skipped);
+            parsed
+        })
+    }
+
+    /// Lines that the parsing skipped with `parse_lossy`, none until the input is parsed.
+    pub fn skipped(&self) -> &[ParseError] {
+        self.skipped.get().map_or(&[], Vec::as_slice)
+    }
+
+    /// Parsed input, or the error reading it for the parts to report.
+    pub fn try_get(&self) -> Result<&T, SolutionError> {
+        self.load()
+            .map_err(|error| SolutionError::Input(error.to_string()))?;
+        Ok(self.get())
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        validate_input(self.load()?)?;
+        self.get();
+        Ok(())
+    }
+}
+
+impl<T> Input<Result<T, ParseError>> {
+    /// Parsed input of the days that fail on the first malformed line, or the error reading or
+    /// parsing it for the parts to report.
+    pub fn try_get_parsed(&self) -> Result<&T, SolutionError> {
+        Ok(self.try_get()?.as_ref().map_err(Clone::clone)?)
+    }
+
+    /// Validates the input like `validate`, also reporting its first malformed line.
+    pub fn validate_parsed(&self) -> Result<(), String> {
+        self.validate()?;
+        self.get()
+            .as_ref()
+            .map(drop)
+            .map_err(|error| error.clone().into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn validate_blank_input() {
+        assert_eq!(validate_input(b"\n  \n"), Err("Input is empty".to_string()));
+    }
+
+    #[test]
+    fn validate_invalid_utf8_input() {
+        assert!(validate_input(b"\xff\xfe").is_err());
+    }
+
+    #[test]
+    fn validate_valid_input() {
+        assert_eq!(validate_input(b"\n1\n2\n"), Ok(()));
+    }
+
+    #[test]
+    fn missing_inputs_are_left_for_the_parts_to_report() {
+        let mut input = Input::new(InputSource::File(PathBuf::from("missing.txt")), |_| ());
+
+        input.parse();
+
+        assert!(matches!(input.try_get(), Err(SolutionError::Input(_))));
+    }
+
+    #[test]
+    fn validation_reports_the_first_malformed_line() {
+        let input = Input::new(&b"1\n\ntwo\n3\n"[..], |input| {
+            byte_lines(input)
+                .try_parse::<u8>()
+                .collect::<Result<Vec<_>, _>>()
+        });
+
+        assert_eq!(
+            input.validate_parsed(),
+            Err("Line 3: invalid digit found in string".to_string())
+        );
+    }
+
+    #[test]
+    fn a_panicking_parse_stops_collecting_the_skipped_lines() {
+        let result = std::panic::catch_unwind(|| collecting_skipped(|| panic!("parse")));
+
+        assert!(result.is_err());
+        assert!(SKIPPED.with_borrow(Option::is_none));
+    }
+
+    #[test]
+    fn lossy_parsing_keeps_the_skipped_lines() {
+        fn parse_numbers(input: &[u8]) -> Vec<u8> {
+            byte_lines(input).parse_lossy().collect()
+        }
+        let input = Input::new(b"1\nx\n\n3\n".to_vec(), parse_numbers);
+        let other = Input::new(b"4\n5\n".to_vec(), parse_numbers);
+
+        assert!(input.skipped().is_empty());
+        assert_eq!(input.get(), &vec![1, 3]);
+        assert_eq!(other.get(), &vec![4, 5]);
+        assert_eq!(
+            input.skipped(),
+            [ParseError {
+                line: 2,
+                message: "invalid digit found in string".to_string(),
+            }],
+        );
+        assert!(other.skipped().is_empty());
+        assert_eq!(parse_numbers(b"x\n6"), vec![6]);
+        assert_eq!(input.skipped().len(), 1);
+    }
+
+    #[test]
+    fn lines_are_split_and_parsed() {
+        assert_eq!("2-4".split_on::<u32, u32>('-'), Ok((2, 4)));
+        assert_eq!("1, 2,3".split_parsed::<u8>(','), Ok(vec![1, 2, 3]));
+        assert_eq!(" 7  -1 ".split_whitespace_parsed::<i8>(), Ok(vec![7, -1]));
+    }
+
+    #[test]
+    fn split_errors_name_the_invalid_part() {
+        assert_eq!(
+            "2-4".split_on::<u32, u32>(','),
+            Err("Missing ',' in \"2-4\"".to_string()),
+        );
+        assert_eq!(
+            "2-x".split_on::<u32, u32>('-'),
+            Err("Invalid value \"x\": invalid digit found in string".to_string()),
+        );
+    }
+
+    #[test]
+    fn transcript_lines_are_tokenized() {
+        let tokens = b"$ cd a\n$ ls\ndir b\n14848514 b.txt\n"
+            .split(|&byte| byte == b'\n')
+            .try_parse::<Token>()
+            .collect::<Result<Vec<_>, _>>();
+
+        assert_eq!(
+            tokens,
+            Ok(vec![
+                Token::Command {
+                    name: "cd".to_string(),
+                    args: vec!["a".to_string()],
+                },
+                Token::Command {
+                    name: "ls".to_string(),
+                    args: vec![],
+                },
+                Token::DirEntry("b".to_string()),
+                Token::FileEntry {
+                    size: 14848514,
+                    name: "b.txt".to_string(),
+                },
+            ]),
+        );
+    }
+
+    #[test]
+    fn invalid_transcript_lines_are_rejected() {
+        assert!("$".parse::<Token>().is_err());
+        assert!("dir".parse::<Token>().is_err());
+        assert!("big b.txt".parse::<Token>().is_err());
+    }
+
+    #[test]
+    fn input_is_parsed_once() {
+        let input = Input::new(&b"1\n2\n"[..], |raw| raw.len());
+
+        assert_eq!(*input.get(), 4);
+        assert_eq!(input.raw(), b"1\n2\n");
+    }
+
+    #[test]
+    fn input_is_parsed_ahead_of_use() {
+        let mut input = Input::new(&b"1\n2\n"[..], |raw| raw.len());
+
+        input.parse();
+
+        assert_eq!(input.parsed.get(), Some(&4));
+    }
+
+    #[test]
+    fn inputs_read_from_readers_are_normalized() {
+        let input = read_all(&b"\xEF\xBB\xBF1\r\n2\r\n"[..]).unwrap();
+
+        assert_eq!(&*input, b"1\n2\n");
+    }
+
+    #[test]
+    fn missing_input_is_an_input_error() {
+        let input = Input::for_day(0, |raw| raw.len());
+
+        assert!(matches!(
+            input.try_get(),
+            Err(SolutionError::Input(error)) if error.starts_with("Cannot read input file")
+        ));
+    }
+
+    #[test]
+    fn missing_input_fails_validation() {
+        let input = Input::for_day(0, |raw| raw.len());
+
+        assert!(input
+            .validate()
+            .is_err_and(|error| error.starts_with("Cannot read input file")));
+    }
+
+    #[test]
+    fn input_sources_from_arguments() {
+        assert_eq!("-".parse(), Ok(InputSource::Stdin));
+        assert_eq!(
+            "https://adventofcode.com/2022/day/1/input".parse(),
+            Ok(InputSource::Url {
+                url: "https://adventofcode.com/2022/day/1/input".to_string(),
+                session: None,
+            }),
+        );
+        assert_eq!(
+            "inputs/day1.txt".parse(),
+            Ok(InputSource::File(PathBuf::from("inputs/day1.txt"))),
+        );
+    }
+
+    #[test]
+    fn first_directory_with_the_input_wins() {
+        let empty = env::temp_dir().join("advent2022-no-inputs");
+        let dirs = [empty.clone(), input_dir(), empty.clone()];
+
+        assert_eq!(resolve(1, &dirs), Some(input_dir().join("day1.txt")));
+        assert_eq!(resolve(0, &dirs), Some(empty.join("day0.txt")));
+        assert_eq!(resolve(1, &[]), None);
+    }
+
+    #[test]
+    fn inputs_are_read_from_the_workspace() {
+        assert!(path_for_day(1).ends_with("inputs/day1.txt"));
+        assert!(for_day(1).is_ok_and(|input| !input.is_empty()));
+    }
+
+    #[test]
+    fn try_parse_reports_line_numbers() {
+        let result: Vec<Result<u32, ParseError>> = ["1", "", "x", "3"].iter().try_parse().collect();
+
+        assert_eq!(
+            result,
+            vec![
+                Ok(1),
+                Err(ParseError {
+                    line: 3,
+                    message: "invalid digit found in string".to_string(),
+                }),
+                Ok(3),
+            ],
+        );
+    }
+
+    #[test]
+    fn parse_or_log_skips_malformed_lines() {
+        let result: Vec<u32> = ["x", "2", "y", "4"]
+            .iter()
+            .try_parse()
+            .first_line(10)
+            .or_log()
+            .collect();
+
+        assert_eq!(result, vec![2, 4]);
+    }
+
+    #[test]
+    fn blocks_are_separated_by_blank_lines() {
+        let blocks: Vec<Vec<String>> = read_lines(&b"\n1\n2\n\n  \n3\n\n4\n5"[..])
+            .blocks()
+            .collect();
+
+        assert_eq!(blocks, vec![vec!["1", "2"], vec!["3"], vec!["4", "5"]]);
+    }
+
+    #[test]
+    fn numbered_blocks_start_at_their_first_line() {
+        let blocks: Vec<(usize, Vec<String>)> = read_lines(&b"\n1\n2\n\n  \n3\n\n4\n5"[..])
+            .blocks()
+            .numbered()
+            .collect();
+
+        assert_eq!(
+            blocks,
+            vec![
+                (2, vec!["1".to_string(), "2".to_string()]),
+                (6, vec!["3".to_string()]),
+                (8, vec!["4".to_string(), "5".to_string()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_utf8_does_not_end_the_lines() {
+        let lines: Vec<String> = read_lines(&b"1\r\n\xff2\n3"[..]).collect();
+
+        assert_eq!(lines, vec!["1", "\u{fffd}2", "3"]);
+    }
+
+    #[test]
+    fn byte_lines_are_slices_of_the_input() {
+        let lines: Vec<&[u8]> = byte_lines(b"1\r\n\n2\n").collect();
+
+        assert_eq!(lines, vec![&b"1"[..], b"", b"2"]);
+    }
+
+    #[test]
+    fn input_files_are_mapped() {
+        let bytes = read_bytes(1).unwrap();
+
+        assert!(matches!(bytes, Bytes::Mapped(_)));
+        assert_eq!(&*bytes, for_day(1).unwrap().as_slice());
+    }
+
+    #[test]
+    fn windows_line_endings_and_bom_are_normalized() {
+        let normalized = |raw: &'static [u8]| normalize(Bytes::Embedded(Cow::Borrowed(raw)));
+
+        assert_eq!(&*normalized(b"\xef\xbb\xbfA Y\r\nB X\r\n"), b"A Y\nB X\n");
+        assert_eq!(&*normalized(b"A Y\rB X\r\n\r\nC Z"), b"A Y\nB X\n\nC Z");
+        assert!(matches!(
+            normalized(b"A Y\nB X\n"),
+            Bytes::Embedded(Cow::Borrowed(_)),
+        ));
+    }
+
+    #[test]
+    fn input_is_split_in_sections() {
+        let [stacks, instructions] = sections(
+            b"\n[Z] [N]\n[P]\n\n\nmove 1 from 2 to 1\n\nmove 3 from 1 to 3\n",
+            ["stacks", "instructions"],
+        );
+
+        assert_eq!(stacks.input, b"[Z] [N]\n[P]\n");
+        assert_eq!(stacks.first_line, 2);
+        assert_eq!(
+            instructions.input,
+            b"move 1 from 2 to 1\n\nmove 3 from 1 to 3\n"
+        );
+        assert_eq!(instructions.first_line, 6);
+        assert_eq!(
+            instructions.lines().collect::<Vec<_>>(),
+            vec![b"move 1 from 2 to 1".as_slice(), b"", b"move 3 from 1 to 3"],
+        );
+    }
+
+    #[test]
+    fn missing_sections_are_empty() {
+        let [first, second] = sections(b"1\n2\n", ["first", "second"]);
+
+        assert_eq!(first.require(), Ok(()));
+        assert_eq!(second.input, b"");
+        assert_eq!(second.require(), Err("Missing second section".to_string()));
+    }
+
+    #[test]
+    fn section_lines_are_numbered_in_the_whole_input() {
+        let [_, numbers] = sections(b"a\n\n1\nx\n", ["letters", "numbers"]);
+
+        let errors = numbers
+            .try_parse::<u8>()
+            .filter_map(Result::err)
+            .map(|error| error.line)
+            .collect::<Vec<_>>();
+
+        assert_eq!(errors, vec![4]);
+    }
+
+    #[test]
+    fn windows_span_the_chunks_of_the_reader() {
+        let reader = io::repeat(b'a').take(20_000).chain(b"bcd".as_slice());
+        let mut windows = WindowReader::new(reader, 4);
+
+        let position = windows.position(|window| window == b"abcd");
+
+        assert_eq!(position.unwrap(), Some(20_003));
+        assert_eq!(windows.next_window().unwrap(), None);
+    }
+
+    #[test]
+    fn no_window_in_short_input() {
+        let mut windows = WindowReader::new(b"abc".as_slice(), 4);
+
+        assert_eq!(windows.next_window().unwrap(), None);
+    }
+
+    #[test]
+    fn lines_of_windows_inputs() {
+        let input = b"\xef\xbb\xbfA Y\r\nB X\r\n".as_slice();
+
+        assert_eq!(read_lines(input).collect::<Vec<_>>(), vec!["A Y", "B X"]);
+        assert_eq!(
+            byte_lines(input).collect::<Vec<_>>(),
+            vec![b"A Y".as_slice(), b"B X"],
+        );
+    }
+
+    #[test]
+    fn stats_of_input() {
+        assert_eq!(
+            Stats::of(b"1000\n2000\n\n4000\n\n\n50000\n"),
+            Stats {
+                bytes: 24,
+                lines: 4,
+                sections: 3,
+                longest_line: 5,
+            },
+        );
+        assert_eq!(Stats::of(b""), Stats::default());
+    }
+
+    #[test]
+    fn parse_all_lines() {
+        struct Sum(u32);
+
+        impl FromLines for Sum {
+            fn from_lines(lines: impl Iterator<Item = String>) -> Self {
+                Self(lines.parse::<u32>().sum())
+            }
+        }
+
+        assert_eq!(b"1\n\n2\n3\n".parse_all::<Sum>().0, 6);
+    }
+}
@@ -0,0 +1,198 @@
+use std::ops::RangeInclusive;
+
+/// A set of integers kept as sorted intervals that neither overlap nor touch, e.g. the columns of
+/// a row of day 15 that the sensors cover.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct IntervalSet(Vec<RangeInclusive<isize>>);
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Adds the integers of `range`, merging the intervals that it overlaps or touches.
+    pub fn insert(&mut self, range: RangeInclusive<isize>) {
+        if range.is_empty() {
+            return;
+        }
+        let (mut start, mut end) = range.into_inner();
+        let first = self
+            .0
+            .partition_point(|interval| interval.end().saturating_add(1) < start);
+        let last = self
+            .0
+            .partition_point(|interval| *interval.start() <= end.saturating_add(1));
+        if first < last {
+            start = start.min(*self.0[first].start());
+            end = end.max(*self.0[last - 1].end());
+        }
+        self.0.splice(first..last, [start..=end]);
+    }
+
+    /// Number of integers in the set.
+    pub fn len(&self) -> usize {
+        self.0
+            .iter()
+            .map(|interval| interval.end().abs_diff(*interval.start()) + 1)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn contains(&self, value: isize) -> bool {
+        self.contains_range(&(value..=value))
+    }
+
+    /// Whether every integer of `range` is in the set.
+    pub fn contains_range(&self, range: &RangeInclusive<isize>) -> bool {
+        let index = self
+            .0
+            .partition_point(|interval| interval.end() < range.start());
+        self.0.get(index).is_some_and(|interval| {
+            interval.start() <= range.start() && range.end() <= interval.end()
+        })
+    }
+
+    /// Whether some integer of `range` is in the set.
+    pub fn overlaps(&self, range: &RangeInclusive<isize>) -> bool {
+        let index = self
+            .0
+            .partition_point(|interval| interval.end() < range.start());
+        !range.is_empty()
+            && self
+                .0
+                .get(index)
+                .is_some_and(|interval| interval.start() <= range.end())
+    }
+
+    /// The intervals of the set, in increasing order.
+    pub fn intervals(&self) -> &[RangeInclusive<isize>] {
+        &self.0
+    }
+
+    /// The integers missing between the first and the last interval of the set.
+    pub fn gaps(&self) -> impl Iterator<Item = RangeInclusive<isize>> + '_ {
+        self.0
+            .windows(2)
+            .map(|pair| pair[0].end() + 1..=pair[1].start() - 1)
+    }
+
+    /// The integers of `bounds` that are not in the set.
+    pub fn complement(&self, bounds: RangeInclusive<isize>) -> IntervalSet {
+        let (start, end) = bounds.into_inner();
+        let mut complement = IntervalSet::new();
+        let mut next = Some(start);
+        for interval in &self.0 {
+            let Some(free) = next.filter(|&free| free <= end) else {
+                break;
+            };
+            if *interval.end() < free {
+                continue;
+            }
+            if *interval.start() > free {
+                complement.insert(free..=end.min(interval.start() - 1));
+            }
+            next = interval.end().checked_add(1);
+        }
+        if let Some(free) = next {
+            complement.insert(free..=end);
+        }
+        complement
+    }
+}
+
+impl From<RangeInclusive<isize>> for IntervalSet {
+    fn from(range: RangeInclusive<isize>) -> Self {
+        let mut set = Self::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl FromIterator<RangeInclusive<isize>> for IntervalSet {
+    fn from_iter<T: IntoIterator<Item = RangeInclusive<isize>>>(iter: T) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<RangeInclusive<isize>> for IntervalSet {
+    fn extend<T: IntoIterator<Item = RangeInclusive<isize>>>(&mut self, iter: T) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn overlapping_and_touching_intervals_merge() {
+        let set = [
+            10..=12,
+            1..=3,
+            5..=6,
+            4..=4,
+            11..=15,
+            RangeInclusive::new(20, 19),
+        ]
+        .into_iter()
+        .collect::<IntervalSet>();
+
+        assert_eq!(set.intervals(), [1..=6, 10..=15]);
+        assert_eq!(set.len(), 12);
+    }
+
+    #[test]
+    fn an_interval_swallows_the_ones_it_covers() {
+        let mut set = [1..=2, 4..=5, 8..=9, 12..=12]
+            .into_iter()
+            .collect::<IntervalSet>();
+
+        set.insert(3..=10);
+
+        assert_eq!(set.intervals(), [1..=10, 12..=12]);
+    }
+
+    #[test]
+    fn membership() {
+        let set = [-5..=-1, 3..=8].into_iter().collect::<IntervalSet>();
+
+        assert!(set.contains(-5));
+        assert!(!set.contains(0));
+        assert!(set.contains_range(&(4..=8)));
+        assert!(!set.contains_range(&(-2..=3)));
+        assert!(set.overlaps(&(-2..=3)));
+        assert!(!set.overlaps(&(0..=2)));
+        assert!(!set.overlaps(&RangeInclusive::new(4, 3)));
+    }
+
+    #[test]
+    fn gaps_and_complement() {
+        let set = [-5..=-1, 3..=8, 10..=12]
+            .into_iter()
+            .collect::<IntervalSet>();
+
+        assert_eq!(set.gaps().collect::<Vec<_>>(), [0..=2, 9..=9]);
+        assert_eq!(
+            set.complement(-10..=20).intervals(),
+            [-10..=-6, 0..=2, 9..=9, 13..=20]
+        );
+        assert_eq!(set.complement(4..=9).intervals(), [9..=9]);
+        assert_eq!(set.complement(4..=7), IntervalSet::new());
+        assert_eq!(IntervalSet::new().complement(1..=3).intervals(), [1..=3]);
+    }
+
+    #[test]
+    fn intervals_reach_the_limits_of_the_integers() {
+        let set = IntervalSet::from(0..=isize::MAX);
+
+        assert_eq!(set.complement(-2..=isize::MAX).intervals(), [-2..=-1]);
+        assert!(set.contains(isize::MAX));
+    }
+}
@@ -0,0 +1,837 @@
+use serde::Serialize;
+use std::any::Any;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{Display, Formatter};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+// Lets the parsers generated by `parse_line!` name this crate from within it too.
+extern crate self as aoc_core;
+
+pub mod anonymize;
+pub mod cancel;
+pub mod collections;
+pub mod combinatorics;
+pub mod cycle;
+pub mod day;
+pub mod geometry;
+pub mod input;
+pub mod interval;
+pub mod math;
+pub mod memo;
+pub mod memory;
+pub mod ocr;
+pub mod parse;
+pub mod pathfinding;
+pub mod plot;
+pub mod progress;
+pub mod puzzle;
+pub mod report;
+pub mod simulation;
+pub mod style;
+
+use cancel::CancellationToken;
+use input::{InputSource, ParseError};
+use progress::ProgressSink;
+use report::{DayReport, ExtraReport, Format, PartReport, Phase, Timings};
+
+pub trait Solution: Send + Sync {
+    fn day(&self) -> u8;
+    /// Year of the puzzle, in the URLs of adventofcode.com.
+    fn year(&self) -> u16 {
+        2022
+    }
+    /// Title of the puzzle recorded in `puzzles.toml` when its input is added, empty when unknown.
+    fn title(&self) -> &'static str {
+        puzzle::title(self.day()).unwrap_or_default()
+    }
+    /// Creates the same solution working on another input.
+    fn with_input(&self, source: InputSource) -> Box<dyn Solution>;
+    /// Parses the input before the parts run, so that they are timed without the parsing. The
+    /// input is parsed by the first part otherwise.
+    fn parse(&mut self) {}
+    fn part_one(&self) -> Result<Answer, SolutionError>;
+    fn part_two(&self) -> Result<Answer, SolutionError>;
+
+    /// Known answer of part one on the input of the inputs directory, which the runner flags the
+    /// part when it no longer gives.
+    fn expected_part_one(&self) -> Option<Answer> {
+        None
+    }
+
+    fn expected_part_two(&self) -> Option<Answer> {
+        None
+    }
+
+    /// What the answer of a part is, shown before it to the humans, e.g. `Highest scenic score`.
+    fn label(&self, part: Part) -> String {
+        format!("Part {part}")
+    }
+
+    fn validate(&self) -> Result<(), String>;
+
+    /// Lines of the input skipped by a lossy parsing, for the runner to list them.
+    fn skipped_lines(&self) -> Vec<ParseError> {
+        Vec::new()
+    }
+
+    fn stats(&self, _params: &Params) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Times the stages of the day separately, reported alongside the statistics.
+    fn phases(&self) -> Vec<Phase> {
+        Vec::new()
+    }
+
+    /// The sample inputs of the puzzle with their answers, for the days that provide them.
+    fn examples(&self) -> Vec<Example> {
+        Vec::new()
+    }
+
+    /// Creates the same solution working on a sample input, like `with_input` unless the samples
+    /// are solved with other parameters than the real input.
+    fn with_example_input(&self, input: InputSource) -> Box<dyn Solution> {
+        self.with_input(input)
+    }
+
+    /// Whether `visualize` animates the puzzle in the terminal.
+    fn can_visualize(&self) -> bool {
+        false
+    }
+
+    /// Animates part one in the terminal and returns its answer, for the days that support it.
+    fn visualize(&self) -> Option<String> {
+        None
+    }
+
+    /// Names of the implementations the parts can be solved with, the default one first, e.g. a
+    /// naive one kept to check a faster one. Empty for the days with a single implementation.
+    fn implementations(&self) -> Vec<&'static str> {
+        Vec::new()
+    }
+
+    /// Creates the same solution working on the same input with another of its `implementations`,
+    /// `None` when it has no implementation with this name.
+    fn with_implementation(&self, _name: &str) -> Option<Box<dyn Solution>> {
+        None
+    }
+
+    /// Parts of the day beyond the two of the puzzle, e.g. statistics or a picture of the solved
+    /// puzzle, run by their name with `extra_part`.
+    fn extra_parts(&self) -> Vec<ExtraPart> {
+        Vec::new()
+    }
+
+    /// Runs an extra part, `None` when the day has no extra part with this name.
+    fn extra_part(&self, _name: &str) -> Option<Result<Answer, SolutionError>> {
+        None
+    }
+
+    /// Parts that are solved, both by default.
+    fn implemented_parts(&self) -> Vec<Part> {
+        Part::ALL.to_vec()
+    }
+
+    fn part(&self, part: Part) -> Result<Answer, SolutionError> {
+        match part {
+            Part::One => self.part_one(),
+            Part::Two => self.part_two(),
+        }
+    }
+
+    fn expected(&self, part: Part) -> Option<Answer> {
+        match part {
+            Part::One => self.expected_part_one(),
+            Part::Two => self.expected_part_two(),
+        }
+    }
+
+    fn execute(&self, options: &Options) -> DayReport {
+        execute_parts(self, options, |part| {
+            Some(cancel::with_token(&options.cancellation, || {
+                progress::with_sink(options.progress.clone(), || catch_panic(|| self.part(part)))
+            }))
+        })
+    }
+}
+
+/// A part of a day beyond the two of the puzzle, run by its name instead of them.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ExtraPart {
+    pub name: &'static str,
+    /// What the answer of the part is, as for `Solution::label`.
+    pub label: &'static str,
+}
+
+/// Solution registered with `register_solution!`, built with the input of its day.
+pub struct Registration(pub fn() -> Arc<dyn Solution>);
+
+inventory::collect!(Registration);
+
+#[doc(hidden)]
+pub use inventory;
+
+/// Registers a solution built with `Default` so that it is listed by `registered_solutions`,
+/// e.g. `register_solution!(Day9);` next to `pub struct Day9`, or built by a function, e.g.
+/// `register_solution!(fn solution);`.
+#[macro_export]
+macro_rules! register_solution {
+    (fn $constructor:path) => {
+        $crate::inventory::submit! {
+            $crate::Registration(|| {
+                ::std::sync::Arc::new($constructor()) as ::std::sync::Arc<dyn $crate::Solution>
+            })
+        }
+    };
+    ($solution:ty) => {
+        $crate::inventory::submit! {
+            $crate::Registration(|| {
+                ::std::sync::Arc::new(<$solution as ::std::default::Default>::default())
+                    as ::std::sync::Arc<dyn $crate::Solution>
+            })
+        }
+    };
+}
+
+/// The solutions registered with `register_solution!` by their year and day.
+pub fn registered_solutions() -> BTreeMap<(u16, u8), Arc<dyn Solution>> {
+    inventory::iter::<Registration>
+        .into_iter()
+        .map(|registration| (registration.0)())
+        .map(|solution| ((solution.year(), solution.day()), solution))
+        .collect()
+}
+
+/// Runs `Solution::parse` and returns the time it took.
+pub fn parse_timed<S: Solution + ?Sized>(solution: &mut S) -> Duration {
+    let day = solution.day();
+    let start = Instant::now();
+    tracing::info_span!("parse", day).in_scope(|| solution.parse());
+    start.elapsed()
+}
+
+/// Executes a day like `Solution::execute`, but runs each part on a worker thread and stops
+/// waiting for it after `budget`. A part that times out is reported as slow without an answer and
+/// cancelled, which stops it at its next `cancel::checkpoint`; a part without checkpoints keeps
+/// running in the background until the process exits.
+pub fn execute_with_budget(
+    solution: &Arc<dyn Solution>,
+    options: &Options,
+    budget: Duration,
+) -> DayReport {
+    execute_parts(solution.as_ref(), options, |part| {
+        let (sender, receiver) = mpsc::channel();
+        let worker = Arc::clone(solution);
+        let token = options.cancellation.child();
+        let worker_token = token.clone();
+        let sink = options.progress.clone();
+        thread::spawn(move || {
+            sender.send(cancel::with_token(&worker_token, || {
+                progress::with_sink(sink, || catch_panic(|| worker.part(part)))
+            }))
+        });
+        match receiver.recv_timeout(budget) {
+            Ok(answer) => Some(answer),
+            Err(RecvTimeoutError::Timeout) => {
+                token.cancel();
+                None
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                panic!("Part {part} of day {} panicked", solution.day())
+            }
+        }
+    })
+}
+
+fn execute_parts<S: Solution + ?Sized>(
+    solution: &S,
+    options: &Options,
+    mut run_part: impl FnMut(Part) -> Option<Result<Answer, SolutionError>>,
+) -> DayReport {
+    let day = solution.day();
+    let mut timings = Timings::start();
+    let mut parts = Vec::new();
+    for part in options.parts() {
+        let ((answer, duration), peak_memory) = tracing::info_span!("part", day, %part)
+            .in_scope(|| memory::measure(|| timings.time(part, || run_part(part))));
+        let slow = answer.is_none() || options.max_time.is_some_and(|budget| duration > budget);
+        let panicked = matches!(answer, Some(Err(SolutionError::Panicked(_))));
+        let (answer, error) = match answer {
+            Some(Ok(answer)) => (Some(answer), None),
+            Some(Err(SolutionError::Cancelled)) => (None, None),
+            Some(Err(error)) => (None, Some(error.to_string())),
+            None => (None, None),
+        };
+        let expected = options
+            .checks_expected_answers()
+            .then(|| solution.expected(part))
+            .flatten()
+            .filter(|expected| {
+                answer
+                    .as_ref()
+                    .is_some_and(|answer| !answer.matches(&expected.to_string()))
+            });
+        parts.push(PartReport {
+            year: solution.year(),
+            day,
+            part,
+            label: solution.label(part),
+            slow,
+            answer,
+            error,
+            panicked,
+            expected,
+            duration,
+            peak_memory,
+        });
+    }
+    let extras = options
+        .extra_part
+        .iter()
+        .filter_map(|name| run_extra_part(solution, options, name))
+        .collect();
+    let (stats, phases) = if options.stats {
+        (solution.stats(&options.params(day)), solution.phases())
+    } else {
+        (Vec::new(), Vec::new())
+    };
+    DayReport {
+        year: solution.year(),
+        day,
+        title: solution.title(),
+        parse: None,
+        parts,
+        extras,
+        stats,
+        phases,
+        skipped: solution.skipped_lines(),
+        duration: timings.total(),
+    }
+}
+
+fn run_extra_part<S: Solution + ?Sized>(
+    solution: &S,
+    options: &Options,
+    name: &str,
+) -> Option<ExtraReport> {
+    let extra = solution
+        .extra_parts()
+        .into_iter()
+        .find(|extra| extra.name == name)?;
+    let start = Instant::now();
+    let result = cancel::with_token(&options.cancellation, || {
+        progress::with_sink(options.progress.clone(), || {
+            catch_panic(|| solution.extra_part(name).transpose()).transpose()
+        })
+    })?;
+    let duration = start.elapsed();
+    let (answer, error) = match result {
+        Ok(answer) => (Some(answer), None),
+        Err(SolutionError::Cancelled) => (None, None),
+        Err(error) => (None, Some(error.to_string())),
+    };
+    Some(ExtraReport {
+        year: solution.year(),
+        day: solution.day(),
+        name: extra.name,
+        label: extra.label,
+        answer,
+        error,
+        duration,
+    })
+}
+
+/// Runs a part, turning a panic of its solver into an error so that the other days still run.
+fn catch_panic<T>(part: impl FnOnce() -> Result<T, SolutionError>) -> Result<T, SolutionError> {
+    panic::catch_unwind(AssertUnwindSafe(part))
+        .unwrap_or_else(|panic| Err(SolutionError::Panicked(panic_message(panic.as_ref()))))
+}
+
+/// Message given to `panic!`, or a placeholder for a panic with another payload.
+pub fn panic_message(panic: &(dyn Any + Send)) -> String {
+    if let Some(message) = panic.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = panic.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Unknown panic".to_string()
+    }
+}
+
+/// Answer of a part, formatted for the humans by the runner and compared or submitted as is.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Text(String),
+    /// A picture, e.g. the letters drawn by the CRT of day 10, one string per row.
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    /// A picture made of the lines of `picture`.
+    pub fn grid(picture: &str) -> Self {
+        Self::Grid(picture.lines().map(str::to_string).collect())
+    }
+
+    /// Whether the answer is `expected`, ignoring the whitespace around it and, for the grids, at
+    /// the end of their rows.
+    pub fn matches(&self, expected: &str) -> bool {
+        match self {
+            Self::Grid(rows) => rows
+                .iter()
+                .map(|row| row.trim_end())
+                .eq(expected.trim_matches('\n').lines().map(str::trim_end)),
+            answer => answer.to_string() == expected.trim(),
+        }
+    }
+
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Self::Grid(rows) if rows.len() > 1)
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unsigned(answer) => write!(f, "{answer}"),
+            Self::Signed(answer) => write!(f, "{answer}"),
+            Self::Text(answer) => write!(f, "{answer}"),
+            Self::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+macro_rules! answer_from {
+    ($variant:ident($target:ty): $($source:ty),*) => {
+        $(impl From<$source> for Answer {
+            fn from(answer: $source) -> Self {
+                Self::$variant(answer as $target)
+            }
+        })*
+    };
+}
+
+answer_from!(Unsigned(u64): u8, u16, u32, u64, usize);
+answer_from!(Signed(i64): i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(answer: String) -> Self {
+        Self::Text(answer)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(answer: &str) -> Self {
+        Self::Text(answer.to_string())
+    }
+}
+
+/// What a solve function returns to answer a part: a value, or a `Result` when it can fail.
+pub trait IntoAnswer {
+    fn into_answer(self) -> Result<Answer, SolutionError>;
+}
+
+impl<T: Into<Answer>> IntoAnswer for T {
+    fn into_answer(self) -> Result<Answer, SolutionError> {
+        Ok(self.into())
+    }
+}
+
+impl<T: Into<Answer>> IntoAnswer for Result<T, SolutionError> {
+    fn into_answer(self) -> Result<Answer, SolutionError> {
+        self.map(Into::into)
+    }
+}
+
+/// Failure of a part, reported as an error of its day instead of aborting the run.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum SolutionError {
+    /// The input cannot be read or does not have the expected format.
+    Input(String),
+    /// The solution reached a state it does not handle, e.g. a puzzle without an answer.
+    Unexpected(String),
+    /// The part stopped at a `cancel::checkpoint` after its cancellation.
+    Cancelled,
+    /// The solver panicked, with the message of the panic.
+    Panicked(String),
+}
+
+impl Display for SolutionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Input(error) => write!(f, "Invalid input: {error}"),
+            Self::Unexpected(error) => write!(f, "{error}"),
+            Self::Cancelled => write!(f, "Cancelled"),
+            Self::Panicked(message) => write!(f, "Panicked: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for SolutionError {}
+
+/// Sample input of a puzzle, with the answers given in the puzzle text.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Example {
+    /// Name of the other samples than the first one, e.g. `large` for `examples/day9-large.txt`.
+    pub name: Option<&'static str>,
+    pub input: &'static [u8],
+    pub answers: ExampleAnswers,
+}
+
+impl Example {
+    pub fn label(&self) -> String {
+        match self.name {
+            Some(name) => format!("example {name}"),
+            None => "example".to_string(),
+        }
+    }
+
+    /// The solution of a day working on this sample.
+    pub fn solution(&self, solution: &dyn Solution) -> Box<dyn Solution> {
+        solution.with_example_input(self.input.into())
+    }
+
+    /// Panics when a part of the solution does not give the sample answer, to check the examples
+    /// in tests.
+    pub fn assert_answers(&self, solution: &dyn Solution) {
+        let example = self.solution(solution);
+        for part in example.implemented_parts() {
+            let answer = example.part(part).unwrap_or_else(|error| {
+                panic!(
+                    "part {part} of the {} of day {} failed: {error}",
+                    self.label(),
+                    example.day()
+                )
+            });
+            assert!(
+                answer.matches(self.answers.answer(part)),
+                "wrong answer to part {part} of the {} of day {}: expected {} but got {answer}",
+                self.label(),
+                example.day(),
+                self.answers.answer(part).trim(),
+            );
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ExampleAnswers {
+    pub part_one: String,
+    pub part_two: String,
+}
+
+impl ExampleAnswers {
+    pub fn new(part_one: impl ToString, part_two: impl ToString) -> Self {
+        Self {
+            part_one: part_one.to_string(),
+            part_two: part_two.to_string(),
+        }
+    }
+
+    pub fn answer(&self, part: Part) -> &str {
+        match part {
+            Part::One => &self.part_one,
+            Part::Two => &self.part_two,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize)]
+#[serde(into = "u8")]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn selected(part: Option<Part>) -> Vec<Part> {
+        match part {
+            Some(part) => vec![part],
+            None => Part::ALL.to_vec(),
+        }
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::One => write!(f, "1"),
+            Self::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(part: &str) -> Result<Self, Self::Err> {
+        match part {
+            "1" => Ok(Self::One),
+            "2" => Ok(Self::Two),
+            _ => Err(format!("Invalid part: {part}")),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Options {
+    pub part: Option<Part>,
+    /// Name of the extra part run instead of the parts of the puzzle, e.g. `inspections` for day 11.
+    pub extra_part: Option<String>,
+    pub stats: bool,
+    pub mask_answers: bool,
+    pub dry_run: bool,
+    pub emit_markdown: Option<PathBuf>,
+    pub input: Option<InputSource>,
+    /// Session cookie of adventofcode.com, to download the missing inputs before the run.
+    pub session: Option<String>,
+    /// Directory of `dayN.txt` files replacing the default inputs.
+    pub input_dir: Option<PathBuf>,
+    /// Name of the implementation solving the days that have several, e.g. `perimeter` for day 15.
+    pub implementation: Option<String>,
+    pub example: bool,
+    pub max_time: Option<Duration>,
+    /// Stops the running part, e.g. on Ctrl-C.
+    pub cancellation: CancellationToken,
+    /// Shows the progress of the long loops of the parts, e.g. as a progress bar.
+    pub progress: Option<Arc<dyn ProgressSink>>,
+    pub format: Format,
+    pub params: Params,
+    /// Parameters of a single day, overridden by `params`.
+    pub day_params: HashMap<u8, Params>,
+}
+
+impl Options {
+    /// Parts of the puzzle to run, none when an extra part is run instead.
+    pub fn parts(&self) -> Vec<Part> {
+        if self.extra_part.is_some() {
+            Vec::new()
+        } else {
+            Part::selected(self.part)
+        }
+    }
+
+    /// Parameters given to the statistics of a day.
+    pub fn params(&self, day: u8) -> Params {
+        let mut params = self.day_params.get(&day).cloned().unwrap_or_default();
+        params.extend(&self.params);
+        params
+    }
+
+    /// Whether the parts run on the default inputs, whose answers are known by the solutions.
+    fn checks_expected_answers(&self) -> bool {
+        self.input.is_none() && self.input_dir.is_none() && !self.example
+    }
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Params(HashMap<String, String>);
+
+impl Params {
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(String::as_str)
+    }
+
+    /// Adds the parameters of `other`, replacing the values of the keys already present.
+    pub fn extend(&mut self, other: &Params) {
+        self.0.extend(
+            other
+                .0
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone())),
+        );
+    }
+}
+
+impl FromIterator<(String, String)> for Params {
+    fn from_iter<T: IntoIterator<Item = (String, String)>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn answers_match_their_raw_value() {
+        assert!(Answer::from(42_usize).matches(" 42\n"));
+        assert!(Answer::from(-3).matches("-3"));
+        assert!(Answer::from("CMZ").matches("CMZ"));
+        assert!(!Answer::from(42_u32).matches("Answer: 42"));
+    }
+
+    struct Known;
+
+    impl Solution for Known {
+        fn day(&self) -> u8 {
+            1
+        }
+
+        fn with_input(&self, _source: InputSource) -> Box<dyn Solution> {
+            Box::new(Known)
+        }
+
+        fn validate(&self) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn part_one(&self) -> Result<Answer, SolutionError> {
+            Ok(Answer::from(2_u32))
+        }
+
+        fn part_two(&self) -> Result<Answer, SolutionError> {
+            Ok(Answer::from(3_u32))
+        }
+
+        fn expected_part_one(&self) -> Option<Answer> {
+            Some(Answer::from(1_u32))
+        }
+
+        fn expected_part_two(&self) -> Option<Answer> {
+            Some(Answer::from(3_u32))
+        }
+    }
+
+    #[test]
+    fn parts_giving_another_answer_than_the_expected_one_are_flagged() {
+        let report = Known.execute(&Options::default());
+
+        assert_eq!(report.parts[0].expected, Some(Answer::Unsigned(1)));
+        assert_eq!(report.parts[1].expected, None);
+    }
+
+    #[test]
+    fn expected_answers_are_only_checked_on_the_default_inputs() {
+        let options = Options {
+            example: true,
+            ..Options::default()
+        };
+
+        let report = Known.execute(&options);
+
+        assert_eq!(report.parts[0].expected, None);
+    }
+
+    /// Loops until its part is cancelled.
+    struct Endless;
+
+    impl Solution for Endless {
+        fn day(&self) -> u8 {
+            2
+        }
+
+        fn with_input(&self, _source: InputSource) -> Box<dyn Solution> {
+            Box::new(Endless)
+        }
+
+        fn validate(&self) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn part_one(&self) -> Result<Answer, SolutionError> {
+            loop {
+                cancel::checkpoint()?;
+                thread::yield_now();
+            }
+        }
+
+        fn part_two(&self) -> Result<Answer, SolutionError> {
+            Ok(Answer::from(3_u32))
+        }
+    }
+
+    #[test]
+    fn parts_exceeding_their_budget_are_cancelled() {
+        let solution: Arc<dyn Solution> = Arc::new(Endless);
+        let token = CancellationToken::default();
+        let options = Options {
+            max_time: Some(Duration::from_millis(10)),
+            cancellation: token.clone(),
+            ..Options::default()
+        };
+
+        let report = execute_with_budget(&solution, &options, Duration::from_millis(10));
+
+        assert!(report.parts[0].slow);
+        assert_eq!(report.parts[0].answer, None);
+        assert_eq!(report.parts[1].answer, Some(Answer::Unsigned(3)));
+        assert!(!token.is_cancelled());
+    }
+
+    #[test]
+    fn cancelled_parts_are_aborted_without_error() {
+        let options = Options::default();
+        options.cancellation.cancel();
+
+        let report = Endless.execute(&options);
+
+        assert_eq!(report.parts[0].answer, None);
+        assert_eq!(report.parts[0].error, None);
+    }
+
+    /// Panics in its first part, as a solver unwrapping a missing value.
+    struct Panicking;
+
+    impl Solution for Panicking {
+        fn day(&self) -> u8 {
+            3
+        }
+
+        fn with_input(&self, _source: InputSource) -> Box<dyn Solution> {
+            Box::new(Panicking)
+        }
+
+        fn validate(&self) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn part_one(&self) -> Result<Answer, SolutionError> {
+            panic!("No marker")
+        }
+
+        fn part_two(&self) -> Result<Answer, SolutionError> {
+            Ok(Answer::from(3_u32))
+        }
+    }
+
+    #[test]
+    fn panicking_parts_fail_without_stopping_the_day() {
+        let report = Panicking.execute(&Options::default());
+
+        assert!(report.parts[0].panicked);
+        assert_eq!(
+            report.parts[0].error.as_deref(),
+            Some("Panicked: No marker")
+        );
+        assert_eq!(report.parts[1].answer, Some(Answer::Unsigned(3)));
+    }
+
+    #[test]
+    fn grids_match_without_trailing_spaces() {
+        let answer = Answer::grid("#  # \n#### ");
+
+        assert!(answer.matches("\n#  #\n####\n"));
+        assert!(!answer.matches("#  #"));
+        assert_eq!(answer.to_string(), "#  # \n#### ");
+    }
+}
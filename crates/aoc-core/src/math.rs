@@ -0,0 +1,160 @@
+/// Greatest common divisor, `gcd(0, 0)` being 0.
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, e.g. the period after which the tests of the monkeys of day 11 all give
+/// the same results again.
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        0
+    } else {
+        a / gcd(a, b) * b
+    }
+}
+
+/// `base` to the power `exponent`, modulo `modulus`.
+pub fn mod_pow(base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let modulus = u128::from(modulus);
+    let mut base = u128::from(base) % modulus;
+    let mut result = 1 % modulus;
+    while exponent > 0 {
+        if exponent % 2 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exponent /= 2;
+    }
+    result as u64
+}
+
+/// Solves the congruences `x ≡ residue (mod modulus)` with the Chinese remainder theorem,
+/// returning the smallest solution with the modulus of all of them, their least common multiple,
+/// or `None` when they contradict each other. The moduli need not be coprime.
+pub fn crt(congruences: impl IntoIterator<Item = (u64, u64)>) -> Option<(u64, u64)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |(x, modulus), (residue, other_modulus)| {
+            let (x, modulus) = (i128::from(x), i128::from(modulus));
+            let (residue, other_modulus) = (i128::from(residue), i128::from(other_modulus));
+            // x + modulus * k ≡ residue (mod other_modulus), with modulus * inverse ≡ gcd.
+            let (gcd, inverse, _) = extended_gcd(modulus, other_modulus);
+            let difference = residue - x;
+            if difference % gcd != 0 {
+                return None;
+            }
+            let step = other_modulus / gcd;
+            let k = (difference / gcd % step * inverse).rem_euclid(step);
+            let combined = modulus * step;
+            let x = (x + modulus * k).rem_euclid(combined);
+            Some((u64::try_from(x).ok()?, u64::try_from(combined).ok()?))
+        })
+}
+
+/// First value of `low..=high` for which `predicate` holds, given that it does not hold before
+/// and holds after, e.g. the smallest parameter of a search that is enough. `None` when it does not
+/// hold at `high`. Takes a logarithmic number of steps over any range, even all of `i64`.
+pub fn binary_search_by_predicate(
+    mut low: i64,
+    mut high: i64,
+    mut predicate: impl FnMut(i64) -> bool,
+) -> Option<i64> {
+    if low > high || !predicate(high) {
+        return None;
+    }
+    while low < high {
+        // The average rounded down, without computing `low + high` nor `high - low`.
+        let middle = (low & high) + ((low ^ high) >> 1);
+        if predicate(middle) {
+            high = middle;
+        } else {
+            low = middle + 1;
+        }
+    }
+    Some(low)
+}
+
+/// `(gcd, s, t)` such that `a * s + b * t = gcd`.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (gcd, s, t) = extended_gcd(b, a % b);
+        (gcd, t, s - a / b * t)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn divisors_and_multiples() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(7, 0), 7);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!([23, 19, 13, 17].into_iter().fold(1, lcm), 96577);
+    }
+
+    #[test]
+    fn modular_exponentiation() {
+        assert_eq!(mod_pow(2, 10, 1000), 24);
+        assert_eq!(mod_pow(3, 0, 7), 1);
+        assert_eq!(mod_pow(5, 3, 1), 0);
+        assert_eq!(mod_pow(u64::MAX, u64::MAX, u64::MAX - 1), 1);
+    }
+
+    #[test]
+    fn chinese_remainders() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([]), Some((0, 1)));
+    }
+
+    #[test]
+    fn crt_solutions_satisfy_every_congruence() {
+        let congruences = [(5, 12), (11, 18), (3, 7), (0, 5)];
+
+        let (x, modulus) = crt(congruences).unwrap();
+
+        assert_eq!(modulus, 1260);
+        for (residue, other_modulus) in congruences {
+            assert_eq!(x % other_modulus, residue);
+        }
+    }
+
+    #[test]
+    fn binary_searches_find_where_predicates_flip() {
+        assert_eq!(
+            binary_search_by_predicate(0, 100, |x| x * x >= 2000),
+            Some(45)
+        );
+        assert_eq!(binary_search_by_predicate(-50, -10, |x| x > -37), Some(-36));
+        assert_eq!(binary_search_by_predicate(3, 7, |_| true), Some(3));
+        assert_eq!(binary_search_by_predicate(3, 7, |x| x > 7), None);
+        assert_eq!(binary_search_by_predicate(7, 3, |_| true), None);
+    }
+
+    #[test]
+    fn binary_searches_do_not_overflow() {
+        let mut steps = 0;
+
+        let first = binary_search_by_predicate(i64::MIN, i64::MAX, |x| {
+            steps += 1;
+            x >= i64::MAX - 1
+        });
+
+        assert_eq!(first, Some(i64::MAX - 1));
+        assert!(steps <= 65);
+        assert_eq!(
+            binary_search_by_predicate(i64::MIN, i64::MAX, |_| true),
+            Some(i64::MIN)
+        );
+    }
+}
@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A function whose results are kept by argument, built by `memoize`.
+pub struct Memoized<K, V, F> {
+    cache: HashMap<K, V>,
+    function: F,
+}
+
+/// Caches the results of a recursive `function`, which gets a callback to call itself through the
+/// cache along with its argument, e.g. for the size of a directory adding up its subdirectories:
+///
+/// ```
+/// use aoc_core::memo::memoize;
+///
+/// let mut fibonacci = memoize(|fibonacci, n: u64| match n {
+///     0 | 1 => n,
+///     _ => fibonacci(n - 1) + fibonacci(n - 2),
+/// });
+///
+/// assert_eq!(fibonacci.get(90), 2_880_067_194_370_816_120);
+/// ```
+pub fn memoize<K, V, F>(function: F) -> Memoized<K, V, F>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+    F: Fn(&mut dyn FnMut(K) -> V, K) -> V,
+{
+    Memoized {
+        cache: HashMap::new(),
+        function,
+    }
+}
+
+impl<K, V, F> Memoized<K, V, F>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+    F: Fn(&mut dyn FnMut(K) -> V, K) -> V,
+{
+    /// Reserves room for the results of `capacity` arguments, for searches whose size is known.
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.cache.reserve(capacity);
+        self
+    }
+
+    /// The result for `key`, computed on the first call only.
+    pub fn get(&mut self, key: K) -> V {
+        Self::lookup(&mut self.cache, &self.function, key)
+    }
+
+    /// Number of results kept.
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    fn lookup(cache: &mut HashMap<K, V>, function: &F, key: K) -> V {
+        if let Some(value) = cache.get(&key) {
+            return value.clone();
+        }
+        let value = function(&mut |key| Self::lookup(cache, function, key), key.clone());
+        cache.insert(key, value.clone());
+        value
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn each_argument_is_computed_once() {
+        let calls = Cell::new(0);
+        let mut paths = memoize(|paths, (rows, columns): (u32, u32)| {
+            calls.set(calls.get() + 1);
+            if rows == 0 || columns == 0 {
+                1_u64
+            } else {
+                paths((rows - 1, columns)) + paths((rows, columns - 1))
+            }
+        })
+        .with_capacity(256);
+
+        assert_eq!(paths.get((16, 16)), 601_080_390);
+        assert_eq!(paths.get((10, 10)), 184_756);
+        assert_eq!(paths.len(), 17 * 17 - 1);
+        assert_eq!(calls.get(), paths.len());
+    }
+}
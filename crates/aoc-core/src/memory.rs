@@ -0,0 +1,90 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// Global allocator counting the allocated bytes, to report the peak memory of the parts.
+///
+/// ```
+/// # use aoc_core::memory::TrackingAllocator;
+/// #[global_allocator]
+/// static ALLOCATOR: TrackingAllocator = TrackingAllocator;
+/// # fn main() {}
+/// ```
+pub struct TrackingAllocator;
+
+impl TrackingAllocator {
+    fn allocated(size: usize) {
+        let allocated = ALLOCATED.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(allocated, Ordering::Relaxed);
+    }
+
+    fn deallocated(size: usize) {
+        ALLOCATED.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc(layout);
+        if !pointer.is_null() {
+            Self::allocated(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc_zeroed(layout);
+        if !pointer.is_null() {
+            Self::allocated(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        System.dealloc(pointer, layout);
+        Self::deallocated(layout.size());
+    }
+
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_pointer = System.realloc(pointer, layout, new_size);
+        if !new_pointer.is_null() {
+            Self::deallocated(layout.size());
+            Self::allocated(new_size);
+        }
+        new_pointer
+    }
+}
+
+/// Runs `f` and returns its result with the peak of the bytes allocated while it ran, above the
+/// bytes allocated before. The peak is unknown when the `TrackingAllocator` is not installed.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<usize>) {
+    let baseline = ALLOCATED.load(Ordering::Relaxed);
+    PEAK.store(baseline, Ordering::Relaxed);
+    let result = f();
+    let peak = PEAK.load(Ordering::Relaxed);
+    (
+        result,
+        (baseline > 0).then(|| peak.saturating_sub(baseline)),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: TrackingAllocator = TrackingAllocator;
+
+    #[test]
+    fn peak_includes_freed_allocations() {
+        let (result, peak) = measure(|| {
+            let buffer = vec![1u8; 1 << 20];
+            buffer.iter().map(|&byte| byte as usize).sum::<usize>()
+        });
+
+        assert_eq!(result, 1 << 20);
+        assert!(peak.is_some_and(|peak| peak >= 1 << 20));
+    }
+}
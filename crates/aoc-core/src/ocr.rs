@@ -0,0 +1,108 @@
+/// Height of the letters, in pixels.
+pub const LETTER_HEIGHT: usize = 6;
+/// Width of the letters, in pixels, without the dark column after each of them.
+pub const LETTER_WIDTH: usize = 4;
+
+/// The letters of the screens of the puzzles, row by row.
+const ALPHABET: [(char, [&str; LETTER_HEIGHT]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...", "#...", ".#.#", "..#.", "..#.", "..#."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Reads the capital letters drawn by the lit pixels, `#`, of a screen such as the CRT of day 10:
+/// each letter is `LETTER_WIDTH` pixels wide and `LETTER_HEIGHT` high, with a column of dark
+/// pixels after it, any other character being dark. Returns `None` when the screen does not have
+/// `LETTER_HEIGHT` rows or shows something else than letters.
+pub fn read_letters(screen: &str) -> Option<String> {
+    let rows = screen
+        .trim_matches('\n')
+        .lines()
+        .map(|row| row.chars().map(|pixel| pixel == '#').collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    if rows.len() != LETTER_HEIGHT {
+        return None;
+    }
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let is_lit = |row: usize, column: usize| rows[row].get(column).copied().unwrap_or(false);
+    let letters = (0..width)
+        .step_by(LETTER_WIDTH + 1)
+        .map(|start| {
+            ALPHABET
+                .iter()
+                .find(|(_, glyph)| {
+                    glyph.iter().enumerate().all(|(row, pixels)| {
+                        pixels
+                            .bytes()
+                            .chain([b'.'])
+                            .enumerate()
+                            .all(|(offset, pixel)| is_lit(row, start + offset) == (pixel == b'#'))
+                    })
+                })
+                .map(|(letter, _)| *letter)
+        })
+        .collect::<Option<String>>()?;
+    (!letters.is_empty()).then_some(letters)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn letters_of_a_screen() {
+        let screen = "
+#### #  #   ## #### ###    ## #### ####
+   # # #     # #    #  #    # #       #
+  #  ##      # ###  ###     # ###    #
+ #   # #     # #    #  #    # #     #
+#    # #  #  # #    #  # #  # #    #
+#### #  #  ##  #    ###   ##  #    ####
+";
+
+        assert_eq!(read_letters(screen), Some("ZKJFBJFZ".to_string()));
+    }
+
+    #[test]
+    fn every_letter_is_read_back() {
+        let screen = (0..LETTER_HEIGHT)
+            .map(|row| {
+                ALPHABET
+                    .iter()
+                    .map(|(_, glyph)| format!("{}.", glyph[row]))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert_eq!(
+            read_letters(&screen),
+            Some("ABCEFGHIJKLOPRSUYZ".to_string())
+        );
+    }
+
+    #[test]
+    fn other_pictures_are_not_letters() {
+        let stripes =
+            "##  ##  ##  \n###   ###   \n####    ####\n#####     ##\n######      \n#######     ";
+
+        assert_eq!(read_letters(stripes), None);
+        assert_eq!(read_letters("####\n#...\n###."), None);
+        assert_eq!(read_letters(""), None);
+    }
+}
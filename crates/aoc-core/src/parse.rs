@@ -0,0 +1,96 @@
+use nom::bytes::complete::take_till1;
+use nom::character::complete::{anychar, digit1, one_of};
+use nom::combinator::{map, map_res, opt, recognize};
+use nom::sequence::pair;
+use nom::IResult;
+
+pub use aoc_macros::parse_line;
+pub use nom;
+
+/// A value read in place of a `{Type}` placeholder of `parse_line!`, e.g. the operation of a
+/// monkey of day 11.
+pub trait Field: Sized {
+    fn parse_field(input: &str) -> IResult<&str, Self>;
+}
+
+macro_rules! unsigned_fields {
+    ($($integer:ty),*) => {
+        $(
+            impl Field for $integer {
+                fn parse_field(input: &str) -> IResult<&str, Self> {
+                    map_res(digit1, str::parse)(input)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! signed_fields {
+    ($($integer:ty),*) => {
+        $(
+            impl Field for $integer {
+                fn parse_field(input: &str) -> IResult<&str, Self> {
+                    map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse)(input)
+                }
+            }
+        )*
+    };
+}
+
+unsigned_fields!(u8, u16, u32, u64, u128, usize);
+signed_fields!(i8, i16, i32, i64, i128, isize);
+
+impl Field for char {
+    fn parse_field(input: &str) -> IResult<&str, Self> {
+        anychar(input)
+    }
+}
+
+/// A word, up to the next whitespace.
+impl Field for String {
+    fn parse_field(input: &str) -> IResult<&str, Self> {
+        map(take_till1(char::is_whitespace), String::from)(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn placeholders_are_parsed_by_type() {
+        let sensor =
+            parse_line!("Sensor at x={isize}, y={isize}: closest beacon is at x={i64}, y={i64}");
+
+        assert_eq!(
+            sensor("Sensor at x=2, y=-18: closest beacon is at x=-2, y=15"),
+            Ok(("", (2, -18, -2, 15))),
+        );
+        assert!(sensor("Sensor at x=2, y=18").is_err());
+        assert!(sensor("Sensor at x=a, y=18: closest beacon is at x=-2, y=15").is_err());
+    }
+
+    #[test]
+    fn single_placeholders_are_not_wrapped() {
+        let monkey = parse_line!("Monkey {usize}:");
+
+        assert_eq!(
+            monkey("Monkey 3:\n  Starting items"),
+            Ok(("\n  Starting items", 3))
+        );
+        assert!(parse_line!("Test: divisible by {u8}")("Test: divisible by 256").is_err());
+    }
+
+    #[test]
+    fn words_chars_and_braces() {
+        assert_eq!(
+            parse_line!("{u64} {String}")("14848514 b.txt"),
+            Ok(("", (14848514, "b.txt".to_string()))),
+        );
+        assert_eq!(
+            parse_line!("{{{char}}} \"{ u32 }\"")("{x} \"7\""),
+            Ok(("", ('x', 7))),
+        );
+        assert_eq!(parse_line!("noop")("noop"), Ok(("", ())));
+    }
+}
@@ -0,0 +1,344 @@
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+use crate::collections::MinHeap;
+
+/// A shortest path to a goal, e.g. the climb of day 12.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Path<N> {
+    /// Sum of the costs of the steps, their number for a breadth-first search.
+    pub cost: usize,
+    /// The nodes from the start to the goal, both included.
+    pub nodes: Vec<N>,
+}
+
+/// Nodes met by a search, stored once and then referred to by their index.
+struct Explored<N> {
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+    costs: Vec<usize>,
+    parents: Vec<Option<usize>>,
+}
+
+impl<N: Eq + Hash + Clone> Explored<N> {
+    fn new(start: N) -> Self {
+        Self {
+            nodes: vec![start.clone()],
+            indices: [(start, 0)].into(),
+            costs: vec![0],
+            parents: vec![None],
+        }
+    }
+
+    /// Records a way to reach `node` from the node at `parent`, returning the index of `node`
+    /// when this way is cheaper than the ones known.
+    fn reach(&mut self, node: N, parent: usize, cost: usize) -> Option<usize> {
+        match self.indices.get(&node) {
+            Some(&index) if self.costs[index] <= cost => None,
+            Some(&index) => {
+                self.costs[index] = cost;
+                self.parents[index] = Some(parent);
+                Some(index)
+            }
+            None => {
+                let index = self.nodes.len();
+                self.indices.insert(node.clone(), index);
+                self.nodes.push(node);
+                self.costs.push(cost);
+                self.parents.push(Some(parent));
+                Some(index)
+            }
+        }
+    }
+
+    fn path(&self, goal: usize) -> Path<N> {
+        let mut nodes = Vec::new();
+        let mut index = Some(goal);
+        while let Some(current) = index {
+            nodes.push(self.nodes[current].clone());
+            index = self.parents[current];
+        }
+        nodes.reverse();
+        Path {
+            cost: self.costs[goal],
+            nodes,
+        }
+    }
+}
+
+/// Shortest path from `start` to a node matching `is_goal` when every step costs 1, visiting the
+/// nodes in order of distance.
+pub fn bfs<N, S, I>(start: N, mut successors: S, is_goal: impl Fn(&N) -> bool) -> Option<Path<N>>
+where
+    N: Eq + Hash + Clone,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut explored = Explored::new(start);
+    let mut queue = VecDeque::from([0]);
+    while let Some(index) = queue.pop_front() {
+        if is_goal(&explored.nodes[index]) {
+            return Some(explored.path(index));
+        }
+        let cost = explored.costs[index] + 1;
+        for successor in successors(&explored.nodes[index]) {
+            if !explored.indices.contains_key(&successor) {
+                queue.extend(explored.reach(successor, index, cost));
+            }
+        }
+    }
+    None
+}
+
+/// Shortest path from `start` to a node matching `is_goal`, `successors` giving the nodes reached
+/// from a node with the cost of each step.
+pub fn dijkstra<N, S, I>(start: N, successors: S, is_goal: impl Fn(&N) -> bool) -> Option<Path<N>>
+where
+    N: Eq + Hash + Clone,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    astar(start, successors, |_| 0, is_goal)
+}
+
+/// Shortest path like `dijkstra`, expanding first the nodes whose cost plus the `heuristic`
+/// estimate of the rest of the path is the lowest. The heuristic must never overestimate the cost
+/// of a step, as the Manhattan distance on a grid.
+pub fn astar<N, S, I, H>(
+    start: N,
+    mut successors: S,
+    heuristic: H,
+    is_goal: impl Fn(&N) -> bool,
+) -> Option<Path<N>>
+where
+    N: Eq + Hash + Clone,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, usize)>,
+    H: Fn(&N) -> usize,
+{
+    // Ties on the estimates go to the nodes reached at the lowest cost.
+    let mut queue = MinHeap::new();
+    queue.push(0, (heuristic(&start), 0));
+    let mut explored = Explored::new(start);
+    while let Some((index, (_, cost))) = queue.pop() {
+        if is_goal(&explored.nodes[index]) {
+            return Some(explored.path(index));
+        }
+        for (successor, step) in successors(&explored.nodes[index]) {
+            let estimate = heuristic(&successor);
+            if let Some(reached) = explored.reach(successor, index, cost + step) {
+                queue.push(reached, (cost + step + estimate, cost + step));
+            }
+        }
+    }
+    None
+}
+
+/// The nodes ordered so that each comes after the nodes it depends on, e.g. the monkeys of day 21
+/// after the monkeys whose numbers they wait for, with Kahn's algorithm. The dependencies given by
+/// `dependencies` join the order even when they are not among `nodes`, and the nodes that are
+/// ready together keep the order in which they were met.
+///
+/// Fails with the nodes of a cycle, each depending on the next one and the last on the first,
+/// when there is one.
+pub fn toposort<N, D, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut dependencies: D,
+) -> Result<Vec<N>, Vec<N>>
+where
+    N: Eq + Hash + Clone,
+    D: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut all = Vec::new();
+    let mut indices = HashMap::new();
+    let mut index_of = |node: N, all: &mut Vec<N>| {
+        *indices.entry(node.clone()).or_insert_with(|| {
+            all.push(node);
+            all.len() - 1
+        })
+    };
+    for node in nodes {
+        index_of(node, &mut all);
+    }
+    // Indices of the nodes each node depends on, all nodes being met once the loop is over.
+    let mut requirements = Vec::new();
+    while requirements.len() < all.len() {
+        let mut required = dependencies(&all[requirements.len()])
+            .into_iter()
+            .map(|dependency| index_of(dependency, &mut all))
+            .collect::<Vec<_>>();
+        required.sort_unstable();
+        required.dedup();
+        requirements.push(required);
+    }
+
+    let mut dependents = vec![Vec::new(); all.len()];
+    for (node, required) in requirements.iter().enumerate() {
+        for &dependency in required {
+            dependents[dependency].push(node);
+        }
+    }
+    let mut waiting = requirements.iter().map(Vec::len).collect::<Vec<_>>();
+    let mut ready = (0..all.len())
+        .filter(|&node| waiting[node] == 0)
+        .collect::<VecDeque<_>>();
+    let mut order = Vec::with_capacity(all.len());
+    while let Some(node) = ready.pop_front() {
+        order.push(node);
+        for &dependent in &dependents[node] {
+            waiting[dependent] -= 1;
+            if waiting[dependent] == 0 {
+                ready.push_back(dependent);
+            }
+        }
+    }
+
+    if order.len() < all.len() {
+        // Each node left waits for another node left, so following them leads around a cycle.
+        let is_left = |node: &usize| waiting[*node] > 0;
+        let mut node = (0..all.len()).find(is_left).unwrap();
+        let mut walk = Vec::new();
+        let mut steps = HashMap::new();
+        while let Entry::Vacant(step) = steps.entry(node) {
+            step.insert(walk.len());
+            walk.push(node);
+            node = *requirements[node]
+                .iter()
+                .find(|node| is_left(node))
+                .unwrap();
+        }
+        return Err(walk[steps[&node]..]
+            .iter()
+            .map(|&node| all[node].clone())
+            .collect());
+    }
+    Ok(order.into_iter().map(|node| all[node].clone()).collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::geometry::GridPos;
+
+    /// The free positions of a grid whose walls are `#`.
+    fn maze_successors<'a>(maze: &'a [&str]) -> impl Fn(&GridPos) -> Vec<GridPos> + 'a {
+        |position| {
+            position
+                .neighbors(maze.len(), maze[0].len())
+                .filter(|neighbor| maze[neighbor.row].as_bytes()[neighbor.column] != b'#')
+                .collect()
+        }
+    }
+
+    const MAZE: [&str; 4] = ["..#.", ".##.", "....", "#.#."];
+
+    #[test]
+    fn bfs_finds_the_shortest_path() {
+        let goal = GridPos::new(0, 3);
+
+        let path = bfs(GridPos::new(0, 0), maze_successors(&MAZE), |&p| p == goal).unwrap();
+
+        assert_eq!(path.cost, 7);
+        assert_eq!(path.nodes.len(), 8);
+        assert_eq!(path.nodes.first(), Some(&GridPos::new(0, 0)));
+        assert_eq!(path.nodes.last(), Some(&goal));
+    }
+
+    #[test]
+    fn unreachable_goals_have_no_path() {
+        let goal = GridPos::new(3, 0);
+
+        assert_eq!(
+            bfs(GridPos::new(0, 0), maze_successors(&MAZE), |&p| p == goal),
+            None
+        );
+    }
+
+    #[test]
+    fn dijkstra_takes_the_cheapest_path_rather_than_the_shortest() {
+        let edges = HashMap::from([
+            ('a', vec![('b', 7), ('c', 2)]),
+            ('c', vec![('d', 2)]),
+            ('d', vec![('b', 1)]),
+        ]);
+        let successors = |node: &char| edges.get(node).cloned().unwrap_or_default();
+
+        let path = dijkstra('a', successors, |&node| node == 'b').unwrap();
+
+        assert_eq!(
+            path,
+            Path {
+                cost: 5,
+                nodes: vec!['a', 'c', 'd', 'b'],
+            }
+        );
+    }
+
+    #[test]
+    fn astar_agrees_with_dijkstra() {
+        let goal = GridPos::new(3, 3);
+        let successors = |position: &GridPos| {
+            maze_successors(&MAZE)(position)
+                .into_iter()
+                .map(|neighbor| (neighbor, 1))
+                .collect::<Vec<_>>()
+        };
+        let manhattan = |position: &GridPos| {
+            position.row.abs_diff(goal.row) + position.column.abs_diff(goal.column)
+        };
+
+        let path = astar(GridPos::new(0, 0), successors, manhattan, |&p| p == goal).unwrap();
+
+        assert_eq!(path.cost, 6);
+        assert_eq!(
+            dijkstra(GridPos::new(0, 0), successors, |&p| p == goal).map(|path| path.cost),
+            Some(6)
+        );
+    }
+
+    #[test]
+    fn toposorts_put_dependencies_first() {
+        let monkeys = HashMap::from([
+            ("root", vec!["pppw", "sjmn"]),
+            ("pppw", vec!["cczh", "lfqf"]),
+            ("cczh", vec!["sllz", "lgvd"]),
+            ("lgvd", vec!["ljgn", "ptdq"]),
+            ("ptdq", vec!["humn", "dvpt"]),
+            ("sjmn", vec!["drzm", "dbpl"]),
+            ("drzm", vec!["hmdt", "zczc"]),
+        ]);
+
+        let order = toposort(["root"], |monkey| {
+            monkeys.get(monkey).cloned().unwrap_or_default()
+        })
+        .unwrap();
+
+        assert_eq!(order.len(), 15);
+        assert_eq!(order.last(), Some(&"root"));
+        for (monkey, waited) in &monkeys {
+            let position = order.iter().position(|m| m == monkey).unwrap();
+            for other in waited {
+                assert!(order[..position].contains(other), "{other} before {monkey}");
+            }
+        }
+    }
+
+    #[test]
+    fn ready_nodes_keep_their_order() {
+        let order = toposort([3, 1, 2, 0], |&n| if n == 0 { vec![] } else { vec![0] });
+
+        assert_eq!(order, Ok(vec![0, 3, 1, 2]));
+    }
+
+    #[test]
+    fn toposorts_report_cycles() {
+        let edges = HashMap::from([('a', vec!['b']), ('b', vec!['c']), ('c', vec!['d', 'b'])]);
+
+        let cycle = toposort(['a'], |node| edges.get(node).cloned().unwrap_or_default());
+
+        assert_eq!(cycle, Err(vec!['b', 'c']));
+        assert_eq!(toposort(['x'], |&x| [x]), Err(vec!['x']));
+    }
+}
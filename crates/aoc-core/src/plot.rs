@@ -0,0 +1,138 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use crate::geometry::{Bounds, Planar};
+
+/// Size of the maps printed in a terminal, in characters.
+pub const TERMINAL_WIDTH: usize = 100;
+pub const TERMINAL_HEIGHT: usize = 40;
+
+/// Map of `points`, `#`, at most `width` characters wide and `height` high, each character
+/// standing for a block of positions when the points spread further, e.g. the positions visited
+/// by the tail of the rope of day 9 or the sand of day 14. The axes are labeled with the first
+/// and the last rows and columns. Empty when there are no points.
+pub fn plot<P: Planar>(points: impl IntoIterator<Item = P>, width: usize, height: usize) -> String {
+    let points = points.into_iter().collect::<Vec<_>>();
+    let Some(bounds) = Bounds::of(points.iter().copied()) else {
+        return String::new();
+    };
+    let columns_per_cell = bounds.width().div_ceil(width.max(1));
+    let rows_per_cell = bounds.height().div_ceil(height.max(1));
+    let cells = points
+        .iter()
+        .map(|point| {
+            let (column, row) = point.axes();
+            (
+                column.abs_diff(bounds.min.0) / columns_per_cell,
+                row.abs_diff(bounds.min.1) / rows_per_cell,
+            )
+        })
+        .collect::<HashSet<_>>();
+    let plot_width = bounds.width().div_ceil(columns_per_cell);
+
+    let mut rows = (0..bounds.height().div_ceil(rows_per_cell)).collect::<Vec<_>>();
+    let (mut top, mut bottom) = (bounds.min.1, bounds.max.1);
+    if !P::ROWS_GROW_DOWN {
+        rows.reverse();
+        (top, bottom) = (bottom, top);
+    }
+    let (top, bottom) = (top.to_string(), bottom.to_string());
+    let margin = top.len().max(bottom.len());
+
+    let mut picture = String::new();
+    if columns_per_cell > 1 || rows_per_cell > 1 {
+        writeln!(
+            picture,
+            "{:margin$}  1 character for {columns_per_cell}×{rows_per_cell} positions",
+            ""
+        )
+        .unwrap();
+    }
+    for (index, row) in rows.iter().enumerate() {
+        let label = if index == 0 {
+            &top
+        } else if index == rows.len() - 1 {
+            &bottom
+        } else {
+            ""
+        };
+        let line = (0..plot_width)
+            .map(|column| {
+                if cells.contains(&(column, *row)) {
+                    '#'
+                } else {
+                    ' '
+                }
+            })
+            .collect::<String>();
+        writeln!(picture, "{label:>margin$} |{}", line.trim_end()).unwrap();
+    }
+    writeln!(picture, "{:margin$} +{}", "", "-".repeat(plot_width)).unwrap();
+    let (left, right) = (bounds.min.0.to_string(), bounds.max.0.to_string());
+    if bounds.width() == 1 {
+        writeln!(picture, "{:margin$}  {left}", "").unwrap();
+    } else {
+        let gap = plot_width.saturating_sub(left.len() + right.len()).max(1);
+        writeln!(picture, "{:margin$}  {left}{:gap$}{right}", "", "").unwrap();
+    }
+    picture
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::geometry::{GridPos, Vec2};
+
+    #[test]
+    fn points_at_full_scale() {
+        let points = HashSet::from([Vec2::new(0, 0), Vec2::new(4, 2), Vec2::new(-2, 1)]);
+
+        assert_eq!(
+            plot(points, 80, 20),
+            "\
+2 |      #
+  |#
+0 |  #
+  +-------
+   -2    4
+"
+        );
+    }
+
+    #[test]
+    fn grid_rows_go_down() {
+        let points = [GridPos::new(3, 10), GridPos::new(5, 12)];
+
+        assert_eq!(
+            plot(points, 80, 20),
+            "\
+3 |#
+  |
+5 |  #
+  +---
+   10 12
+"
+        );
+    }
+
+    #[test]
+    fn spread_points_are_scaled_down() {
+        let points = (0..100).map(|x| Vec2::new(x, x / 10));
+
+        let picture = plot(points, 20, 5);
+
+        assert_eq!(
+            picture.lines().next(),
+            Some("   1 character for 5×2 positions")
+        );
+        assert_eq!(picture.lines().nth(1), Some("9 |                ####"));
+        assert_eq!(picture.lines().nth(5), Some("0 |####"));
+        assert_eq!(picture.lines().last(), Some("   0                 99"));
+    }
+
+    #[test]
+    fn nothing_to_plot() {
+        assert_eq!(plot(Vec::<Vec2>::new(), 10, 10), "");
+        assert_eq!(plot([Vec2::new(7, -3)], 10, 10), "-3 |#\n   +-\n    7\n");
+    }
+}
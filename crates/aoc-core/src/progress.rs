@@ -0,0 +1,62 @@
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+/// Receives the progress of the long loops of the solvers, e.g. the 10 000 rounds of day 11, for the
+/// runner to show a progress bar. Every method does nothing by default.
+pub trait ProgressSink: Send + Sync + Debug {
+    /// Starts a loop of `total` steps, or of an unknown number of steps.
+    fn start(&self, _label: &str, _total: Option<u64>) {}
+
+    fn advance(&self, _steps: u64) {}
+
+    fn finish(&self) {}
+}
+
+/// Sink of the solvers run without a progress bar, e.g. by the tests.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoProgress;
+
+impl ProgressSink for NoProgress {}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Arc<dyn ProgressSink>>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` on this thread with the sink returned by `current`.
+pub fn with_sink<R>(sink: Option<Arc<dyn ProgressSink>>, f: impl FnOnce() -> R) -> R {
+    let previous = CURRENT.replace(sink);
+    let result = f();
+    CURRENT.set(previous);
+    result
+}
+
+/// The sink of the part running on this thread, which the parts pass to their solvers.
+pub fn current() -> Arc<dyn ProgressSink> {
+    CURRENT.with_borrow(|sink| sink.clone().unwrap_or_else(|| Arc::new(NoProgress)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Debug, Default)]
+    struct Steps(Mutex<u64>);
+
+    impl ProgressSink for Steps {
+        fn advance(&self, steps: u64) {
+            *self.0.lock().unwrap() += steps;
+        }
+    }
+
+    #[test]
+    fn parts_report_to_the_sink_they_run_with() {
+        let steps = Arc::new(Steps::default());
+
+        with_sink(Some(steps.clone()), || current().advance(3));
+        current().advance(2);
+
+        assert_eq!(*steps.0.lock().unwrap(), 3);
+    }
+}
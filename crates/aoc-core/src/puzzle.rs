@@ -0,0 +1,143 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use crate::input::{self, InputSource};
+
+/// File of the workspace holding the metadata of the puzzles, fetched with their inputs.
+const PUZZLES: &str = "puzzles.toml";
+
+/// Titles of the puzzles, e.g.
+///
+/// ```toml
+/// [title]
+/// 8 = "Treetop Tree House"
+/// ```
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Puzzles {
+    #[serde(default)]
+    title: BTreeMap<String, String>,
+}
+
+impl Puzzles {
+    pub fn path() -> PathBuf {
+        input::workspace_dir().join(PUZZLES)
+    }
+
+    pub fn load() -> Result<Self, String> {
+        let path = Self::path();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(&path)
+            .map_err(|error| format!("Cannot read {}: {error}", path.display()))?;
+        toml::from_str(&content)
+            .map_err(|error| format!("Invalid puzzles file {}: {error}", path.display()))
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = Self::path();
+        let content = toml::to_string(self)
+            .map_err(|error| format!("Cannot serialize the puzzles: {error}"))?;
+        fs::write(&path, content)
+            .map_err(|error| format!("Cannot write {}: {error}", path.display()))
+    }
+
+    pub fn title(&self, day: u8) -> Option<&str> {
+        self.title.get(&day.to_string()).map(String::as_str)
+    }
+
+    pub fn set_title(&mut self, day: u8, title: String) {
+        self.title.insert(day.to_string(), title);
+    }
+}
+
+/// Title of a puzzle from `puzzles.toml`, loaded once.
+pub fn title(day: u8) -> Option<&'static str> {
+    static PUZZLES: OnceLock<Puzzles> = OnceLock::new();
+    PUZZLES
+        .get_or_init(|| {
+            Puzzles::load().unwrap_or_else(|error| {
+                tracing::warn!("{error}");
+                Puzzles::default()
+            })
+        })
+        .title(day)
+}
+
+/// Downloads the page of the puzzle whose input is downloaded from `source` and extracts its title.
+/// Nothing is fetched unless `source` is the URL of a puzzle input, e.g.
+/// `https://adventofcode.com/2022/day/8/input`.
+pub fn fetch_title(source: &InputSource) -> Result<Option<String>, String> {
+    let Some(page) = page_source(source) else {
+        return Ok(None);
+    };
+    let page = page.read()?;
+    parse_title(&String::from_utf8_lossy(&page))
+        .map(Some)
+        .ok_or_else(|| format!("No title in the page of {source}"))
+}
+
+fn page_source(source: &InputSource) -> Option<InputSource> {
+    let InputSource::Url { url, session } = source else {
+        return None;
+    };
+    url.strip_suffix("/input").map(|page| InputSource::Url {
+        url: page.to_string(),
+        session: session.clone(),
+    })
+}
+
+/// Extracts the title from the page of a puzzle, whose heading reads `--- Day 8: Treetop Tree House ---`.
+pub fn parse_title(page: &str) -> Option<String> {
+    let heading = page
+        .split_once("<h2>--- Day ")?
+        .1
+        .split_once(" ---</h2>")?
+        .0;
+    let (_, title) = heading.split_once(": ")?;
+    Some(title.trim().to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn title_of_puzzle_page() {
+        let page = r#"<main><article class="day-desc"><h2>--- Day 8: Treetop Tree House ---</h2><p>The expedition"#;
+
+        assert_eq!(parse_title(page), Some("Treetop Tree House".to_string()));
+        assert_eq!(parse_title("<h2>Advent of Code</h2>"), None);
+    }
+
+    #[test]
+    fn page_of_puzzle_input() {
+        let input = InputSource::Url {
+            url: "https://adventofcode.com/2022/day/8/input".to_string(),
+            session: Some("abc".to_string()),
+        };
+
+        assert!(matches!(
+            page_source(&input),
+            Some(InputSource::Url { url, session })
+                if url == "https://adventofcode.com/2022/day/8" && session.as_deref() == Some("abc")
+        ));
+        assert!(page_source(&InputSource::Stdin).is_none());
+    }
+
+    #[test]
+    fn titles_are_stored_by_day() {
+        let mut puzzles = Puzzles::default();
+        puzzles.set_title(8, "Treetop Tree House".to_string());
+
+        let content = toml::to_string(&puzzles).unwrap();
+        let puzzles = toml::from_str::<Puzzles>(&content).unwrap();
+
+        assert_eq!(content, "[title]\n8 = \"Treetop Tree House\"\n");
+        assert_eq!(puzzles.title(8), Some("Treetop Tree House"));
+        assert_eq!(puzzles.title(9), None);
+    }
+}
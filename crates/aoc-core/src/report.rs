@@ -0,0 +1,875 @@
+use serde::{Deserialize, Serialize, Serializer};
+use std::borrow::Cow;
+use std::cmp::Reverse;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use crate::input::ParseError;
+use crate::style::Color;
+use crate::{Answer, Part};
+
+/// Number of parts listed in the summary of a multi-day run.
+const SLOWEST_PARTS: usize = 3;
+
+#[derive(Debug, Serialize)]
+pub struct PartReport {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    /// What the answer is, e.g. `Highest scenic score`.
+    pub label: String,
+    /// Missing when the part failed or was aborted after the time budget.
+    pub answer: Option<Answer>,
+    /// Error of a part that failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// The error is a panic of the solver, e.g. an `unwrap` on an input of another format.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub panicked: bool,
+    /// Known answer of the part when it gave another one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<Answer>,
+    #[serde(rename = "duration_us", serialize_with = "serialize_micros")]
+    pub duration: Duration,
+    /// Exceeded the time budget given with `--max-time`.
+    pub slow: bool,
+    /// Peak of the bytes allocated by the part, when the runner tracks the allocations.
+    #[serde(rename = "peak_memory_bytes")]
+    pub peak_memory: Option<usize>,
+}
+
+/// Report of an extra part of a day, serialized like a `PartReport` with its name as part.
+#[derive(Debug, Serialize)]
+pub struct ExtraReport {
+    pub year: u16,
+    pub day: u8,
+    #[serde(rename = "part")]
+    pub name: &'static str,
+    pub label: &'static str,
+    /// Missing when the part failed or was cancelled.
+    pub answer: Option<Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(rename = "duration_us", serialize_with = "serialize_micros")]
+    pub duration: Duration,
+}
+
+/// Entry of the JSON output of a run.
+#[derive(Serialize)]
+#[serde(untagged)]
+enum JsonEntry {
+    Part(PartReport),
+    Extra(ExtraReport),
+}
+
+fn serialize_micros<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_micros())
+}
+
+#[derive(Debug)]
+pub struct DayReport {
+    pub year: u16,
+    pub day: u8,
+    /// Title of the puzzle, empty when unknown.
+    pub title: &'static str,
+    /// Duration of `Solution::parse`, when the input was parsed before the parts.
+    pub parse: Option<Duration>,
+    pub parts: Vec<PartReport>,
+    /// Extra parts run instead of the parts of the puzzle.
+    pub extras: Vec<ExtraReport>,
+    pub stats: Vec<String>,
+    pub phases: Vec<Phase>,
+    /// Lines of the input that the parsing skipped.
+    pub skipped: Vec<ParseError>,
+    pub duration: Duration,
+}
+
+/// Duration of a named stage inside a day, e.g. parsing the input.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Phase {
+    pub name: &'static str,
+    pub duration: Duration,
+}
+
+#[derive(Debug, Default)]
+pub struct PhaseTimer {
+    phases: Vec<Phase>,
+}
+
+impl PhaseTimer {
+    pub fn time<T>(&mut self, name: &'static str, phase: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let result = phase();
+        self.phases.push(Phase {
+            name,
+            duration: start.elapsed(),
+        });
+        result
+    }
+
+    pub fn finish(self) -> Vec<Phase> {
+        self.phases
+    }
+}
+
+/// Stopwatch of a day, capturing the duration of each part with nanosecond precision.
+#[derive(Debug)]
+pub struct Timings {
+    start: Instant,
+    parts: Vec<(Part, Duration)>,
+}
+
+impl Timings {
+    pub fn start() -> Self {
+        Self {
+            start: Instant::now(),
+            parts: Vec::new(),
+        }
+    }
+
+    /// Runs a part and records its duration under the label of that part.
+    pub fn time<T>(&mut self, part: Part, run: impl FnOnce() -> T) -> (T, Duration) {
+        let start = Instant::now();
+        let result = run();
+        let duration = start.elapsed();
+        self.parts.push((part, duration));
+        (result, duration)
+    }
+
+    pub fn parts(&self) -> &[(Part, Duration)] {
+        &self.parts
+    }
+
+    /// Time elapsed since the start of the day.
+    pub fn total(&self) -> Duration {
+        self.start.elapsed()
+    }
+}
+
+/// Formats a duration with the unit suited to its magnitude, e.g. `850.3µs`, `12.35ms` or `1.20s`.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000_000 {
+        format!("{:.1}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", duration.as_secs_f64())
+    }
+}
+
+/// Formats a number of bytes with binary units, e.g. `512 B`, `12.3 KiB` or `4.0 MiB`.
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+#[derive(Debug)]
+pub struct Reporter {
+    format: Format,
+    mask_answers: bool,
+    reports: Vec<DayReport>,
+}
+
+impl Reporter {
+    pub fn new(format: Format, mask_answers: bool) -> Self {
+        Self {
+            format,
+            mask_answers,
+            reports: Vec::new(),
+        }
+    }
+
+    pub fn report(&mut self, report: DayReport) {
+        if self.format == Format::Text {
+            if !self.reports.is_empty() {
+                println!();
+            }
+            self.print_text(&report);
+        }
+        self.reports.push(report);
+    }
+
+    pub fn finish(&self, total_duration: Duration) {
+        match self.format {
+            Format::Text => {
+                if self.reports.len() > 1 {
+                    println!();
+                    for line in self.summary(total_duration) {
+                        println!("{line}");
+                    }
+                }
+                let slow_parts = self.slow_parts();
+                if !slow_parts.is_empty() {
+                    println!("{}: {}", Color::Red.paint("SLOW"), slow_parts.join(", "));
+                }
+                let failed_parts = self.failed_parts();
+                if !failed_parts.is_empty() {
+                    println!("{}: {}", Color::Red.paint("ERROR"), failed_parts.join(", "));
+                }
+                let wrong_parts = self.wrong_parts();
+                if !wrong_parts.is_empty() {
+                    println!("{}: {}", Color::Red.paint("WRONG"), wrong_parts.join(", "));
+                }
+            }
+            Format::Json => self.print_json(),
+        }
+    }
+
+    fn print_text(&self, report: &DayReport) {
+        let day = report.day;
+        if !report.title.is_empty() {
+            println!("{day}:title — {}", report.title);
+        }
+        if let Some(parse) = report.parse {
+            println!("Parsed in {}", format_duration(parse));
+        }
+        for part in &report.parts {
+            println!("{day}:{} — {}", part.part, self.text_answer(part));
+            let memory = part.peak_memory.map_or(String::new(), |bytes| {
+                format!(", {} peak", format_bytes(bytes))
+            });
+            if part.slow {
+                println!(
+                    "Part {} in {}{memory} — {}",
+                    part.part,
+                    format_duration(part.duration),
+                    Color::Red.paint("SLOW")
+                );
+            } else {
+                println!(
+                    "Part {} in {}{memory}",
+                    part.part,
+                    format_duration(part.duration)
+                );
+            }
+        }
+        for extra in &report.extras {
+            let answer = match (&extra.answer, &extra.error) {
+                (Some(answer), _) => self.labeled_answer(extra.label, answer),
+                (None, Some(error)) => format!("{} {error}", Color::Red.paint("ERROR")),
+                (None, None) => "aborted".to_string(),
+            };
+            println!("{day}:{} — {answer}", extra.name);
+            println!(
+                "Extra part {} in {}",
+                extra.name,
+                format_duration(extra.duration)
+            );
+        }
+        for stat in &report.stats {
+            println!("{day}:stats — {}", self.display_stat(stat));
+        }
+        for phase in &report.phases {
+            println!(
+                "{day}:phase {} — {}",
+                phase.name,
+                format_duration(phase.duration)
+            );
+        }
+        println!("Done in {}", format_duration(report.duration));
+    }
+
+    fn print_json(&self) {
+        let parts = self
+            .reports
+            .iter()
+            .flat_map(|report| &report.parts)
+            .map(|part| {
+                JsonEntry::Part(PartReport {
+                    year: part.year,
+                    day: part.day,
+                    part: part.part,
+                    label: part.label.clone(),
+                    answer: part
+                        .answer
+                        .as_ref()
+                        .map(|answer| self.display_answer(answer).into_owned()),
+                    error: part.error.clone(),
+                    panicked: part.panicked,
+                    expected: part
+                        .expected
+                        .as_ref()
+                        .map(|expected| self.display_answer(expected).into_owned()),
+                    duration: part.duration,
+                    slow: part.slow,
+                    peak_memory: part.peak_memory,
+                })
+            });
+        let extras = self
+            .reports
+            .iter()
+            .flat_map(|report| &report.extras)
+            .map(|extra| {
+                JsonEntry::Extra(ExtraReport {
+                    year: extra.year,
+                    day: extra.day,
+                    name: extra.name,
+                    label: extra.label,
+                    answer: extra
+                        .answer
+                        .as_ref()
+                        .map(|answer| self.display_answer(answer).into_owned()),
+                    error: extra.error.clone(),
+                    duration: extra.duration,
+                })
+            });
+        let entries = parts.chain(extras).collect::<Vec<_>>();
+        println!("{}", serde_json::to_string_pretty(&entries).unwrap());
+    }
+
+    /// Summary of a multi-day run: total time, slowest parts and share of the time of each day.
+    fn summary(&self, total_duration: Duration) -> Vec<String> {
+        let mut parts = self
+            .reports
+            .iter()
+            .flat_map(|report| &report.parts)
+            .collect::<Vec<_>>();
+        parts.sort_by_key(|part| Reverse(part.duration));
+        let slowest = parts
+            .iter()
+            .take(SLOWEST_PARTS)
+            .map(|part| {
+                format!(
+                    "{}:{} in {}",
+                    part.day,
+                    part.part,
+                    format_duration(part.duration)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let days_duration = self
+            .reports
+            .iter()
+            .map(|report| report.duration)
+            .sum::<Duration>()
+            .max(Duration::from_nanos(1));
+        let shares = self
+            .reports
+            .iter()
+            .map(|report| {
+                format!(
+                    "{} {:.1}%",
+                    report.day,
+                    100.0 * report.duration.as_secs_f64() / days_duration.as_secs_f64()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        vec![
+            format!("All days done in {}", format_duration(total_duration)),
+            format!("Slowest parts: {slowest}"),
+            format!("Time per day: {shares}"),
+        ]
+    }
+
+    pub fn has_slow_parts(&self) -> bool {
+        !self.slow_parts().is_empty()
+    }
+
+    /// Whether a part failed with an error rather than giving an answer.
+    pub fn has_failed_parts(&self) -> bool {
+        !self.failed_parts().is_empty()
+    }
+
+    /// Whether a part gave another answer than the one its solution expects.
+    pub fn has_wrong_parts(&self) -> bool {
+        !self.wrong_parts().is_empty()
+    }
+
+    fn wrong_parts(&self) -> Vec<String> {
+        self.reports
+            .iter()
+            .flat_map(|report| &report.parts)
+            .filter(|part| part.expected.is_some())
+            .map(|part| format!("{}:{}", part.day, part.part))
+            .collect()
+    }
+
+    fn failed_parts(&self) -> Vec<String> {
+        let parts = self
+            .reports
+            .iter()
+            .flat_map(|report| &report.parts)
+            .filter(|part| part.error.is_some())
+            .map(|part| format!("{}:{}", part.day, part.part));
+        let extras = self
+            .reports
+            .iter()
+            .flat_map(|report| &report.extras)
+            .filter(|extra| extra.error.is_some())
+            .map(|extra| format!("{}:{}", extra.day, extra.name));
+        parts.chain(extras).collect()
+    }
+
+    fn slow_parts(&self) -> Vec<String> {
+        self.reports
+            .iter()
+            .flat_map(|report| &report.parts)
+            .filter(|part| part.slow)
+            .map(|part| format!("{}:{}", part.day, part.part))
+            .collect()
+    }
+
+    pub fn write_markdown(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, self.markdown_table())
+            .map_err(|error| format!("Cannot write {}: {error}", path.display()))
+    }
+
+    fn markdown_table(&self) -> String {
+        let mut table = String::from(
+            "| Day | Title | Part one | Time | Part two | Time |\n|----:|-------|----------|-----:|----------|-----:|\n",
+        );
+        for report in &self.reports {
+            let mut cells = vec![report.day.to_string(), report.title.replace('|', "\\|")];
+            for part in Part::ALL {
+                match report.parts.iter().find(|report| report.part == part) {
+                    Some(part) => {
+                        cells.push(match (&part.answer, &part.error) {
+                            (Some(answer), _) => markdown_cell(&self.display_answer(answer)),
+                            (None, Some(_)) if part.panicked => "FAILED".to_string(),
+                            (None, Some(_)) => "ERROR".to_string(),
+                            (None, None) => "aborted".to_string(),
+                        });
+                        if part.slow {
+                            cells.push(format!("{} (SLOW)", format_duration(part.duration)));
+                        } else {
+                            cells.push(format_duration(part.duration));
+                        }
+                    }
+                    None => cells.extend([String::new(), String::new()]),
+                }
+            }
+            table.push_str(&format!("| {} |\n", cells.join(" | ")));
+        }
+        table
+    }
+
+    fn display_answer<'a>(&self, answer: &'a Answer) -> Cow<'a, Answer> {
+        if self.mask_answers {
+            Cow::Owned(Answer::Text("***".to_string()))
+        } else {
+            Cow::Borrowed(answer)
+        }
+    }
+
+    /// A stat with what follows its label masked like the answers, which stats often give away
+    /// (e.g. the picture drawn by day 10).
+    fn display_stat<'a>(&self, stat: &'a str) -> Cow<'a, str> {
+        match stat.split_once(':') {
+            _ if !self.mask_answers => Cow::Borrowed(stat),
+            Some((label, _)) => Cow::Owned(format!("{label}: ***")),
+            None => Cow::Borrowed("***"),
+        }
+    }
+
+    /// An answer after its label, highlighted.
+    fn labeled_answer(&self, label: &str, answer: &Answer) -> String {
+        let answer = self.display_answer(answer);
+        let separator = if answer.is_multiline() { "\n" } else { " " };
+        format!("{label}:{separator}{}", Color::Cyan.paint(answer.as_ref()))
+    }
+
+    /// The answer of a part after its label, highlighted, or why it is missing.
+    fn text_answer(&self, part: &PartReport) -> String {
+        match (&part.answer, &part.error) {
+            (Some(answer), _) => {
+                let wrong = part.expected.as_ref().map_or(String::new(), |expected| {
+                    let expected = self.display_answer(expected);
+                    let separator = if expected.is_multiline() { "\n" } else { " " };
+                    format!(
+                        " — {}, expected{separator}{expected}",
+                        Color::Red.paint("WRONG")
+                    )
+                });
+                format!("{}{wrong}", self.labeled_answer(&part.label, answer))
+            }
+            (None, Some(error)) if part.panicked => {
+                format!("{} {error}", Color::Red.paint("FAILED"))
+            }
+            (None, Some(error)) => format!("{} {error}", Color::Red.paint("ERROR")),
+            (None, None) => format!("aborted after {}", format_duration(part.duration)),
+        }
+    }
+}
+
+/// Outcome of a part compared with a reference answer, e.g. of `answers.toml` or of a sample.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    /// No reference answer to compare with.
+    Missing,
+    Error(String),
+    /// Cancelled or out of time before answering.
+    Aborted,
+}
+
+impl Verdict {
+    /// Compares the answer of a part with its reference answer, when there is one.
+    pub fn of(part: &PartReport, expected: Option<&str>) -> Self {
+        match (&part.answer, &part.error) {
+            (Some(answer), _) => Self::of_answer(answer, expected),
+            (None, Some(error)) => Self::Error(error.clone()),
+            (None, None) => Self::Aborted,
+        }
+    }
+
+    pub fn of_answer(answer: &Answer, expected: Option<&str>) -> Self {
+        match expected {
+            Some(expected) if answer.matches(expected) => Self::Pass,
+            Some(expected) => Self::Fail {
+                expected: expected.trim().to_string(),
+                actual: answer.to_string(),
+            },
+            None => Self::Missing,
+        }
+    }
+
+    /// The line of the verdict on a part, after what the part was compared with when it is not
+    /// the answers file, e.g. `9:2 — large example FAIL, expected 36 but got 1`.
+    pub fn line(&self, part: &PartReport, reference: Option<&str>) -> String {
+        let reference = reference.map_or(String::new(), |reference| format!("{reference} "));
+        format!("{}:{} — {reference}{self}", part.day, part.part)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pass => write!(f, "{}", Color::Green.paint("PASS")),
+            Self::Fail { expected, actual } => write!(
+                f,
+                "{}, expected {expected} but got {actual}",
+                Color::Red.paint("FAIL")
+            ),
+            Self::Missing => write!(f, "{}, no expected answer", Color::Yellow.paint("SKIP")),
+            Self::Error(error) => write!(f, "{} {error}", Color::Red.paint("ERROR")),
+            Self::Aborted => write!(f, "{} aborted", Color::Red.paint("ERROR")),
+        }
+    }
+}
+
+/// Line of the answer of a part given by one of the implementations of its day, for `compare`.
+pub fn implementation_line(name: &str, part: &PartReport) -> String {
+    let answer = match (&part.answer, &part.error) {
+        (Some(answer), _) => format!("{answer} in {}", format_duration(part.duration)),
+        (None, Some(error)) => format!("{} {error}", Color::Red.paint("ERROR")),
+        (None, None) => format!("aborted after {}", format_duration(part.duration)),
+    };
+    format!("{}:{} — {name}: {answer}", part.day, part.part)
+}
+
+fn markdown_cell(answer: &Answer) -> String {
+    let answer = answer.to_string().replace('|', "\\|");
+    if answer.contains('\n') {
+        format!("<pre>{}</pre>", answer.replace('\n', "<br>"))
+    } else {
+        answer
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn masked_answers_keep_their_label() {
+        let reporter = Reporter::new(Format::Text, true);
+        let report = day_report(10, &["13140", "##|#\n#..#"]);
+
+        assert_eq!(reporter.text_answer(&report.parts[0]), "Part 1: ***");
+        assert_eq!(reporter.text_answer(&report.parts[1]), "Part 2: ***");
+    }
+
+    #[test]
+    fn masked_stats_keep_their_label() {
+        let reporter = Reporter::new(Format::Text, true);
+
+        assert_eq!(
+            reporter.display_stat("Picture drawn on a 20x12 CRT:\n##..\n#..#"),
+            "Picture drawn on a 20x12 CRT: ***"
+        );
+        assert_eq!(
+            reporter.display_stat("Deepest packet: 5 nested lists"),
+            "Deepest packet: ***"
+        );
+        assert_eq!(reporter.display_stat("42 rounds"), "***");
+        assert_eq!(
+            Reporter::new(Format::Text, false).display_stat("Deepest packet: 5 nested lists"),
+            "Deepest packet: 5 nested lists"
+        );
+    }
+
+    #[test]
+    fn grids_start_on_their_own_line() {
+        let reporter = Reporter::new(Format::Text, false);
+        let report = day_report(10, &["13140", "##|#\n#..#"]);
+
+        assert_eq!(
+            reporter.text_answer(&report.parts[1]),
+            "Part 2:\n##|#\n#..#"
+        );
+    }
+
+    fn day_report(day: u8, answers: &[&str]) -> DayReport {
+        DayReport {
+            year: 2022,
+            day,
+            title: "",
+            parse: None,
+            parts: Part::ALL
+                .iter()
+                .zip(answers)
+                .map(|(&part, answer)| PartReport {
+                    year: 2022,
+                    day,
+                    part,
+                    label: format!("Part {part}"),
+                    answer: Some(match answer.parse() {
+                        Ok(answer) => Answer::Unsigned(answer),
+                        Err(_) if answer.contains('\n') => Answer::grid(answer),
+                        Err(_) => Answer::from(*answer),
+                    }),
+                    error: None,
+                    panicked: false,
+                    expected: None,
+                    duration: Duration::from_millis(day as u64),
+                    slow: false,
+                    peak_memory: None,
+                })
+                .collect(),
+            extras: Vec::new(),
+            stats: Vec::new(),
+            phases: Vec::new(),
+            skipped: Vec::new(),
+            duration: Duration::from_millis(2 * day as u64),
+        }
+    }
+
+    #[test]
+    fn markdown_table() {
+        let mut reporter = Reporter::new(Format::Json, false);
+        reporter.report(DayReport {
+            title: "Calorie Counting",
+            ..day_report(1, &["68467", "203420"])
+        });
+        reporter.report(day_report(10, &["13140", "##|#\n#..#"]));
+        reporter.report(day_report(11, &["10605"]));
+
+        let result = reporter.markdown_table();
+
+        assert_eq!(
+            result,
+            "| Day | Title | Part one | Time | Part two | Time |
+|----:|-------|----------|-----:|----------|-----:|
+| 1 | Calorie Counting | 68467 | 1.00ms | 203420 | 1.00ms |
+| 10 |  | 13140 | 10.00ms | <pre>##\\|#<br>#..#</pre> | 10.00ms |
+| 11 |  | 10605 | 11.00ms |  |  |
+",
+        );
+    }
+
+    #[test]
+    fn markdown_table_with_masked_answers() {
+        let mut reporter = Reporter::new(Format::Text, true);
+        reporter.reports.push(day_report(1, &["68467", "203420"]));
+
+        let result = reporter.markdown_table();
+
+        assert!(result.ends_with("| 1 |  | *** | 1.00ms | *** | 1.00ms |\n"));
+    }
+
+    #[test]
+    fn slow_parts_are_marked() {
+        let mut reporter = Reporter::new(Format::Json, false);
+        let mut report = day_report(15, &["26", "56000011"]);
+        report.parts[1].slow = true;
+        reporter.report(report);
+
+        let result = reporter.markdown_table();
+
+        assert!(reporter.has_slow_parts());
+        assert!(result.ends_with("| 15 |  | 26 | 15.00ms | 56000011 | 15.00ms (SLOW) |\n"));
+    }
+
+    #[test]
+    fn summary_ranks_slowest_parts() {
+        let mut reporter = Reporter::new(Format::Json, false);
+        for day in [1, 15, 11, 3] {
+            reporter.report(day_report(day, &["1", "2"]));
+        }
+        reporter.reports[1].parts[1].duration = Duration::from_millis(25);
+
+        let result = reporter.summary(Duration::from_millis(70));
+
+        assert_eq!(
+            result,
+            vec![
+                "All days done in 70.00ms",
+                "Slowest parts: 15:2 in 25.00ms, 15:1 in 15.00ms, 11:1 in 11.00ms",
+                "Time per day: 1 3.3%, 15 50.0%, 11 36.7%, 3 10.0%",
+            ],
+        );
+    }
+
+    #[test]
+    fn phase_timer_records_phases_in_order() {
+        let mut timer = PhaseTimer::default();
+
+        let result = timer.time("parse", || 21) * timer.time("solve", || 2);
+        let phases = timer.finish();
+
+        assert_eq!(result, 42);
+        assert_eq!(
+            phases.iter().map(|phase| phase.name).collect::<Vec<_>>(),
+            vec!["parse", "solve"],
+        );
+    }
+
+    #[test]
+    fn timings_are_labeled_with_their_part() {
+        let mut timings = Timings::start();
+
+        let (one, _) = timings.time(Part::One, || 1);
+        let (two, _) = timings.time(Part::Two, || 2);
+
+        assert_eq!((one, two), (1, 2));
+        assert_eq!(
+            timings
+                .parts()
+                .iter()
+                .map(|&(part, _)| part)
+                .collect::<Vec<_>>(),
+            vec![Part::One, Part::Two],
+        );
+        assert!(timings.total() >= timings.parts().iter().map(|&(_, duration)| duration).sum());
+    }
+
+    #[test]
+    fn durations_use_adaptive_units() {
+        assert_eq!(format_duration(Duration::from_nanos(850_340)), "850.3µs");
+        assert_eq!(format_duration(Duration::from_nanos(12_345_678)), "12.35ms");
+        assert_eq!(format_duration(Duration::from_millis(1_204)), "1.20s");
+    }
+
+    #[test]
+    fn bytes_use_binary_units() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(12_595), "12.3 KiB");
+        assert_eq!(format_bytes(4 << 20), "4.0 MiB");
+    }
+
+    #[test]
+    fn part_report_to_json() {
+        let report = PartReport {
+            year: 2022,
+            day: 9,
+            part: Part::Two,
+            label: "Number of positions".to_string(),
+            answer: Some(Answer::Unsigned(36)),
+            error: None,
+            panicked: false,
+            expected: None,
+            duration: Duration::from_nanos(1_234_567),
+            slow: false,
+            peak_memory: Some(2048),
+        };
+
+        let result = serde_json::to_string(&report).unwrap();
+
+        assert_eq!(
+            result,
+            r#"{"year":2022,"day":9,"part":2,"label":"Number of positions","answer":36,"duration_us":1234,"slow":false,"peak_memory_bytes":2048}"#
+        );
+    }
+
+    #[test]
+    fn extra_parts_are_reported_by_name() {
+        let mut reporter = Reporter::new(Format::Json, true);
+        let mut report = day_report(11, &[]);
+        report.extras.push(ExtraReport {
+            year: 2022,
+            day: 11,
+            name: "inspections",
+            label: "Inspections",
+            answer: None,
+            error: Some("No monkey".to_string()),
+            duration: Duration::from_micros(12),
+        });
+        reporter.report(report);
+
+        assert_eq!(reporter.failed_parts(), vec!["11:inspections"]);
+        assert_eq!(
+            serde_json::to_string(&reporter.reports[0].extras[0]).unwrap(),
+            r#"{"year":2022,"day":11,"part":"inspections","label":"Inspections","answer":null,"error":"No monkey","duration_us":12}"#
+        );
+    }
+
+    #[test]
+    fn verdicts_compare_the_answers() {
+        let report = day_report(9, &["13", "1"]);
+        let pass = Verdict::of(&report.parts[0], Some("13\n"));
+        let fail = Verdict::of(&report.parts[1], Some("36"));
+
+        assert_eq!(pass.line(&report.parts[0], None), "9:1 — PASS");
+        assert_eq!(
+            fail.line(&report.parts[1], Some("large example")),
+            "9:2 — large example FAIL, expected 36 but got 1"
+        );
+        assert_eq!(Verdict::of(&report.parts[0], None), Verdict::Missing);
+    }
+
+    #[test]
+    fn wrong_parts_are_listed() {
+        let mut reporter = Reporter::new(Format::Text, false);
+        let mut report = day_report(4, &["477", "831"]);
+        report.parts[1].expected = Some(Answer::Unsigned(830));
+        reporter.report(report);
+
+        assert!(reporter.has_wrong_parts());
+        assert_eq!(reporter.wrong_parts(), vec!["4:2"]);
+        assert!(reporter
+            .text_answer(&reporter.reports[0].parts[1])
+            .ends_with(" expected 830"));
+    }
+
+    #[test]
+    fn failed_parts_are_listed() {
+        let mut reporter = Reporter::new(Format::Json, false);
+        let mut report = day_report(12, &["31", "0"]);
+        report.parts[1].answer = None;
+        report.parts[1].error = Some("No square of elevation a".to_string());
+        reporter.report(report);
+
+        assert!(reporter.has_failed_parts());
+        assert_eq!(reporter.failed_parts(), vec!["12:2"]);
+        assert!(reporter
+            .markdown_table()
+            .ends_with("| 12 |  | 31 | 12.00ms | ERROR | 12.00ms |\n"));
+    }
+}
@@ -0,0 +1,194 @@
+use std::collections::BTreeMap;
+
+/// A world evolving tick by tick, e.g. the sand falling in the cave of day 14 or the CPU of day 10.
+pub trait Simulation {
+    /// What `restore` needs to bring the world back to a tick, e.g. the sand at rest.
+    type Snapshot: Clone;
+
+    /// Advances the world by one tick.
+    fn step(&mut self);
+
+    /// Whether the world stopped evolving, after which `Driver` does not step it anymore.
+    fn is_done(&self) -> bool;
+
+    fn snapshot(&self) -> Self::Snapshot;
+
+    fn restore(&mut self, snapshot: Self::Snapshot);
+}
+
+/// Runs a simulation and counts its ticks, keeping snapshots to rewind it when asked to, e.g. for
+/// a visualization going back and forth.
+pub struct Driver<S: Simulation> {
+    simulation: S,
+    ticks: usize,
+    snapshot_interval: Option<usize>,
+    snapshots: BTreeMap<usize, S::Snapshot>,
+}
+
+impl<S: Simulation> Driver<S> {
+    pub fn new(simulation: S) -> Self {
+        Self {
+            simulation,
+            ticks: 0,
+            snapshot_interval: None,
+            snapshots: BTreeMap::new(),
+        }
+    }
+
+    /// Keeps a snapshot every `interval` ticks, starting with the current one, for `rewind`.
+    pub fn with_snapshots(mut self, interval: usize) -> Self {
+        self.snapshot_interval = Some(interval.max(1));
+        self.keep_snapshot();
+        self
+    }
+
+    pub fn simulation(&self) -> &S {
+        &self.simulation
+    }
+
+    pub fn into_simulation(self) -> S {
+        self.simulation
+    }
+
+    /// Number of ticks from the start.
+    pub fn ticks(&self) -> usize {
+        self.ticks
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.simulation.is_done()
+    }
+
+    /// Advances by one tick, returning `false` when the simulation was already done.
+    pub fn step(&mut self) -> bool {
+        if self.simulation.is_done() {
+            return false;
+        }
+        self.simulation.step();
+        self.ticks += 1;
+        self.keep_snapshot();
+        true
+    }
+
+    /// Advances by `ticks` ticks or until the simulation is done, returning the ticks run.
+    pub fn run(&mut self, ticks: usize) -> usize {
+        (0..ticks).take_while(|_| self.step()).count()
+    }
+
+    /// Advances until `condition` holds, returning `false` when the simulation is done first.
+    pub fn run_until(&mut self, mut condition: impl FnMut(&S) -> bool) -> bool {
+        while !condition(&self.simulation) {
+            if !self.step() {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Advances until the simulation is done, returning the ticks run.
+    pub fn run_to_end(&mut self) -> usize {
+        self.run(usize::MAX)
+    }
+
+    /// Brings the simulation back to `tick` from the last snapshot before it, returning `false`
+    /// when there is none or the simulation ends before that tick.
+    pub fn rewind(&mut self, tick: usize) -> bool {
+        let Some((&snapshot_tick, snapshot)) = self.snapshots.range(..=tick).next_back() else {
+            return false;
+        };
+        self.simulation.restore(snapshot.clone());
+        self.ticks = snapshot_tick;
+        self.run(tick - snapshot_tick);
+        self.ticks == tick
+    }
+
+    fn keep_snapshot(&mut self) {
+        if let Some(interval) = self.snapshot_interval {
+            if self.ticks.is_multiple_of(interval) {
+                self.snapshots
+                    .entry(self.ticks)
+                    .or_insert_with(|| self.simulation.snapshot());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Counts up to a limit, with its history to tell the ticks apart.
+    struct Counter {
+        values: Vec<u32>,
+        limit: u32,
+    }
+
+    impl Counter {
+        fn new(limit: u32) -> Self {
+            Self {
+                values: vec![0],
+                limit,
+            }
+        }
+
+        fn value(&self) -> u32 {
+            *self.values.last().unwrap()
+        }
+    }
+
+    impl Simulation for Counter {
+        type Snapshot = Vec<u32>;
+
+        fn step(&mut self) {
+            self.values.push(self.value() + 1);
+        }
+
+        fn is_done(&self) -> bool {
+            self.value() == self.limit
+        }
+
+        fn snapshot(&self) -> Self::Snapshot {
+            self.values.clone()
+        }
+
+        fn restore(&mut self, snapshot: Self::Snapshot) {
+            self.values = snapshot;
+        }
+    }
+
+    #[test]
+    fn runs_stop_when_the_simulation_is_done() {
+        let mut driver = Driver::new(Counter::new(10));
+
+        assert_eq!(driver.run(4), 4);
+        assert!(driver.run_until(|counter| counter.value() == 7));
+        assert_eq!(driver.run_to_end(), 3);
+        assert!(!driver.run_until(|counter| counter.value() == 20));
+        assert!(!driver.step());
+        assert_eq!(driver.ticks(), 10);
+        assert!(driver.is_done());
+    }
+
+    #[test]
+    fn rewinds_replay_from_the_last_snapshot() {
+        let mut driver = Driver::new(Counter::new(100)).with_snapshots(10);
+        driver.run(57);
+
+        assert!(driver.rewind(23));
+        assert_eq!(driver.ticks(), 23);
+        assert_eq!(driver.simulation().values, (0..=23).collect::<Vec<_>>());
+        assert!(driver.rewind(40));
+        assert_eq!(driver.simulation().value(), 40);
+        assert!(!driver.rewind(150));
+        assert_eq!(driver.into_simulation().value(), 100);
+    }
+
+    #[test]
+    fn rewinds_need_snapshots() {
+        let mut driver = Driver::new(Counter::new(100));
+        driver.run(5);
+
+        assert!(!driver.rewind(2));
+        assert_eq!(driver.ticks(), 5);
+    }
+}
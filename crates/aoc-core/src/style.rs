@@ -0,0 +1,84 @@
+use std::env;
+use std::fmt::{self, Display, Formatter};
+use std::io::{self, IsTerminal};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Clears the terminal and moves the cursor to its top left corner.
+pub const CLEAR_SCREEN: &str = "\x1b[2J\x1b[1;1H";
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Enables the colors when stdout is a terminal, unless `no_color` is set or the `NO_COLOR`
+/// environment variable is not empty.
+pub fn init(no_color: bool) {
+    let no_color_env = env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+    set_enabled(!no_color && !no_color_env && io::stdout().is_terminal());
+}
+
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl Color {
+    fn code(self) -> u8 {
+        match self {
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+            Color::White => 37,
+        }
+    }
+
+    /// Displays `value` in this color when the colors are enabled.
+    pub fn paint<T: Display>(self, value: T) -> Painted<T> {
+        Painted { color: self, value }
+    }
+}
+
+#[derive(Debug)]
+pub struct Painted<T> {
+    color: Color,
+    value: T,
+}
+
+impl<T: Display> Display for Painted<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if enabled() {
+            write!(f, "\x1b[{}m{}\x1b[0m", self.color.code(), self.value)
+        } else {
+            self.value.fmt(f)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn paint_only_when_enabled() {
+        set_enabled(true);
+        let colored = Color::Green.paint("PASS").to_string();
+        set_enabled(false);
+        let plain = Color::Green.paint("PASS").to_string();
+
+        assert_eq!(colored, "\x1b[32mPASS\x1b[0m");
+        assert_eq!(plain, "PASS");
+    }
+}
@@ -0,0 +1,50 @@
+use aoc_core::input::InputSource;
+use aoc_core::Part;
+use aoc_days_2022::solutions;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn days(c: &mut Criterion) {
+    let mut group = c.benchmark_group("days");
+    group.sample_size(10);
+    for solution in solutions().values() {
+        // The days whose input is not in the inputs directory yet.
+        if solution.validate().is_err() {
+            continue;
+        }
+        for part in Part::ALL {
+            group.bench_function(format!("day{} part{part}", solution.day()), |b| {
+                b.iter(|| solution.part(part))
+            });
+        }
+    }
+    group.finish();
+}
+
+/// Each implementation of the days having several of them, on the first sample of the day.
+fn implementations(c: &mut Criterion) {
+    let mut group = c.benchmark_group("implementations");
+    for solution in solutions().values() {
+        let implementations = solution.implementations();
+        let Some(example) = solution.examples().into_iter().next() else {
+            continue;
+        };
+        if implementations.len() < 2 {
+            continue;
+        }
+        let sample = solution.with_input(InputSource::from(example.input));
+        for name in implementations {
+            let Some(implementation) = sample.with_implementation(name) else {
+                continue;
+            };
+            for part in Part::ALL {
+                group.bench_function(format!("day{} part{part} {name}", solution.day()), |b| {
+                    b.iter(|| implementation.part(part))
+                });
+            }
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, days, implementations);
+criterion_main!(benches);
@@ -0,0 +1,292 @@
+use aoc_core::anonymize::{Scrambler, Scramblers};
+use aoc_core::input::InputSource;
+use aoc_core::{Answer, Part, Solution, SolutionError};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// Includes a sample input from the `examples` directory at the root of the workspace, e.g.
+/// `example_file!("day9-large")`.
+#[allow(unused_macros)]
+macro_rules! example_file {
+    ($($name:tt)+) => {
+        include_bytes!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../examples/",
+            $($name)+,
+            ".txt"
+        ))
+    };
+}
+
+/// Declares the example of a day from `examples/<day>.txt` as `EXAMPLE`, the sample with its
+/// answers as `example()`, and generates the tests checking them through the `solve_part1` and
+/// `solve_part2` functions of the day module. Days whose solve functions take extra arguments give
+/// them in parentheses, e.g. `part1(10) = 26`, and are tested through `parse_input`,
+/// `solve_part_one` and `solve_part_two` instead.
+#[allow(unused_macros)]
+macro_rules! solution_example {
+    ($day:ident, part1 = $part1:expr, part2 = $part2:expr $(,)?) => {
+        solution_example!(@example stringify!($day), $part1, $part2);
+        solution_example!(@tests $part1, $part2);
+    };
+    (
+        $day:ident,
+        part1($($part1_arg:expr),*) = $part1:expr,
+        part2($($part2_arg:expr),*) = $part2:expr $(,)?
+    ) => {
+        solution_example!(@example stringify!($day), $part1, $part2);
+
+        #[cfg(test)]
+        mod solution_tests {
+            use super::*;
+
+            #[test]
+            fn part1_example() {
+                let input = parse_input(EXAMPLE);
+
+                let result = solve_part_one(&input $(, $part1_arg)*);
+                let result = aoc_core::day::answer(result).unwrap();
+
+                assert!(result.matches(&$part1.to_string()), "{result} is not {}", $part1);
+            }
+
+            #[test]
+            fn part2_example() {
+                let input = parse_input(EXAMPLE);
+
+                let result = solve_part_two(&input $(, $part2_arg)*);
+                let result = aoc_core::day::answer(result).unwrap();
+
+                assert!(result.matches(&$part2.to_string()), "{result} is not {}", $part2);
+            }
+        }
+    };
+    (@example $name:expr, $part1:expr, $part2:expr) => {
+        const EXAMPLE: &[u8] = example_file!($name);
+
+        fn example() -> aoc_core::Example {
+            aoc_core::Example {
+                name: None,
+                input: EXAMPLE,
+                answers: aoc_core::ExampleAnswers::new($part1, $part2),
+            }
+        }
+    };
+    (@tests $part1:expr, $part2:expr) => {
+        #[cfg(test)]
+        mod solution_tests {
+            use super::*;
+
+            #[test]
+            fn part1_example() {
+                let result = solve_part1(EXAMPLE).unwrap();
+
+                assert!(result.matches(&$part1.to_string()), "{result} is not {}", $part1);
+            }
+
+            #[test]
+            fn part2_example() {
+                let result = solve_part2(EXAMPLE).unwrap();
+
+                assert!(result.matches(&$part2.to_string()), "{result} is not {}", $part2);
+            }
+        }
+    };
+}
+
+/// Generates the solution of a day from its parse and solve functions, e.g.
+/// `day!(16, "Proboscidea Volcanium", parse_input, solve_part_one, solve_part_two);`: the
+/// solution registered for the runner, its `solve_part1` and `solve_part2` functions and, when
+/// the answers of `examples/day16.txt` are given after the functions (`part1 = 1651, part2 =
+/// 1707`), the example with its tests. They can be followed by the answers of the input
+/// (`expected = (1651_u32, 1707_u32)`), the labels of the parts (`labels = ("Most pressure",
+/// "Most pressure with an elephant")`) and a validation of the input (`validate =
+/// validate_lines::<Valve>`). The solve functions return a value convertible to an `Answer`, or
+/// a `Result` of one. A parse function written `try parse_input` returns a `Result` with a
+/// `ParseError`, which fails the parts and the validation, unless the day gives its own.
+#[allow(unused_macros)]
+macro_rules! day {
+    ($day:literal, $title:literal, try $parse:path, $($rest:tt)*) => {
+        day!(@day [try] $day, $title, $parse, $($rest)*);
+    };
+    ($day:literal, $title:literal, $parse:path, $($rest:tt)*) => {
+        day!(@day [] $day, $title, $parse, $($rest)*);
+    };
+    (
+        @day [$($try:tt)?]
+        $day:literal,
+        $title:literal,
+        $parse:path,
+        $part1:path,
+        $part2:path
+        $(, part1 = $answer1:expr, part2 = $answer2:expr)?
+        $(, expected = ($expected1:expr, $expected2:expr))?
+        $(, labels = ($label1:literal, $label2:literal))?
+        $(, validate = $validate:path)?
+        $(,)?
+    ) => {
+        /// The solution of the day on its input.
+        pub fn solution() -> impl aoc_core::Solution {
+            aoc_core::day::Day::new(
+                $day,
+                $title,
+                $parse,
+                |input| aoc_core::day::answer($part1(day!(@parsed $($try)? input))),
+                |input| aoc_core::day::answer($part2(day!(@parsed $($try)? input))),
+            )
+            $(.with_examples(day!(@examples $answer1)))?
+            $(.with_validation(day!(@validate $try $parse)))?
+            $(.with_expected($expected1, $expected2))?
+            $(.with_labels([$label1, $label2]))?
+            $(.with_validation($validate))?
+        }
+
+        aoc_core::register_solution!(fn solution);
+
+        pub fn solve_part1(
+            input: impl std::io::BufRead,
+        ) -> Result<aoc_core::Answer, aoc_core::SolutionError> {
+            let input = $parse(&aoc_core::input::read_all(input)?);
+            aoc_core::day::answer($part1(day!(@parsed $($try)? &input)))
+        }
+
+        pub fn solve_part2(
+            input: impl std::io::BufRead,
+        ) -> Result<aoc_core::Answer, aoc_core::SolutionError> {
+            let input = $parse(&aoc_core::input::read_all(input)?);
+            aoc_core::day::answer($part2(day!(@parsed $($try)? &input)))
+        }
+
+        $(
+            solution_example!(@example concat!("day", $day), $answer1, $answer2);
+            solution_example!(@tests $answer1, $answer2);
+        )?
+    };
+    (@parsed try $input:expr) => {
+        $input.as_ref().map_err(Clone::clone)?
+    };
+    (@parsed $input:expr) => {
+        $input
+    };
+    (@validate try $parse:path) => {
+        |input| $parse(input).map(drop).map_err(String::from)
+    };
+    (@examples $answer:expr) => {
+        || vec![example()]
+    };
+}
+
+pub mod year2022;
+
+/// The days registered with `aoc_core::register_solution!` in their module, by year and day.
+pub fn solutions() -> BTreeMap<(u16, u8), Arc<dyn Solution>> {
+    aoc_core::registered_solutions()
+}
+
+/// The days of a year by day.
+pub fn solutions_of(year: u16) -> BTreeMap<u8, Arc<dyn Solution>> {
+    solutions()
+        .into_iter()
+        .filter(|&((solution_year, _), _)| solution_year == year)
+        .map(|((_, day), solution)| (day, solution))
+        .collect()
+}
+
+/// The latest year with solved days, run when no year is given.
+pub fn latest_year() -> Option<u16> {
+    solutions().into_keys().map(|(year, _)| year).max()
+}
+
+/// Solves a part of a day on an input, e.g.
+/// `solve(2022, 1, Part::One, &b"1000\n2000\n"[..])`.
+pub fn solve(
+    year: u16,
+    day: u8,
+    part: Part,
+    input: impl Into<InputSource>,
+) -> Result<Answer, SolutionError> {
+    let solution = solutions()
+        .remove(&(year, day))
+        .ok_or_else(|| SolutionError::Unexpected(format!("Day {day} of {year} is not solved")))?;
+    solution.with_input(input.into()).part(part)
+}
+
+/// Scramblers of the days of a year whose input has a format that the generic one would not keep.
+pub fn scramblers(year: u16) -> Scramblers {
+    let mut scramblers = Scramblers::default();
+    if year == 2022 {
+        let days: &[(u8, Scrambler)] = &[
+            #[cfg(feature = "day1")]
+            (1, year2022::day1::anonymize_input),
+            #[cfg(feature = "day2")]
+            (2, year2022::day2::anonymize_input),
+            #[cfg(feature = "day3")]
+            (3, year2022::day3::anonymize_input),
+            #[cfg(feature = "day4")]
+            (4, year2022::day4::anonymize_input),
+            #[cfg(feature = "day9")]
+            (9, year2022::day9::anonymize_input),
+            #[cfg(feature = "day10")]
+            (10, year2022::day10::anonymize_input),
+        ];
+        for &(day, scrambler) in days {
+            scramblers.register(day, scrambler);
+        }
+    }
+    scramblers
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "all-days")]
+    fn every_day_of_2022_is_registered() {
+        assert!(solutions_of(2022)
+            .into_keys()
+            .eq((1..=15).chain([20, 22, 23, 25])));
+        assert_eq!(latest_year(), Some(2022));
+    }
+
+    #[test]
+    #[cfg(feature = "day1")]
+    fn solve_a_part_of_a_day() {
+        assert_eq!(
+            solve(
+                2022,
+                1,
+                Part::Two,
+                &b"1000\n2000\n\n4000\n\n5000\n6000\n"[..]
+            ),
+            Ok(Answer::Unsigned(18000))
+        );
+        assert_eq!(
+            solve(2022, 26, Part::One, &b""[..]),
+            Err(SolutionError::Unexpected(
+                "Day 26 of 2022 is not solved".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "day1")]
+    fn a_day_runs_on_several_inputs_in_one_process() {
+        let day1 = &solutions()[&(2022, 1)];
+
+        let first = day1.with_input(InputSource::from(b"1\n\n2\n".to_vec()));
+        let second = day1.with_input(InputSource::from(b"3\n4\n".to_vec()));
+
+        assert_eq!(first.part_one(), Ok(Answer::Unsigned(2)));
+        assert_eq!(second.part_one(), Ok(Answer::Unsigned(7)));
+    }
+
+    #[test]
+    fn solutions_give_the_sample_answers() {
+        for solution in solutions().values() {
+            for example in solution.examples() {
+                example.assert_answers(solution.as_ref());
+            }
+        }
+    }
+}
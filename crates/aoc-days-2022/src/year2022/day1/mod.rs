@@ -0,0 +1,80 @@
+use aoc_core::anonymize::Rng;
+use aoc_core::input::{read_lines, BlocksExt, ParseError, ParseExt};
+
+day!(
+    1,
+    "Calorie Counting",
+    try parse_input,
+    solve_part_one,
+    solve_part_two,
+    part1 = 24000,
+    part2 = 45000,
+    expected = (68467_u32, 203420_u32),
+    labels = (
+        "Maximum calories held by one Elf",
+        "Sum of top three calories held by Elves"
+    ),
+);
+
+type Calories = Vec<u32>;
+
+fn parse_input(input: &[u8]) -> Result<Vec<Calories>, ParseError> {
+    parse_calories(read_lines(input))
+}
+
+fn solve_part_one(all_calories: &[Calories]) -> u32 {
+    compute_max_calories(all_calories)
+}
+
+fn solve_part_two(all_calories: &[Calories]) -> u32 {
+    compute_top_three_calories(all_calories)
+}
+
+fn parse_calories(lines: impl Iterator<Item = String>) -> Result<Vec<Calories>, ParseError> {
+    lines
+        .blocks()
+        .numbered()
+        .map(|(first_line, block)| block.iter().try_parse().first_line(first_line).collect())
+        .collect()
+}
+
+fn compute_max_calories(all_calories: &[Calories]) -> u32 {
+    all_calories
+        .iter()
+        .map(|calories| calories.iter().sum::<u32>())
+        .max()
+        .unwrap()
+}
+
+fn compute_top_three_calories(all_calories: &[Calories]) -> u32 {
+    let mut calories = all_calories
+        .iter()
+        .map(|calories| calories.iter().sum::<u32>())
+        .collect::<Vec<_>>();
+    calories.sort();
+    calories.reverse();
+    calories.iter().take(3).sum()
+}
+
+/// Shuffles the calories between the Elves, keeping the number of items of each Elf. Lines that
+/// are not calories stay in place.
+pub(crate) fn anonymize_input(input: &[u8], rng: &mut Rng) -> Result<String, String> {
+    let is_calories = |line: &String| line.parse::<u32>().is_ok();
+    let mut lines = read_lines(input)
+        .skip_while(String::is_empty)
+        .collect::<Vec<_>>();
+    let mut values = lines
+        .iter()
+        .filter(|line| is_calories(line))
+        .cloned()
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut values);
+    for (line, value) in lines
+        .iter_mut()
+        .filter(|line| is_calories(line))
+        .zip(values)
+    {
+        *line = value;
+    }
+    Ok(lines.iter().map(|line| format!("{line}\n")).collect())
+}
@@ -0,0 +1,600 @@
+use std::io::BufRead;
+use std::str::FromStr;
+
+use aoc_core::anonymize::Rng;
+use aoc_core::input::{
+    read_all, read_lines, FilterNotEmpty, Input, InputSource, ParseError, ParseExt, SplitParse,
+};
+use aoc_core::ocr;
+use aoc_core::simulation::{Driver, Simulation};
+use aoc_core::{Answer, Example, Params, Part, Solution, SolutionError};
+
+pub struct Day10(Input<Result<Vec<Instruction>, ParseError>>);
+
+aoc_core::register_solution!(Day10);
+
+impl Day10 {
+    pub fn new(input: impl Into<InputSource>) -> Self {
+        Self(Input::new(input, parse_input))
+    }
+}
+
+impl Default for Day10 {
+    fn default() -> Self {
+        Self(Input::for_day(10, parse_input))
+    }
+}
+
+impl Solution for Day10 {
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn with_input(&self, input: InputSource) -> Box<dyn Solution> {
+        Box::new(Self::new(input))
+    }
+
+    fn parse(&mut self) {
+        self.0.parse();
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![example()]
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        self.0.validate_parsed()
+    }
+
+    fn part_one(&self) -> Result<Answer, SolutionError> {
+        Ok(solve_part_one(self.0.try_get_parsed()?).into())
+    }
+
+    fn part_two(&self) -> Result<Answer, SolutionError> {
+        Ok(crt_answer(&solve_part_two(self.0.try_get_parsed()?)))
+    }
+
+    fn expected_part_one(&self) -> Option<Answer> {
+        Some(Answer::Unsigned(12840))
+    }
+
+    fn expected_part_two(&self) -> Option<Answer> {
+        Some(Answer::Text("ZKJFBJFZ".to_string()))
+    }
+
+    fn label(&self, part: Part) -> String {
+        match part {
+            Part::One => "Sum of the six signal strengths",
+            Part::Two => "Letters drawn on CRT",
+        }
+        .to_string()
+    }
+
+    fn stats(&self, params: &Params) -> Vec<String> {
+        let Ok(instructions) = self.0.try_get_parsed() else {
+            return Vec::new();
+        };
+        let optimized = optimize(instructions, &SIGNAL_CYCLES);
+        let equivalence = match check_equivalence(instructions, &optimized, &SIGNAL_CYCLES) {
+            Ok(()) => "equivalent".to_string(),
+            Err(Divergence { cycle, left, right }) => {
+                format!("diverges at cycle {cycle} ({left} instead of {right})")
+            }
+        };
+        let mut stats = vec![format!(
+            "Program optimized for the signal strengths: {} instructions with {} addx, down from {} with {}, {equivalence}",
+            optimized.len(),
+            count_add_x(&optimized),
+            instructions.len(),
+            count_add_x(instructions),
+        )];
+        match params.get("crt").map(parse_crt_size).transpose() {
+            Ok(Some((width, height))) => stats.push(format!(
+                "Picture drawn on a {width}x{height} CRT:\n{}",
+                Cpu::default().execute_and_compute_picture(
+                    &mut instructions.iter().copied(),
+                    width,
+                    height
+                )
+            )),
+            Ok(None) => {}
+            Err(error) => stats.push(error),
+        }
+        stats
+    }
+}
+
+pub fn solve_part1(input: impl BufRead) -> Result<Answer, SolutionError> {
+    Ok(solve_part_one(&parse_input(&read_all(input)?)?).into())
+}
+
+pub fn solve_part2(input: impl BufRead) -> Result<Answer, SolutionError> {
+    Ok(crt_answer(&solve_part_two(&parse_input(&read_all(
+        input,
+    )?)?)))
+}
+
+/// The letters drawn on the CRT, or its picture when it shows something else.
+fn crt_answer(picture: &str) -> Answer {
+    ocr::read_letters(picture).map_or_else(|| Answer::grid(picture), Answer::Text)
+}
+
+fn parse_input(input: &[u8]) -> Result<Vec<Instruction>, ParseError> {
+    read_lines(input).try_parse().collect()
+}
+
+fn solve_part_one(instructions: &[Instruction]) -> i32 {
+    let mut cpu = Cpu::default();
+    sum_six_signal_strengths(&mut cpu, &mut instructions.iter().copied())
+}
+
+fn solve_part_two(instructions: &[Instruction]) -> String {
+    let mut cpu = Cpu::default();
+    cpu.execute_and_compute_picture(&mut instructions.iter().copied(), CRT_WIDTH, CRT_HEIGHT)
+}
+
+const CRT_WIDTH: usize = 40;
+const CRT_HEIGHT: usize = 6;
+
+/// Parses a CRT size such as `40x6`.
+fn parse_crt_size(size: &str) -> Result<(usize, usize), String> {
+    size.split_on('x')
+        .ok()
+        .filter(|&(width, height)| width > 0 && height > 0)
+        .ok_or_else(|| format!("Invalid CRT size: {size}, expected WIDTHxHEIGHT"))
+}
+
+fn sum_six_signal_strengths<I: Iterator<Item = Instruction>>(
+    cpu: &mut Cpu,
+    instructions: &mut I,
+) -> i32 {
+    cpu.execute_and_compute_signal_strength(instructions, 20)
+        + cpu.execute_and_compute_signal_strength(instructions, 40)
+        + cpu.execute_and_compute_signal_strength(instructions, 40)
+        + cpu.execute_and_compute_signal_strength(instructions, 40)
+        + cpu.execute_and_compute_signal_strength(instructions, 40)
+        + cpu.execute_and_compute_signal_strength(instructions, 40)
+}
+
+const SIGNAL_CYCLES: [usize; 6] = [20, 60, 100, 140, 180, 220];
+
+/// Value of the X register during each cycle, starting with the first one.
+fn trace(instructions: &[Instruction], cycles: usize) -> Vec<i32> {
+    let mut driver = Driver::new(Program::new(instructions));
+    (0..cycles)
+        .map(|_| {
+            let x_register = driver.simulation().cpu.x_register;
+            driver.step();
+            x_register
+        })
+        .collect()
+}
+
+/// A program running on the CPU, a cycle at each tick.
+#[derive(Debug)]
+struct Program<'a> {
+    cpu: Cpu,
+    instructions: &'a [Instruction],
+    /// Index of the next instruction to load.
+    next: usize,
+}
+
+impl<'a> Program<'a> {
+    fn new(instructions: &'a [Instruction]) -> Self {
+        Self {
+            cpu: Cpu::default(),
+            instructions,
+            next: 0,
+        }
+    }
+}
+
+impl Simulation for Program<'_> {
+    type Snapshot = (Cpu, usize);
+
+    fn step(&mut self) {
+        if self.cpu.is_idle() {
+            if let Some(&instruction) = self.instructions.get(self.next) {
+                self.cpu.add_instruction(instruction);
+                self.next += 1;
+            }
+        }
+        self.cpu.tick();
+    }
+
+    fn is_done(&self) -> bool {
+        self.cpu.is_idle() && self.next == self.instructions.len()
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        (self.cpu.clone(), self.next)
+    }
+
+    fn restore(&mut self, (cpu, next): Self::Snapshot) {
+        self.cpu = cpu;
+        self.next = next;
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct Divergence {
+    cycle: usize,
+    left: i32,
+    right: i32,
+}
+
+/// Compares the X register of two programs during the sampled cycles (1-based).
+fn check_equivalence(
+    left: &[Instruction],
+    right: &[Instruction],
+    sampled_cycles: &[usize],
+) -> Result<(), Divergence> {
+    let cycles = sampled_cycles.iter().max().copied().unwrap_or(0);
+    let left_trace = trace(left, cycles);
+    let right_trace = trace(right, cycles);
+    match sampled_cycles
+        .iter()
+        .find(|&&cycle| left_trace[cycle - 1] != right_trace[cycle - 1])
+    {
+        Some(&cycle) => Err(Divergence {
+            cycle,
+            left: left_trace[cycle - 1],
+            right: right_trace[cycle - 1],
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Reduces the number of register updates of a program without changing the X register during
+/// the sampled cycles (1-based) nor the duration of the instructions that remain:
+/// - `addx 0` becomes two `noop`;
+/// - `addx a` followed by `addx b` becomes `noop`, `noop`, `addx a+b` when the cycles of the
+///   second instruction are not sampled;
+/// - instructions starting after the last sampled cycle are removed.
+fn optimize(instructions: &[Instruction], sampled_cycles: &[usize]) -> Vec<Instruction> {
+    let last_sampled_cycle = sampled_cycles.iter().max().copied().unwrap_or(0);
+    let mut optimized: Vec<Instruction> = Vec::new();
+    let mut start = 1;
+    let mut last_add_x_start = None;
+
+    for &instruction in instructions {
+        if start > last_sampled_cycle {
+            break;
+        }
+        match instruction {
+            Instruction::AddX(value) => {
+                let merged = match (optimized.last(), last_add_x_start) {
+                    (Some(Instruction::AddX(previous)), Some(previous_start))
+                        if previous_start + 2 == start
+                            && !sampled_cycles.contains(&start)
+                            && !sampled_cycles.contains(&(start + 1)) =>
+                    {
+                        Some(previous + value)
+                    }
+                    _ => None,
+                };
+                let value = match merged {
+                    Some(merged) => {
+                        optimized.pop();
+                        optimized.extend([Instruction::Noop, Instruction::Noop]);
+                        merged
+                    }
+                    None => value,
+                };
+                if value == 0 {
+                    optimized.extend([Instruction::Noop, Instruction::Noop]);
+                    last_add_x_start = None;
+                } else {
+                    optimized.push(Instruction::AddX(value));
+                    last_add_x_start = Some(start);
+                }
+            }
+            Instruction::Noop => {
+                optimized.push(instruction);
+                last_add_x_start = None;
+            }
+        }
+        start += instruction.cycles();
+    }
+
+    optimized
+}
+
+fn count_add_x(instructions: &[Instruction]) -> usize {
+    instructions
+        .iter()
+        .filter(|instruction| matches!(instruction, Instruction::AddX(_)))
+        .count()
+}
+
+#[derive(Debug, Clone)]
+struct Cpu {
+    x_register: i32,
+    cycles: usize,
+    current_instruction: Option<(usize, Instruction)>,
+}
+
+impl Default for Cpu {
+    fn default() -> Self {
+        Self {
+            x_register: 1,
+            cycles: 0,
+            current_instruction: None,
+        }
+    }
+}
+
+impl Cpu {
+    pub fn execute_and_compute_signal_strength<I: Iterator<Item = Instruction>>(
+        &mut self,
+        instructions: &mut I,
+        cycles: usize,
+    ) -> i32 {
+        let mut signal_strength = 0;
+        for _ in 0..cycles {
+            let x_register = self.x_register;
+            self.tick_with_instructions(instructions);
+            signal_strength = self.cycles as i32 * x_register;
+        }
+        signal_strength
+    }
+
+    pub fn execute_and_compute_picture<I: Iterator<Item = Instruction>>(
+        &mut self,
+        instructions: &mut I,
+        width: usize,
+        height: usize,
+    ) -> String {
+        let mut picture = String::new();
+
+        for i in 0..width * height {
+            let current_pixel = i % width;
+            let sprite_position = self.x_register;
+            if (current_pixel as i32 - sprite_position).abs() <= 1 {
+                picture.push('#');
+            } else {
+                picture.push(' ');
+            }
+            if current_pixel == width - 1 {
+                picture.push('\n');
+            }
+            self.tick_with_instructions(instructions);
+        }
+
+        picture
+    }
+
+    fn tick_with_instructions<I: Iterator<Item = Instruction>>(&mut self, instructions: &mut I) {
+        if self.is_idle() {
+            if let Some(instruction) = instructions.next() {
+                self.add_instruction(instruction);
+            }
+        }
+        self.tick();
+    }
+
+    fn is_idle(&self) -> bool {
+        self.current_instruction.is_none()
+    }
+
+    fn add_instruction(&mut self, instruction: Instruction) {
+        self.current_instruction = Some((0, instruction));
+    }
+
+    fn tick(&mut self) {
+        self.cycles += 1;
+        if let Some((mut cycles, instruction)) = self.current_instruction {
+            cycles += 1;
+            if cycles == instruction.cycles() {
+                if let Instruction::AddX(x) = instruction {
+                    self.x_register += x;
+                }
+                self.current_instruction = None;
+            } else {
+                self.current_instruction = Some((cycles, instruction));
+            }
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Instruction {
+    Noop,
+    AddX(i32),
+}
+
+impl Instruction {
+    fn cycles(&self) -> usize {
+        match self {
+            Self::Noop => 1,
+            Self::AddX(_) => 2,
+        }
+    }
+}
+
+const NOOP: &str = "noop";
+const ADD_X: &str = "addx";
+
+impl FromStr for Instruction {
+    type Err = String;
+
+    fn from_str(instruction: &str) -> Result<Self, Self::Err> {
+        if instruction == NOOP {
+            Ok(Instruction::Noop)
+        } else if instruction.starts_with(ADD_X) {
+            instruction
+                .get(ADD_X.len() + 1..)
+                .and_then(|value| value.parse().ok())
+                .map(Instruction::AddX)
+                .ok_or_else(|| format!("Invalid instruction: {instruction}"))
+        } else {
+            Err(format!("Invalid instruction: {instruction}"))
+        }
+    }
+}
+
+/// Shuffles the values added to the X register between the `addx` instructions. Lines that are
+/// not instructions are kept as they are.
+pub(crate) fn anonymize_input(input: &[u8], rng: &mut Rng) -> Result<String, String> {
+    let addx_value = |line: &String| {
+        line.strip_prefix("addx ")
+            .filter(|value| value.parse::<i32>().is_ok())
+            .map(str::to_string)
+    };
+    let mut lines = read_lines(input).filter_not_empty().collect::<Vec<_>>();
+    let mut values = lines.iter().filter_map(addx_value).collect::<Vec<_>>();
+    rng.shuffle(&mut values);
+    for (line, value) in lines
+        .iter_mut()
+        .filter(|line| addx_value(line).is_some())
+        .zip(values)
+    {
+        *line = format!("addx {value}");
+    }
+    Ok(lines.iter().map(|line| format!("{line}\n")).collect())
+}
+
+solution_example!(
+    day10,
+    part1 = 13140,
+    part2 = r"##  ##  ##  ##  ##  ##  ##  ##  ##  ##  
+###   ###   ###   ###   ###   ###   ### 
+####    ####    ####    ####    ####    
+#####     #####     #####     #####     
+######      ######      ######      ####
+#######       #######       #######     
+",
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn part1_small_example() {
+        let mut cpu = Cpu::default();
+        let mut instructions = read_lines(
+            b"\
+noop
+addx 3
+addx -5
+"
+            .as_slice(),
+        )
+        .filter_not_empty()
+        .parse();
+
+        cpu.execute_and_compute_signal_strength(&mut instructions, 5);
+
+        assert_eq!(cpu.cycles, 5);
+        assert_eq!(cpu.x_register, -1);
+    }
+
+    fn program(instructions: &str) -> Vec<Instruction> {
+        parse_input(instructions.replace(';', "\n").as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn programs_end_with_their_last_instruction() {
+        let instructions = program("noop;addx 3;addx -5");
+        let mut driver = Driver::new(Program::new(&instructions)).with_snapshots(2);
+
+        assert_eq!(driver.run_to_end(), 5);
+        assert_eq!(driver.simulation().cpu.x_register, -1);
+        assert!(driver.rewind(3));
+        assert_eq!(driver.simulation().cpu.x_register, 4);
+    }
+
+    #[test]
+    fn optimize_replaces_add_x_zero() {
+        let result = optimize(&program("addx 0;addx 3"), &[1, 2, 3, 4]);
+
+        assert_eq!(result, program("noop;noop;addx 3"));
+    }
+
+    #[test]
+    fn optimize_merges_unsampled_add_x() {
+        let result = optimize(&program("addx 2;addx 3;addx -5;noop"), &[7]);
+
+        assert_eq!(result, program("noop;noop;noop;noop;noop;noop;noop"));
+    }
+
+    #[test]
+    fn optimize_keeps_sampled_add_x() {
+        let result = optimize(&program("addx 2;addx 3;noop"), &[3]);
+
+        assert_eq!(result, program("addx 2;addx 3"));
+    }
+
+    #[test]
+    fn optimized_example_is_equivalent() {
+        let instructions = parse_input(EXAMPLE).unwrap();
+
+        let optimized = optimize(&instructions, &SIGNAL_CYCLES);
+
+        assert!(count_add_x(&optimized) < count_add_x(&instructions));
+        assert_eq!(
+            check_equivalence(&instructions, &optimized, &SIGNAL_CYCLES),
+            Ok(())
+        );
+        assert_eq!(solve_part_one(&optimized), 13140);
+    }
+
+    #[test]
+    fn equivalence_reports_first_divergence() {
+        let result = check_equivalence(
+            &program("addx 1;noop"),
+            &program("noop;addx 1"),
+            &[1, 2, 3, 4],
+        );
+
+        assert_eq!(
+            result,
+            Err(Divergence {
+                cycle: 3,
+                left: 2,
+                right: 1
+            })
+        );
+    }
+
+    #[test]
+    fn picture_on_a_smaller_crt() {
+        let params = [("crt".to_string(), "20x2".to_string())]
+            .into_iter()
+            .collect();
+
+        let result = Day10::new(EXAMPLE).stats(&params);
+
+        assert_eq!(
+            result[1],
+            "Picture drawn on a 20x2 CRT:\n##  ##  ##  ##  ##  \n                    \n"
+        );
+        assert!(parse_crt_size("40by6").is_err());
+    }
+
+    #[test]
+    fn invalid_instructions_fail_the_parts() {
+        let result = solve_part1(&b"noop\naddx\nnoop\n"[..]);
+
+        assert_eq!(
+            result,
+            Err(SolutionError::Input(
+                "Line 2: Invalid instruction: addx".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn anonymized_input_keeps_malformed_lines() {
+        let input = anonymize_input(b"addx 3\naddx x\nnoop\naddx -5\n", &mut Rng::new(1));
+
+        let input = input.unwrap();
+        let lines = input.lines().collect::<Vec<_>>();
+        assert_eq!(lines[1..3], ["addx x", "noop"]);
+        assert!(matches!(
+            [lines[0], lines[3]],
+            ["addx 3", "addx -5"] | ["addx -5", "addx 3"]
+        ));
+    }
+}
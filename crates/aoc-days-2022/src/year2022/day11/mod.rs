@@ -0,0 +1,402 @@
+use itertools::Itertools;
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::{multispace1, u64};
+use nom::combinator::{map, value};
+use nom::multi::separated_list1;
+use nom::sequence::{preceded, tuple};
+use nom::IResult;
+use std::io::BufRead;
+use tracing::{debug, debug_span, trace};
+
+use aoc_core::input::{byte_lines, read_all, BlocksExt, Input, InputSource, ParseError};
+use aoc_core::math::lcm;
+use aoc_core::parse::{parse_line, Field};
+use aoc_core::progress::{self, ProgressSink};
+use aoc_core::{cancel, Answer, Example, ExtraPart, Part, Solution, SolutionError};
+
+pub struct Day11(Input<Result<Vec<Monkey>, ParseError>>);
+
+aoc_core::register_solution!(Day11);
+
+impl Day11 {
+    pub fn new(input: impl Into<InputSource>) -> Self {
+        Self(Input::new(input, parse_input))
+    }
+}
+
+impl Default for Day11 {
+    fn default() -> Self {
+        Self(Input::for_day(11, parse_input))
+    }
+}
+
+impl Solution for Day11 {
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn with_input(&self, input: InputSource) -> Box<dyn Solution> {
+        Box::new(Self::new(input))
+    }
+
+    fn parse(&mut self) {
+        self.0.parse();
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![example()]
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        self.0.validate_parsed()
+    }
+
+    fn part_one(&self) -> Result<Answer, SolutionError> {
+        Ok(solve_part_one(self.0.try_get_parsed()?, &*progress::current())?.into())
+    }
+
+    fn part_two(&self) -> Result<Answer, SolutionError> {
+        Ok(solve_part_two(self.0.try_get_parsed()?, &*progress::current())?.into())
+    }
+
+    fn expected_part_one(&self) -> Option<Answer> {
+        Some(Answer::Unsigned(55930))
+    }
+
+    fn expected_part_two(&self) -> Option<Answer> {
+        Some(Answer::Unsigned(14636993466))
+    }
+
+    fn extra_parts(&self) -> Vec<ExtraPart> {
+        vec![ExtraPart {
+            name: "inspections",
+            label: "Items inspected by each monkey in 10 000 rounds",
+        }]
+    }
+
+    fn extra_part(&self, name: &str) -> Option<Result<Answer, SolutionError>> {
+        (name == "inspections").then(|| {
+            let mut monkeys = self.0.try_get_parsed()?.to_vec();
+            let inspections =
+                count_inspections(&mut monkeys, 10_000, false, &*progress::current())?;
+            Ok(Answer::from(inspections.iter().join(", ")))
+        })
+    }
+
+    fn label(&self, part: Part) -> String {
+        match part {
+            Part::One => "Level of monkey business after 20 rounds",
+            Part::Two => "Level of monkey business after 10 000 rounds",
+        }
+        .to_string()
+    }
+}
+
+fn solve_part_one(monkeys: &[Monkey], progress: &dyn ProgressSink) -> Result<usize, SolutionError> {
+    compute_monkey_business(&mut monkeys.to_vec(), 20, true, progress)
+}
+
+fn solve_part_two(monkeys: &[Monkey], progress: &dyn ProgressSink) -> Result<usize, SolutionError> {
+    compute_monkey_business(&mut monkeys.to_vec(), 10_000, false, progress)
+}
+
+fn compute_monkey_business(
+    monkeys: &mut [Monkey],
+    rounds: usize,
+    worry_level_reduction: bool,
+    progress: &dyn ProgressSink,
+) -> Result<usize, SolutionError> {
+    let inspections = count_inspections(monkeys, rounds, worry_level_reduction, progress)?;
+    Ok(inspections.iter().sorted().rev().take(2).product::<usize>())
+}
+
+/// Number of items inspected by each monkey during the rounds.
+fn count_inspections(
+    monkeys: &mut [Monkey],
+    rounds: usize,
+    worry_level_reduction: bool,
+    progress: &dyn ProgressSink,
+) -> Result<Vec<usize>, SolutionError> {
+    let _span = debug_span!("monkey_business", rounds).entered();
+    let mut inspections = vec![0; monkeys.len()];
+    progress.start("rounds", Some(rounds as u64));
+
+    for round in 1..=rounds {
+        cancel::checkpoint()?;
+        let new_inspections = play_round(monkeys, worry_level_reduction);
+        inspections = inspections
+            .into_iter()
+            .zip(new_inspections)
+            .map(|(a, b)| a + b)
+            .collect();
+        trace!(round, ?inspections);
+        progress.advance(1);
+        if round % 1000 == 0 {
+            debug!(round, "{}% of the rounds played", round * 100 / rounds);
+        }
+    }
+    progress.finish();
+
+    Ok(inspections)
+}
+
+fn play_round(monkeys: &mut [Monkey], worry_level_reduction: bool) -> Vec<usize> {
+    // Every test gives the same result for two worry levels congruent modulo this period.
+    let period = monkeys
+        .iter()
+        .map(|monkey| monkey.divisible_test)
+        .fold(1, lcm);
+    let mut result = Vec::new();
+    let mut items = monkeys
+        .iter()
+        .map(|monkey| monkey.items.clone())
+        .collect::<Vec<_>>();
+
+    for monkey in &*monkeys {
+        let mut monkey_items = Vec::new();
+        monkey_items.append(&mut items[monkey.number]);
+        result.push(monkey_items.len());
+        for worry_level in monkey_items {
+            let mut new_worry_level = monkey.operation.apply(worry_level);
+
+            if worry_level_reduction {
+                new_worry_level /= 3;
+            } else {
+                new_worry_level %= period;
+            }
+
+            let target = if new_worry_level % monkey.divisible_test == 0 {
+                monkey.on_true_monkey
+            } else {
+                monkey.on_false_monkey
+            };
+
+            items[target].push(new_worry_level);
+        }
+    }
+
+    for monkey in monkeys {
+        monkey.items = items[monkey.number].clone();
+    }
+
+    result
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Monkey {
+    number: usize,
+    items: Vec<u64>,
+    operation: Operation,
+    divisible_test: u64,
+    on_true_monkey: usize,
+    on_false_monkey: usize,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Operation {
+    Add(u64),
+    Multiply(u64),
+    Square,
+}
+
+impl Operation {
+    fn apply(&self, worry_level: u64) -> u64 {
+        match self {
+            Self::Add(operand) => worry_level + *operand,
+            Self::Multiply(operand) => worry_level * *operand,
+            Self::Square => worry_level * worry_level,
+        }
+    }
+}
+
+/// `+ 6`, `* 19` or `* old`.
+impl Field for Operation {
+    fn parse_field(input: &str) -> IResult<&str, Self> {
+        alt((
+            map(parse_line!("+ {u64}"), Operation::Add),
+            map(parse_line!("* {u64}"), Operation::Multiply),
+            value(Operation::Square, tag("* old")),
+        ))(input)
+    }
+}
+
+fn monkey(input: &str) -> IResult<&str, Monkey> {
+    let (
+        input,
+        (number, _, items, _, operation, _, divisible_test, _, on_true_monkey, _, on_false_monkey),
+    ) = tuple((
+        parse_line!("Monkey {usize}:"),
+        multispace1,
+        preceded(tag("Starting items: "), separated_list1(tag(", "), u64)),
+        multispace1,
+        parse_line!("Operation: new = old {Operation}"),
+        multispace1,
+        parse_line!("Test: divisible by {u64}"),
+        multispace1,
+        parse_line!("If true: throw to monkey {usize}"),
+        multispace1,
+        parse_line!("If false: throw to monkey {usize}"),
+    ))(input)?;
+
+    Ok((
+        input,
+        Monkey {
+            number,
+            items,
+            operation,
+            divisible_test,
+            on_true_monkey,
+            on_false_monkey,
+        },
+    ))
+}
+
+pub fn solve_part1(input: impl BufRead) -> Result<Answer, SolutionError> {
+    Ok(solve_part_one(&parse_input(&read_all(input)?)?, &*progress::current())?.into())
+}
+
+pub fn solve_part2(input: impl BufRead) -> Result<Answer, SolutionError> {
+    Ok(solve_part_two(&parse_input(&read_all(input)?)?, &*progress::current())?.into())
+}
+
+fn parse_input(input: &[u8]) -> Result<Vec<Monkey>, ParseError> {
+    byte_lines(input)
+        .blocks()
+        .numbered()
+        .map(|(first_line, block)| {
+            let block = String::from_utf8_lossy(&block.join(&b'\n')).into_owned();
+            let (_, monkey) = monkey(&block).map_err(|error| {
+                let rest = match &error {
+                    nom::Err::Error(error) | nom::Err::Failure(error) => error.input,
+                    nom::Err::Incomplete(_) => "",
+                };
+                let line = block[..block.len() - rest.len()].matches('\n').count();
+                ParseError {
+                    line: first_line + line,
+                    message: format!(
+                        "Invalid monkey: {}",
+                        block.lines().nth(line).unwrap_or_default().trim()
+                    ),
+                }
+            })?;
+            Ok(monkey)
+        })
+        .collect()
+}
+
+solution_example!(day11, part1 = 10605, part2 = 2713310158u64);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::{assert_eq, vec};
+
+    #[test]
+    fn parse_example_first_monkey() {
+        let result = monkey(
+            "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3",
+        );
+
+        assert_eq!(
+            result,
+            Ok((
+                "",
+                Monkey {
+                    number: 0,
+                    items: vec![79, 98],
+                    operation: Operation::Multiply(19),
+                    divisible_test: 23,
+                    on_true_monkey: 2,
+                    on_false_monkey: 3,
+                }
+            ))
+        )
+    }
+
+    #[test]
+    fn parse_example() {
+        let monkeys = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(
+            monkeys,
+            vec![
+                Monkey {
+                    number: 0,
+                    items: vec![79, 98],
+                    operation: Operation::Multiply(19),
+                    divisible_test: 23,
+                    on_true_monkey: 2,
+                    on_false_monkey: 3,
+                },
+                Monkey {
+                    number: 1,
+                    items: vec![54, 65, 75, 74],
+                    operation: Operation::Add(6),
+                    divisible_test: 19,
+                    on_true_monkey: 2,
+                    on_false_monkey: 0,
+                },
+                Monkey {
+                    number: 2,
+                    items: vec![79, 60, 97],
+                    operation: Operation::Square,
+                    divisible_test: 13,
+                    on_true_monkey: 1,
+                    on_false_monkey: 3,
+                },
+                Monkey {
+                    number: 3,
+                    items: vec![74],
+                    operation: Operation::Add(3),
+                    divisible_test: 17,
+                    on_true_monkey: 0,
+                    on_false_monkey: 1,
+                },
+            ],
+        )
+    }
+
+    #[test]
+    fn example_first_round() {
+        let mut monkeys = parse_input(EXAMPLE).unwrap();
+
+        play_round(&mut monkeys, true);
+
+        assert_eq!(monkeys[0].items, vec![20, 23, 27, 26]);
+        assert_eq!(monkeys[1].items, vec![2080, 25, 167, 207, 401, 1046]);
+        assert!(monkeys[2].items.is_empty());
+        assert!(monkeys[3].items.is_empty());
+    }
+
+    #[test]
+    fn example_inspections() {
+        let day = Day11::new(EXAMPLE.to_vec());
+
+        assert_eq!(
+            day.extra_part("inspections"),
+            Some(Ok(Answer::from("52166, 47830, 1938, 52013")))
+        );
+        assert_eq!(day.extra_part("unknown"), None);
+    }
+
+    #[test]
+    fn invalid_monkeys_fail_the_parts() {
+        let input = String::from_utf8_lossy(EXAMPLE)
+            .replace("Operation: new = old * 19", "Operation: new = old ^ 2");
+
+        let result = solve_part1(input.as_bytes());
+
+        assert_eq!(
+            result,
+            Err(SolutionError::Input(
+                "Line 3: Invalid monkey: Operation: new = old ^ 2".to_string()
+            ))
+        );
+    }
+}
@@ -0,0 +1,381 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+use std::io::BufRead;
+#[cfg(feature = "visualization")]
+use std::time::Duration;
+use tracing::{debug_span, trace, Level};
+
+use aoc_core::geometry::GridPos;
+use aoc_core::input::{read_all, FromLines, Input, InputSource, ParseAll};
+use aoc_core::pathfinding;
+use aoc_core::style::Color;
+#[cfg(feature = "visualization")]
+use aoc_core::style::CLEAR_SCREEN;
+use aoc_core::{Answer, Example, Part, Solution, SolutionError};
+
+pub struct Day12(Input<HeightMap>, Search);
+
+aoc_core::register_solution!(Day12);
+
+impl Day12 {
+    pub fn new(input: impl Into<InputSource>) -> Self {
+        Self(Input::new(input, parse_input), Search::default())
+    }
+}
+
+impl Default for Day12 {
+    fn default() -> Self {
+        Self(Input::for_day(12, parse_input), Search::default())
+    }
+}
+
+impl Solution for Day12 {
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn with_input(&self, input: InputSource) -> Box<dyn Solution> {
+        Box::new(Self(Input::new(input, parse_input), self.1))
+    }
+
+    fn parse(&mut self) {
+        self.0.parse();
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![example()]
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        self.0.validate()
+    }
+
+    fn part_one(&self) -> Result<Answer, SolutionError> {
+        Ok(solve_part_one(self.0.try_get()?, self.1)?.into())
+    }
+
+    fn part_two(&self) -> Result<Answer, SolutionError> {
+        Ok(solve_part_two(self.0.try_get()?, self.1)?.into())
+    }
+
+    fn implementations(&self) -> Vec<&'static str> {
+        IMPLEMENTATIONS.iter().map(|(name, _)| *name).collect()
+    }
+
+    fn with_implementation(&self, name: &str) -> Option<Box<dyn Solution>> {
+        let &(_, search) = IMPLEMENTATIONS.iter().find(|(known, _)| *known == name)?;
+        let input = Input::new(self.0.source().clone(), parse_input);
+        Some(Box::new(Self(input, search)))
+    }
+
+    fn expected_part_one(&self) -> Option<Answer> {
+        Some(Answer::Unsigned(449))
+    }
+
+    fn expected_part_two(&self) -> Option<Answer> {
+        Some(Answer::Unsigned(443))
+    }
+
+    fn label(&self, part: Part) -> String {
+        match part {
+            Part::One => "Shortest path",
+            Part::Two => "Shortest a to end",
+        }
+        .to_string()
+    }
+
+    #[cfg(feature = "visualization")]
+    fn can_visualize(&self) -> bool {
+        true
+    }
+
+    #[cfg(feature = "visualization")]
+    fn visualize(&self) -> Option<String> {
+        let height_map = self.0.get();
+        let steps = height_map.shortest_path(
+            height_map.start,
+            true,
+            |p| p == height_map.end,
+            |visited, shortest_paths| {
+                print!("{CLEAR_SCREEN}");
+                print!("{}", height_map.render(visited, shortest_paths, true));
+                std::thread::sleep(Duration::from_millis(50))
+            },
+        )?;
+        Some(format!("Shortest path: {steps}"))
+    }
+}
+
+pub fn solve_part1(input: impl BufRead) -> Result<Answer, SolutionError> {
+    Ok(solve_part_one(&parse_input(&read_all(input)?), Search::default())?.into())
+}
+
+pub fn solve_part2(input: impl BufRead) -> Result<Answer, SolutionError> {
+    Ok(solve_part_two(&parse_input(&read_all(input)?), Search::default())?.into())
+}
+
+/// How the shortest paths are searched.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+enum Search {
+    /// Expands the closest position known in a map of the shortest paths, which is traced and
+    /// visualized. It scans the whole map at each step, which keeps it a naive oracle for
+    /// `compare`.
+    #[default]
+    HashMapDijkstra,
+    /// Expands the positions in order of distance from a priority queue.
+    BinaryHeap,
+    /// Expands the positions level by level, as every step costs 1.
+    Bfs,
+}
+
+const IMPLEMENTATIONS: [(&str, Search); 3] = [
+    ("hashmap-dijkstra", Search::HashMapDijkstra),
+    ("binary-heap", Search::BinaryHeap),
+    ("bfs", Search::Bfs),
+];
+
+fn parse_input(input: &[u8]) -> HeightMap {
+    input.parse_all()
+}
+
+fn solve_part_one(height_map: &HeightMap, search: Search) -> Result<usize, SolutionError> {
+    height_map
+        .search(search, height_map.start, true, |p| p == height_map.end)
+        .ok_or_else(|| SolutionError::Unexpected("No path from the start to the end".to_string()))
+}
+
+fn solve_part_two(height_map: &HeightMap, search: Search) -> Result<usize, SolutionError> {
+    height_map
+        .search(search, height_map.end, false, |p| {
+            height_map.height(&p) == b'a'
+        })
+        .ok_or_else(|| {
+            SolutionError::Unexpected("No path from a square of elevation a to the end".to_string())
+        })
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+struct HeightMap {
+    start: GridPos,
+    end: GridPos,
+    heights: Vec<Vec<char>>,
+}
+
+impl FromLines for HeightMap {
+    fn from_lines(lines: impl Iterator<Item = String>) -> Self {
+        let mut start = GridPos::default();
+        let mut end = GridPos::default();
+        let mut heights = Vec::new();
+
+        for (row, line) in lines.enumerate() {
+            let mut row_heights = Vec::new();
+            for (column, char) in line.chars().enumerate() {
+                let height = match char {
+                    'S' => {
+                        start = GridPos::new(row, column);
+                        'a'
+                    }
+                    'E' => {
+                        end = GridPos::new(row, column);
+                        'z'
+                    }
+                    _ => char,
+                };
+                row_heights.push(height);
+            }
+            heights.push(row_heights);
+        }
+
+        Self {
+            start,
+            end,
+            heights,
+        }
+    }
+}
+
+impl HeightMap {
+    fn rows(&self) -> usize {
+        self.heights.len()
+    }
+
+    fn columns(&self) -> usize {
+        self.heights.first().map_or(0, Vec::len)
+    }
+
+    /// Length of the shortest path from `start` to a position matching `end_condition`.
+    fn search<E>(
+        &self,
+        search: Search,
+        start: GridPos,
+        forward: bool,
+        end_condition: E,
+    ) -> Option<usize>
+    where
+        E: Fn(GridPos) -> bool,
+    {
+        match search {
+            Search::HashMapDijkstra => {
+                self.shortest_path(start, forward, end_condition, |visited, shortest_paths| {
+                    self.trace(visited, shortest_paths)
+                })
+            }
+            Search::BinaryHeap => pathfinding::dijkstra(
+                start,
+                |position| {
+                    self.get_neighbors(position, forward)
+                        .into_iter()
+                        .map(|neighbor| (neighbor, 1))
+                },
+                |&position| end_condition(position),
+            )
+            .map(|path| path.cost),
+            Search::Bfs => pathfinding::bfs(
+                start,
+                |position| self.get_neighbors(position, forward),
+                |&position| end_condition(position),
+            )
+            .map(|path| path.cost),
+        }
+    }
+
+    /// Dijkstra search from `start`, calling `on_step` after each expanded position.
+    fn shortest_path<E, S>(
+        &self,
+        start: GridPos,
+        forward: bool,
+        end_condition: E,
+        mut on_step: S,
+    ) -> Option<usize>
+    where
+        E: Fn(GridPos) -> bool,
+        S: FnMut(&HashSet<GridPos>, &HashMap<GridPos, usize>),
+    {
+        let _span = debug_span!("shortest_path", ?start, forward).entered();
+        let mut visited = HashSet::new();
+        let mut shortest_paths: HashMap<GridPos, usize> = [(start, 0)].into();
+
+        while let Some((&position, &shortest_path)) = shortest_paths
+            .iter()
+            .filter(|(position, _)| !visited.contains(*position))
+            .min_by_key(|(_, path)| **path)
+        {
+            for neighbor in self.get_neighbors(&position, forward) {
+                if end_condition(neighbor) {
+                    return Some(shortest_path + 1);
+                }
+                shortest_paths
+                    .entry(neighbor)
+                    .and_modify(|current| *current = (*current).min(shortest_path + 1))
+                    .or_insert(shortest_path + 1);
+            }
+            visited.insert(position);
+            trace!(?position, shortest_path, "expanded");
+            on_step(&visited, &shortest_paths);
+        }
+
+        shortest_paths.get(&self.end).copied()
+    }
+
+    /// Logs the state of the search at the trace level.
+    fn trace(&self, visited: &HashSet<GridPos>, shortest_paths: &HashMap<GridPos, usize>) {
+        if tracing::enabled!(Level::TRACE) {
+            trace!("\n{}", self.render(visited, shortest_paths, false));
+        }
+    }
+
+    /// Grid of the known shortest paths, colored by state when `colors` is set.
+    fn render(
+        &self,
+        visited: &HashSet<GridPos>,
+        shortest_paths: &HashMap<GridPos, usize>,
+        colors: bool,
+    ) -> String {
+        let mut grid = String::new();
+        for row in 0..self.rows() {
+            for column in 0..self.columns() {
+                let position = GridPos::new(row, column);
+
+                let cell = match shortest_paths.get(&position) {
+                    Some(shortest_path) => format!("{shortest_path:3}"),
+                    None => "  ?".to_string(),
+                };
+                if !colors {
+                    grid.push_str(&cell);
+                    continue;
+                }
+                let color = if position == self.start {
+                    Color::Magenta
+                } else if position == self.end {
+                    Color::Yellow
+                } else if visited.contains(&position) {
+                    Color::Green
+                } else {
+                    Color::Red
+                };
+                write!(grid, "{}", color.paint(cell)).unwrap();
+            }
+            grid.push('\n');
+        }
+        grid
+    }
+
+    fn get_neighbors(&self, position: &GridPos, forward: bool) -> Vec<GridPos> {
+        position
+            .neighbors(self.rows(), self.columns())
+            .filter(|neighbor| self.can_move(position, neighbor, forward))
+            .collect()
+    }
+
+    fn can_move(&self, from: &GridPos, to: &GridPos, forward: bool) -> bool {
+        let from_height = self.height(from);
+        let to_height = self.height(to);
+
+        if forward {
+            from_height >= to_height || from_height == to_height - 1
+        } else {
+            from_height <= to_height || from_height - 1 == to_height
+        }
+    }
+
+    pub fn height(&self, &GridPos { row, column }: &GridPos) -> u8 {
+        self.heights[row][column] as u8
+    }
+}
+
+solution_example!(day12, part1 = 31, part2 = 29);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn implementations_agree_on_the_example() {
+        let height_map = parse_input(EXAMPLE);
+
+        for (name, search) in IMPLEMENTATIONS {
+            assert_eq!(solve_part_one(&height_map, search), Ok(31), "{name}");
+            assert_eq!(solve_part_two(&height_map, search), Ok(29), "{name}");
+        }
+    }
+
+    #[test]
+    fn parse_example() {
+        let height_map = parse_input(EXAMPLE);
+
+        assert_eq!(
+            height_map,
+            HeightMap {
+                start: GridPos::new(0, 0),
+                end: GridPos::new(2, 5),
+                heights: vec![
+                    vec!['a', 'a', 'b', 'q', 'p', 'o', 'n', 'm'],
+                    vec!['a', 'b', 'c', 'r', 'y', 'x', 'x', 'l'],
+                    vec!['a', 'c', 'c', 's', 'z', 'z', 'x', 'k'],
+                    vec!['a', 'c', 'c', 't', 'u', 'v', 'w', 'j'],
+                    vec!['a', 'b', 'd', 'e', 'f', 'g', 'h', 'i'],
+                ],
+            }
+        )
+    }
+}
@@ -0,0 +1,491 @@
+use itertools::Itertools;
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::digit1;
+use nom::combinator::map_res;
+use nom::multi::separated_list0;
+use nom::sequence::delimited;
+use nom::IResult;
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use std::str::FromStr;
+
+use aoc_core::input::{
+    byte_lines, read_all, validate_lines, Input, InputSource, ParseError, ParseExt,
+};
+use aoc_core::{Answer, Example, Params, Part, Solution, SolutionError};
+
+pub struct Day13(Input<Result<Vec<Packet>, ParseError>>);
+
+aoc_core::register_solution!(Day13);
+
+impl Day13 {
+    pub fn new(input: impl Into<InputSource>) -> Self {
+        Self(Input::new(input, parse_input))
+    }
+}
+
+impl Default for Day13 {
+    fn default() -> Self {
+        Self(Input::for_day(13, parse_input))
+    }
+}
+
+impl Solution for Day13 {
+    fn day(&self) -> u8 {
+        13
+    }
+
+    fn with_input(&self, input: InputSource) -> Box<dyn Solution> {
+        Box::new(Self::new(input))
+    }
+
+    fn parse(&mut self) {
+        self.0.parse();
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![example()]
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        self.0.validate()?;
+        validate_lines::<Packet>(self.0.raw())
+    }
+
+    fn part_one(&self) -> Result<Answer, SolutionError> {
+        Ok(solve_part_one(self.0.try_get_parsed()?).into())
+    }
+
+    fn part_two(&self) -> Result<Answer, SolutionError> {
+        Ok(solve_part_two(self.0.try_get_parsed()?).into())
+    }
+
+    fn expected_part_one(&self) -> Option<Answer> {
+        Some(Answer::Unsigned(4734))
+    }
+
+    fn expected_part_two(&self) -> Option<Answer> {
+        Some(Answer::Unsigned(21836))
+    }
+
+    fn label(&self, part: Part) -> String {
+        match part {
+            Part::One => "Sum of indices of correctly ordered pairs",
+            Part::Two => "Decoder key",
+        }
+        .to_string()
+    }
+
+    fn stats(&self, _params: &Params) -> Vec<String> {
+        let Ok(packets) = self.0.try_get_parsed() else {
+            return Vec::new();
+        };
+        let pairs = packets.iter().cloned().tuples().collect::<Vec<_>>();
+        let pruned = pairs
+            .iter()
+            .map(|(left, right): &(Packet, Packet)| {
+                (
+                    left.max_depth_prune(STATS_PRUNE_DEPTH),
+                    right.max_depth_prune(STATS_PRUNE_DEPTH),
+                )
+            })
+            .collect::<Vec<_>>();
+        let merged = pairs
+            .into_iter()
+            .map(|(left, right)| left.merge(right))
+            .collect();
+        vec![
+            format!(
+                "Deepest packet: {} nested lists",
+                packets.iter().map(Packet::depth).max().unwrap_or(0),
+            ),
+            format!(
+                "Sum of indices of ordered pairs without lists deeper than {STATS_PRUNE_DEPTH}: {}",
+                sum_indices_of_correctly_ordered_pairs(&pruned),
+            ),
+            format!(
+                "Decoder key of the pairs merged into single packets: {}",
+                compute_decoder_key(merged),
+            ),
+        ]
+    }
+}
+
+/// Depth to which the statistics prune the packets.
+const STATS_PRUNE_DEPTH: usize = 2;
+
+pub fn solve_part1(input: impl BufRead) -> Result<Answer, SolutionError> {
+    Ok(solve_part_one(&parse_input(&read_all(input)?)?).into())
+}
+
+pub fn solve_part2(input: impl BufRead) -> Result<Answer, SolutionError> {
+    Ok(solve_part_two(&parse_input(&read_all(input)?)?).into())
+}
+
+/// Packets are compared by pairs of lines, which a skipped line would shift.
+fn parse_input(input: &[u8]) -> Result<Vec<Packet>, ParseError> {
+    byte_lines(input).try_parse().collect()
+}
+
+fn solve_part_one(packets: &[Packet]) -> usize {
+    sum_indices_of_correctly_ordered_pairs(&packets.iter().cloned().tuples().collect::<Vec<_>>())
+}
+
+fn solve_part_two(packets: &[Packet]) -> usize {
+    compute_decoder_key(packets.to_vec())
+}
+
+fn sum_indices_of_correctly_ordered_pairs(packets: &[(Packet, Packet)]) -> usize {
+    packets
+        .iter()
+        .enumerate()
+        .filter_map(|(index, (p1, p2))| (p1 <= p2).then_some(index + 1))
+        .sum()
+}
+
+fn compute_decoder_key(packets: Vec<Packet>) -> usize {
+    let mut packets = packets.into_iter().collect::<OrderedPackets>();
+    // Packets such as `[2]` compare equal to the dividers, so they are found where they are
+    // inserted rather than searched for.
+    let first = packets.sorted_insert("[[2]]".parse().unwrap());
+    let second = packets.sorted_insert("[[6]]".parse().unwrap());
+    let first = if second <= first { first + 1 } else { first };
+    (first + 1) * (second + 1)
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+enum Packet {
+    List(Vec<Packet>),
+    Integer(u32),
+}
+
+impl Packet {
+    fn into_list(self) -> Vec<Packet> {
+        match self {
+            Self::List(packets) => packets,
+            integer => vec![integer],
+        }
+    }
+
+    fn merge(self, other: Packet) -> Packet {
+        let mut packets = self.into_list();
+        packets.extend(other.into_list());
+        Self::List(packets)
+    }
+
+    fn depth(&self) -> usize {
+        match self {
+            Self::Integer(_) => 0,
+            Self::List(packets) => 1 + packets.iter().map(Packet::depth).max().unwrap_or(0),
+        }
+    }
+
+    fn max_depth_prune(&self, depth: usize) -> Packet {
+        match self {
+            Self::Integer(integer) => Self::Integer(*integer),
+            Self::List(packets) => Self::List(
+                packets
+                    .iter()
+                    .filter(|packet| depth > 1 || matches!(packet, Self::Integer(_)))
+                    .map(|packet| packet.max_depth_prune(depth.saturating_sub(1)))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Default, Eq, PartialEq, Clone)]
+struct OrderedPackets(Vec<Packet>);
+
+impl OrderedPackets {
+    fn sorted_insert(&mut self, packet: Packet) -> usize {
+        let index = self.0.partition_point(|p| p <= &packet);
+        self.0.insert(index, packet);
+        index
+    }
+}
+
+impl FromIterator<Packet> for OrderedPackets {
+    fn from_iter<T: IntoIterator<Item = Packet>>(iter: T) -> Self {
+        let mut packets = iter.into_iter().collect::<Vec<_>>();
+        packets.sort();
+        Self(packets)
+    }
+}
+
+impl Display for Packet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Integer(packet) => write!(f, "{}", packet),
+            Self::List(packets) => {
+                write!(f, "[")?;
+                if let Some((first, rest)) = packets.split_first() {
+                    write!(f, "{}", first)?;
+                    for packet in rest {
+                        write!(f, ",{}", packet)?;
+                    }
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Integer(i1), Self::Integer(i2)) => i1.cmp(i2),
+            (Self::List(l1), Self::List(l2)) => compare(l1, l2),
+            (Self::List(list), Self::Integer(integer)) => compare(list, &[Self::Integer(*integer)]),
+            (Self::Integer(integer), Self::List(list)) => compare(&[Self::Integer(*integer)], list),
+        }
+    }
+}
+
+fn compare(l1: &[Packet], l2: &[Packet]) -> Ordering {
+    for i in 0..l1.len().min(l2.len()) {
+        let comparison = l1[i].cmp(&l2[i]);
+        if comparison != Ordering::Equal {
+            return comparison;
+        }
+    }
+    l1.len().cmp(&l2.len())
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for Packet {
+    type Err = String;
+
+    fn from_str(packet: &str) -> Result<Self, Self::Err> {
+        if let Ok(("", packet)) = parse_packet(packet) {
+            Ok(packet)
+        } else {
+            Err(format!("Invalid packet data: {packet}"))
+        }
+    }
+}
+
+fn parse_packet(input: &str) -> IResult<&str, Packet> {
+    alt((parse_list_packet, parse_integer_packet))(input)
+}
+
+fn parse_list_packet(input: &str) -> IResult<&str, Packet> {
+    let (input, packets) =
+        delimited(tag("["), separated_list0(tag(","), parse_packet), tag("]"))(input)?;
+    Ok((input, Packet::List(packets)))
+}
+
+fn parse_integer_packet(input: &str) -> IResult<&str, Packet> {
+    let (input, integer) = map_res(digit1, str::parse)(input)?;
+    Ok((input, Packet::Integer(integer)))
+}
+
+solution_example!(day13, part1 = 13, part2 = 140);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_integer() {
+        let packet = "1".parse::<Packet>();
+
+        assert_eq!(packet, Ok(Packet::Integer(1)));
+    }
+
+    #[test]
+    fn parse_integer_list() {
+        let packet = "[1,2]".parse::<Packet>();
+
+        assert_eq!(
+            packet,
+            Ok(Packet::List(vec![Packet::Integer(1), Packet::Integer(2)])),
+        );
+    }
+
+    #[test]
+    fn parse_list_of_list() {
+        let packet = "[[1],[2,3,4]]".parse::<Packet>();
+
+        assert_eq!(
+            packet,
+            Ok(Packet::List(vec![
+                Packet::List(vec![Packet::Integer(1)]),
+                Packet::List(vec![
+                    Packet::Integer(2),
+                    Packet::Integer(3),
+                    Packet::Integer(4)
+                ]),
+            ])),
+        );
+    }
+
+    #[test]
+    fn compare_first_example() {
+        let left = "[1,1,3,1,1]".parse::<Packet>();
+        let right = "[1,1,5,1,1]".parse::<Packet>();
+
+        assert!(left < right);
+    }
+
+    #[test]
+    fn compare_second_example() {
+        let left = "[[1],[2,3,4]]".parse::<Packet>();
+        let right = "[[1],4]".parse::<Packet>();
+
+        assert!(left < right);
+    }
+
+    #[test]
+    fn compare_third_example() {
+        let left = "[9]".parse::<Packet>();
+        let right = "[[8,7,6]]".parse::<Packet>();
+
+        assert!(left > right);
+    }
+
+    #[test]
+    fn compare_test() {
+        let left = "[1,[2,[3,[4,[5,6,0]]]],8,9]".parse::<Packet>();
+        let right = "[[1],4]".parse::<Packet>();
+
+        assert!(left < right);
+    }
+
+    #[test]
+    fn sort_example() {
+        let mut packets = byte_lines(EXAMPLE)
+            .parse_or_log::<Packet>()
+            .collect::<Vec<_>>();
+        packets.sort();
+
+        let result = packets.iter().map(Packet::to_string).join("\n");
+
+        assert_eq!(
+            &result,
+            "[]
+[[]]
+[[[]]]
+[1,1,3,1,1]
+[1,1,5,1,1]
+[[1],[2,3,4]]
+[1,[2,[3,[4,[5,6,0]]]],8,9]
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[[1],4]
+[3]
+[[4,4],4,4]
+[[4,4],4,4,4]
+[7,7,7]
+[7,7,7,7]
+[[8,7,6]]
+[9]",
+        )
+    }
+
+    fn packet(packet: &str) -> Packet {
+        packet.parse().unwrap()
+    }
+
+    #[test]
+    fn merge_lists() {
+        let result = packet("[1,[2]]").merge(packet("[[3],4]"));
+
+        assert_eq!(result, packet("[1,[2],[3],4]"));
+    }
+
+    #[test]
+    fn merge_integers() {
+        let result = packet("1").merge(packet("[2]"));
+
+        assert_eq!(result, packet("[1,2]"));
+    }
+
+    #[test]
+    fn test_depth() {
+        assert_eq!(packet("3").depth(), 0);
+        assert_eq!(packet("[]").depth(), 1);
+        assert_eq!(packet("[1,[2,[3,[4,[5,6,7]]]],8,9]").depth(), 5);
+    }
+
+    #[test]
+    fn max_depth_prune_removes_deeper_lists() {
+        let original = packet("[1,[2,[3,[4,[5,6,7]]]],8,9]");
+
+        assert_eq!(original.max_depth_prune(1), packet("[1,8,9]"));
+        assert_eq!(original.max_depth_prune(3), packet("[1,[2,[3]],8,9]"));
+        assert_eq!(original.max_depth_prune(5), original);
+    }
+
+    #[test]
+    fn max_depth_prune_keeps_depth_under_limit() {
+        let packets = byte_lines(EXAMPLE).parse_or_log::<Packet>();
+
+        for packet in packets {
+            for depth in 1..6 {
+                assert!(packet.max_depth_prune(depth).depth() <= depth);
+            }
+        }
+    }
+
+    #[test]
+    fn sorted_insert_keeps_packets_ordered() {
+        let mut packets = OrderedPackets::default();
+
+        for packet in byte_lines(EXAMPLE).parse_or_log::<Packet>() {
+            packets.sorted_insert(packet);
+        }
+
+        assert!(packets.0.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eq!(packets.0[8], packet("[[1],4]"));
+    }
+
+    #[test]
+    fn sorted_insert_returns_index() {
+        let mut packets = ["[1]", "[3]"]
+            .into_iter()
+            .map(packet)
+            .collect::<OrderedPackets>();
+
+        let result = packets.sorted_insert(packet("[2]"));
+
+        assert_eq!(result, 1);
+        assert_eq!(packets.0, vec![packet("[1]"), packet("[2]"), packet("[3]")]);
+    }
+
+    #[test]
+    fn decoder_key_with_packets_equal_to_the_dividers() {
+        let packets = ["[2]", "[[2]]", "[1]", "[[[2]]]", "[7]", "[6]"]
+            .into_iter()
+            .map(packet)
+            .collect();
+
+        assert_eq!(compute_decoder_key(packets), 5 * 7);
+    }
+
+    #[test]
+    fn malformed_packets_fail_the_parts() {
+        let result = solve_part1(&b"[1]\n[2,x]\n\n[3]\n[4]\n"[..]);
+
+        assert!(
+            matches!(&result, Err(SolutionError::Input(error)) if error.starts_with("Line 2")),
+            "{result:?}"
+        );
+    }
+
+    #[test]
+    fn integers_too_large_fail_the_parts() {
+        let result = solve_part1(&b"[1,99999999999999999999999]\n[2]\n"[..]);
+
+        assert!(
+            matches!(&result, Err(SolutionError::Input(error)) if error.starts_with("Line 1")),
+            "{result:?}"
+        );
+    }
+}
@@ -0,0 +1,728 @@
+#[cfg(feature = "visualization")]
+use aoc_core::style::CLEAR_SCREEN;
+use itertools::Itertools;
+use nom::bytes::complete::tag;
+use nom::character::complete::digit1;
+use nom::combinator::{all_consuming, map_res};
+use nom::multi::separated_list1;
+use nom::sequence::tuple;
+use nom::IResult;
+use std::collections::HashSet;
+use std::io::BufRead;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+#[cfg(feature = "visualization")]
+use std::time::Duration;
+use tracing::{debug, Level};
+
+use aoc_core::geometry::{flood_fill, Bounds, GridPos, PositionSet, SparseGrid, Vec2};
+use aoc_core::input::{
+    byte_lines, read_all, validate_lines, Input, InputSource, ParseError, ParseExt,
+};
+use aoc_core::plot::{plot, TERMINAL_HEIGHT, TERMINAL_WIDTH};
+use aoc_core::progress::{self, ProgressSink};
+use aoc_core::simulation::{Driver, Simulation};
+use aoc_core::style::Color;
+use aoc_core::{Answer, Example, ExtraPart, Part, Solution, SolutionError};
+
+pub struct Day14(Input<Vec<Rock>>);
+
+aoc_core::register_solution!(Day14);
+
+impl Day14 {
+    pub fn new(input: impl Into<InputSource>) -> Self {
+        Self(Input::new(input, parse_input))
+    }
+}
+
+impl Default for Day14 {
+    fn default() -> Self {
+        Self(Input::for_day(14, parse_input))
+    }
+}
+
+impl Solution for Day14 {
+    fn day(&self) -> u8 {
+        14
+    }
+
+    fn with_input(&self, input: InputSource) -> Box<dyn Solution> {
+        Box::new(Self::new(input))
+    }
+
+    fn parse(&mut self) {
+        self.0.parse();
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![example()]
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        self.0.validate()?;
+        validate_lines::<Rock>(self.0.raw())
+    }
+
+    fn skipped_lines(&self) -> Vec<ParseError> {
+        self.0.skipped().to_vec()
+    }
+
+    fn part_one(&self) -> Result<Answer, SolutionError> {
+        Ok(solve_part_one(self.0.try_get()?, &*progress::current()).into())
+    }
+
+    fn part_two(&self) -> Result<Answer, SolutionError> {
+        Ok(solve_part_two(self.0.try_get()?, &*progress::current()).into())
+    }
+
+    fn expected_part_one(&self) -> Option<Answer> {
+        Some(Answer::Unsigned(832))
+    }
+
+    fn expected_part_two(&self) -> Option<Answer> {
+        Some(Answer::Unsigned(27601))
+    }
+
+    fn extra_parts(&self) -> Vec<ExtraPart> {
+        vec![
+            ExtraPart {
+                name: "cave",
+                label: "Cave once the sand flows into the abyss",
+            },
+            ExtraPart {
+                name: "floor",
+                label: "Cave with a floor once the sand blocks its entry point",
+            },
+        ]
+    }
+
+    fn extra_part(&self, name: &str) -> Option<Result<Answer, SolutionError>> {
+        let rocks = || self.0.try_get().cloned();
+        match name {
+            "cave" => Some(rocks().map(|rocks| {
+                let mut driver = Driver::new(AbyssCave::new(rocks));
+                driver.run_to_end();
+                Answer::grid(&driver.simulation().render(false))
+            })),
+            "floor" => Some(rocks().map(|rocks| Answer::grid(&render_filled_cave(rocks)))),
+            _ => None,
+        }
+    }
+
+    fn label(&self, part: Part) -> String {
+        match part {
+            Part::One => "Number of resting sand units in cave with abyss",
+            Part::Two => "Number of resting sand units in cave with floor",
+        }
+        .to_string()
+    }
+
+    #[cfg(feature = "visualization")]
+    fn can_visualize(&self) -> bool {
+        true
+    }
+
+    #[cfg(feature = "visualization")]
+    fn visualize(&self) -> Option<String> {
+        let mut driver = Driver::new(AbyssCave::new(self.0.get().clone()));
+        while driver.run(VISUALIZATION_STEP) == VISUALIZATION_STEP {
+            driver.simulation().print();
+            std::thread::sleep(Duration::from_millis(20));
+        }
+        driver.simulation().print();
+        Some(format!(
+            "Number of resting sand units in cave with abyss: {}",
+            driver.simulation().resting_sands()
+        ))
+    }
+}
+
+pub fn solve_part1(input: impl BufRead) -> Result<Answer, SolutionError> {
+    Ok(solve_part_one(&parse_input(&read_all(input)?), &*progress::current()).into())
+}
+
+pub fn solve_part2(input: impl BufRead) -> Result<Answer, SolutionError> {
+    Ok(solve_part_two(&parse_input(&read_all(input)?), &*progress::current()).into())
+}
+
+fn parse_input(input: &[u8]) -> Vec<Rock> {
+    byte_lines(input).parse_lossy().collect()
+}
+
+fn solve_part_one(rocks: &[Rock], progress: &dyn ProgressSink) -> usize {
+    pour_sand(AbyssCave::new(rocks.to_vec()), progress)
+}
+
+fn solve_part_two(rocks: &[Rock], progress: &dyn ProgressSink) -> usize {
+    pour_sand(FloorCave::new(rocks.to_vec()), progress)
+}
+
+/// Number of sand units resting in a cave once it is full.
+fn pour_sand(cave: impl Cave, progress: &dyn ProgressSink) -> usize {
+    progress.start("sand units", None);
+    let mut driver = Driver::new(cave);
+    while driver.step() {
+        progress.advance(1);
+    }
+    progress.finish();
+    let cave = driver.simulation();
+    if tracing::enabled!(Level::DEBUG) {
+        let sands = cave.sands();
+        debug!(
+            "Sand at rest:\n{}",
+            plot(sands, TERMINAL_WIDTH, TERMINAL_HEIGHT)
+        );
+    }
+    cave.resting_sands()
+}
+
+/// A cave filling with sand, a unit falling at each tick.
+trait Cave: Simulation {
+    fn resting_sands(&self) -> usize;
+
+    /// Positions of the sand at rest.
+    fn sands(&self) -> Vec<GridPos>;
+}
+
+/// Where a sand unit tries to fall, in order: down, then down and left, then down and right.
+const FALLS: [Vec2; 3] = [Vec2::DOWN, Vec2::new(-1, -1), Vec2::new(1, -1)];
+
+fn lower_positions(position: GridPos) -> impl Iterator<Item = GridPos> {
+    FALLS
+        .into_iter()
+        .filter_map(move |offset| position.offset(offset))
+}
+
+#[derive(Debug, Clone)]
+enum Line {
+    Horizontal {
+        row: usize,
+        columns: RangeInclusive<usize>,
+    },
+    Vertical {
+        rows: RangeInclusive<usize>,
+        column: usize,
+    },
+}
+
+impl Line {
+    fn new(p1: &GridPos, p2: &GridPos) -> Self {
+        if p1.column == p2.column {
+            Self::Vertical {
+                rows: range(p1.row, p2.row),
+                column: p1.column,
+            }
+        } else {
+            Self::Horizontal {
+                row: p1.row,
+                columns: range(p1.column, p2.column),
+            }
+        }
+    }
+
+    fn all_positions(&self) -> Vec<GridPos> {
+        match self {
+            Self::Horizontal { row, columns } => columns
+                .clone()
+                .map(|column| GridPos::new(*row, column))
+                .collect(),
+            Self::Vertical { rows, column } => {
+                rows.clone().map(|row| GridPos::new(row, *column)).collect()
+            }
+        }
+    }
+}
+
+fn range(a: usize, b: usize) -> RangeInclusive<usize> {
+    if a < b {
+        a..=b
+    } else {
+        b..=a
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Rock(Vec<Line>);
+
+impl FromStr for Rock {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        if let Ok((_, positions)) = all_consuming(separated_list1(tag(" -> "), position))(line) {
+            let lines = positions
+                .iter()
+                .tuple_windows()
+                .map(|(c1, c2)| Line::new(c1, c2))
+                .collect();
+            Ok(Rock(lines))
+        } else {
+            Err(format!("Invalid rock : {line}"))
+        }
+    }
+}
+
+fn position(input: &str) -> IResult<&str, GridPos> {
+    let (input, (x, _, y)) = tuple((
+        map_res(digit1, str::parse),
+        tag(","),
+        map_res(digit1, str::parse),
+    ))(input)?;
+    Ok((input, GridPos::new(y, x)))
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Tile {
+    Rock,
+    Sand,
+}
+
+#[derive(Debug, Clone)]
+struct AbyssCave {
+    tiles: SparseGrid<GridPos, Tile>,
+    abyss: usize,
+    /// Whether the last sand unit flowed into the abyss.
+    overflowing: bool,
+}
+
+impl AbyssCave {
+    fn new(rocks: Vec<Rock>) -> Self {
+        let tiles = rock_positions(rocks)
+            .iter()
+            .map(|&rock| (rock, Tile::Rock))
+            .collect::<SparseGrid<_, _>>();
+        let abyss = tiles.bounds().unwrap().max.1 as usize;
+        Self {
+            tiles,
+            abyss,
+            overflowing: false,
+        }
+    }
+
+    #[cfg(feature = "visualization")]
+    fn print(&self) {
+        print!("{CLEAR_SCREEN}{}", self.render(true));
+    }
+
+    /// Picture of the rocks, the sand and its entry point, colored when `colors` is set.
+    fn render(&self, colors: bool) -> String {
+        let columns = self.tiles.bounds().map_or(0..=0, |bounds| bounds.columns());
+        let bounds = Bounds {
+            min: (columns.start() - 1, 0),
+            max: (columns.end() + 1, self.abyss as isize),
+        };
+        self.tiles.render(bounds, |position, tile| {
+            let cell = match tile {
+                Some(Tile::Rock) => Some(('#', Color::White)),
+                Some(Tile::Sand) => Some(('o', Color::Yellow)),
+                None if position == SAND_ENTRY_POINT => Some(('+', Color::Red)),
+                None => None,
+            };
+            match cell {
+                Some((cell, color)) if colors => color.paint(cell).to_string(),
+                Some((cell, _)) => cell.to_string(),
+                None => " ".to_string(),
+            }
+        })
+    }
+}
+
+static SAND_ENTRY_POINT: GridPos = GridPos::new(0, 500);
+
+impl Simulation for AbyssCave {
+    type Snapshot = (SparseGrid<GridPos, Tile>, bool);
+
+    fn step(&mut self) {
+        let mut sand_unit = SAND_ENTRY_POINT;
+        while let Some(position) = lower_positions(sand_unit).find(|p| !self.tiles.contains(p)) {
+            if position.row >= self.abyss {
+                self.overflowing = true;
+                return;
+            }
+            sand_unit = position;
+        }
+        self.tiles.insert(sand_unit, Tile::Sand);
+    }
+
+    fn is_done(&self) -> bool {
+        self.overflowing || self.tiles.contains(&SAND_ENTRY_POINT)
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        (self.tiles.clone(), self.overflowing)
+    }
+
+    fn restore(&mut self, (tiles, overflowing): Self::Snapshot) {
+        self.tiles = tiles;
+        self.overflowing = overflowing;
+    }
+}
+
+impl Cave for AbyssCave {
+    fn resting_sands(&self) -> usize {
+        self.sands().len()
+    }
+
+    fn sands(&self) -> Vec<GridPos> {
+        self.tiles
+            .iter()
+            .filter(|(_, &tile)| tile == Tile::Sand)
+            .map(|(&position, _)| position)
+            .collect()
+    }
+}
+
+/// Number of sand units falling between two frames of the visualization.
+#[cfg(feature = "visualization")]
+const VISUALIZATION_STEP: usize = 10;
+
+/// Margin added on each side of the sand triangle when deriving the bounds of a dense grid.
+const DENSE_GRID_MARGIN: usize = 2;
+
+/// Occupancy of the cells above the floor, stored in a fixed window of columns.
+#[derive(Debug, Clone)]
+struct DenseGrid {
+    first_column: usize,
+    width: usize,
+    rows: usize,
+    cells: Vec<bool>,
+}
+
+impl DenseGrid {
+    fn new(first_column: usize, width: usize, rows: usize) -> Self {
+        Self {
+            first_column,
+            width,
+            rows,
+            cells: vec![false; width * rows],
+        }
+    }
+
+    /// Sand piles up in a triangle under the entry point, so it cannot spread further than the
+    /// floor depth on each side. Returns `None` when that triangle does not fit on the grid.
+    fn for_floor(floor: usize) -> Option<Self> {
+        let half_width = floor + DENSE_GRID_MARGIN;
+        let first_column = SAND_ENTRY_POINT.column.checked_sub(half_width)?;
+        Some(Self::new(first_column, 2 * half_width + 1, floor))
+    }
+
+    fn index(&self, position: &GridPos) -> Option<usize> {
+        let column = position.column.checked_sub(self.first_column)?;
+        (column < self.width && position.row < self.rows)
+            .then_some(position.row * self.width + column)
+    }
+
+    fn is_on_edge(&self, position: &GridPos) -> bool {
+        position.column <= self.first_column
+            || position.column >= self.first_column + self.width - 1
+    }
+
+    fn contains(&self, position: &GridPos) -> bool {
+        self.index(position).is_some_and(|index| self.cells[index])
+    }
+
+    fn insert(&mut self, position: GridPos) {
+        if let Some(index) = self.index(&position) {
+            self.cells[index] = true;
+        }
+    }
+
+    fn positions(&self) -> impl Iterator<Item = GridPos> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .filter(|(_, occupied)| **occupied)
+            .map(|(index, _)| {
+                GridPos::new(index / self.width, self.first_column + index % self.width)
+            })
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Backend {
+    Dense(DenseGrid),
+    Sparse(PositionSet<GridPos>),
+}
+
+impl Backend {
+    fn contains(&self, position: &GridPos) -> bool {
+        match self {
+            Backend::Dense(grid) => grid.contains(position),
+            Backend::Sparse(positions) => positions.contains(position),
+        }
+    }
+
+    fn insert(&mut self, position: GridPos) {
+        match self {
+            Backend::Dense(grid) => grid.insert(position),
+            Backend::Sparse(positions) => {
+                positions.insert(position);
+            }
+        }
+    }
+
+    fn is_on_edge(&self, position: &GridPos) -> bool {
+        match self {
+            Backend::Dense(grid) => grid.is_on_edge(position),
+            Backend::Sparse(_) => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct FloorCave {
+    rocks: PositionSet<GridPos>,
+    occupied: Backend,
+    sands: usize,
+    floor: usize,
+}
+
+impl FloorCave {
+    fn new(rocks: Vec<Rock>) -> Self {
+        let rocks = rock_positions(rocks);
+        let floor = floor(&rocks);
+        let occupied = match DenseGrid::for_floor(floor) {
+            Some(grid) => Backend::Dense(grid),
+            None => Backend::Sparse(PositionSet::new()),
+        };
+        Self::with_backend(rocks, occupied)
+    }
+
+    fn with_backend(rocks: PositionSet<GridPos>, mut occupied: Backend) -> Self {
+        let floor = floor(&rocks);
+        for rock in &rocks {
+            occupied.insert(*rock);
+        }
+        Self {
+            rocks,
+            occupied,
+            sands: 0,
+            floor,
+        }
+    }
+
+    fn is_occupied(&self, position: &GridPos) -> bool {
+        position.row >= self.floor || self.occupied.contains(position)
+    }
+
+    /// Moves every occupied cell to the sparse backend, which has no bounds.
+    fn fall_back_to_sparse(&mut self) {
+        if let Backend::Dense(grid) = &self.occupied {
+            let positions = grid.positions().chain(self.rocks.iter().copied()).collect();
+            self.occupied = Backend::Sparse(positions);
+        }
+    }
+}
+
+fn rock_positions(rocks: Vec<Rock>) -> PositionSet<GridPos> {
+    rocks
+        .into_iter()
+        .flat_map(|rock| rock.0)
+        .flat_map(|line| line.all_positions())
+        .collect()
+}
+
+fn floor(rocks: &PositionSet<GridPos>) -> usize {
+    rocks.bounds().unwrap().max.1 as usize + 2
+}
+
+/// Positions where sand rests once the cave with a floor is full: a sand unit comes to rest at
+/// each position that one can fall to from the entry point.
+fn filled_positions(rocks: &PositionSet<GridPos>) -> HashSet<GridPos> {
+    let floor = floor(rocks);
+    flood_fill(SAND_ENTRY_POINT, |&position| {
+        lower_positions(position)
+            .filter(|lower| lower.row < floor && !rocks.contains(lower))
+            .collect::<Vec<_>>()
+    })
+}
+
+/// Picture of the rocks, the floor and the sand once it blocks the entry point.
+fn render_filled_cave(rocks: Vec<Rock>) -> String {
+    let rocks = rock_positions(rocks);
+    let floor = floor(&rocks);
+    let mut tiles = filled_positions(&rocks)
+        .into_iter()
+        .map(|sand| (sand, Tile::Sand))
+        .collect::<SparseGrid<_, _>>();
+    tiles.extend(rocks.iter().map(|&rock| (rock, Tile::Rock)));
+    let bounds = tiles.bounds().unwrap();
+    let bounds = Bounds {
+        min: (bounds.min.0, 0),
+        max: (bounds.max.0, floor as isize),
+    };
+    tiles.render(bounds, |position, tile| match tile {
+        Some(Tile::Rock) => '#',
+        Some(Tile::Sand) => 'o',
+        None if position.row == floor => '#',
+        None => ' ',
+    })
+}
+
+impl Simulation for FloorCave {
+    type Snapshot = (Backend, usize);
+
+    fn step(&mut self) {
+        let mut sand_unit = SAND_ENTRY_POINT;
+        while let Some(position) = lower_positions(sand_unit).find(|p| !self.is_occupied(p)) {
+            sand_unit = position;
+            if self.occupied.is_on_edge(&sand_unit) {
+                self.fall_back_to_sparse();
+            }
+        }
+        self.occupied.insert(sand_unit);
+        self.sands += 1;
+    }
+
+    fn is_done(&self) -> bool {
+        self.is_occupied(&SAND_ENTRY_POINT)
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        (self.occupied.clone(), self.sands)
+    }
+
+    fn restore(&mut self, (occupied, sands): Self::Snapshot) {
+        self.occupied = occupied;
+        self.sands = sands;
+    }
+}
+
+impl Cave for FloorCave {
+    fn resting_sands(&self) -> usize {
+        self.sands
+    }
+
+    fn sands(&self) -> Vec<GridPos> {
+        let occupied = match &self.occupied {
+            Backend::Dense(grid) => grid.positions().collect::<Vec<_>>(),
+            Backend::Sparse(positions) => positions.iter().copied().collect(),
+        };
+        occupied
+            .into_iter()
+            .filter(|position| !self.rocks.contains(position))
+            .collect()
+    }
+}
+
+solution_example!(day14, part1 = 24, part2 = 93);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_core::progress::NoProgress;
+
+    fn example_rocks() -> PositionSet<GridPos> {
+        rock_positions(parse_input(EXAMPLE))
+    }
+
+    #[test]
+    fn example_uses_dense_backend() {
+        let cave = FloorCave::new(parse_input(b"498,4 -> 498,6 -> 496,6"));
+
+        assert!(matches!(cave.occupied, Backend::Dense(_)));
+    }
+
+    #[test]
+    fn sparse_backend_when_floor_is_too_deep() {
+        let cave = FloorCave::new(parse_input(b"500,600 -> 501,600"));
+
+        assert!(matches!(cave.occupied, Backend::Sparse(_)));
+    }
+
+    #[test]
+    fn cave_of_the_example() {
+        let day = Day14::new(EXAMPLE.to_vec());
+
+        let Some(Ok(picture)) = day.extra_part("cave") else {
+            panic!("no picture of the cave");
+        };
+
+        assert!(picture.matches(
+            "
+       +
+
+       o
+      ooo
+     #ooo##
+    o#ooo#
+   ###ooo#
+     oooo#
+  o ooooo#
+ #########
+"
+        ));
+    }
+
+    #[test]
+    fn sand_fills_every_position_it_can_fall_to() {
+        assert_eq!(filled_positions(&example_rocks()).len(), 93);
+    }
+
+    #[test]
+    fn filled_cave_of_the_example() {
+        let day = Day14::new(EXAMPLE.to_vec());
+
+        let Some(Ok(picture)) = day.extra_part("floor") else {
+            panic!("no picture of the cave");
+        };
+
+        assert!(picture.matches(
+            "
+          o
+         ooo
+        ooooo
+       ooooooo
+      oo#ooo##o
+     ooo#ooo#ooo
+    oo###ooo#oooo
+   oooo oooo#ooooo
+  oooooooooo#oooooo
+ ooo#########ooooooo
+ooooo       ooooooooo
+#####################
+"
+        ));
+    }
+
+    #[test]
+    fn sparse_backend_matches_example() {
+        let cave = FloorCave::with_backend(example_rocks(), Backend::Sparse(PositionSet::new()));
+
+        assert_eq!(pour_sand(cave, &NoProgress), 93);
+    }
+
+    #[test]
+    fn falls_back_to_sparse_when_sand_reaches_edge() {
+        let cave =
+            FloorCave::with_backend(example_rocks(), Backend::Dense(DenseGrid::new(495, 10, 11)));
+        let mut driver = Driver::new(cave);
+
+        driver.run_to_end();
+
+        assert_eq!(driver.simulation().resting_sands(), 93);
+        assert!(matches!(driver.simulation().occupied, Backend::Sparse(_)));
+    }
+
+    #[test]
+    fn rewound_cave_matches_the_cave_poured_to_the_same_tick() {
+        let mut driver = Driver::new(AbyssCave::new(parse_input(EXAMPLE))).with_snapshots(5);
+        driver.run_to_end();
+
+        assert!(driver.rewind(12));
+
+        let mut poured = Driver::new(AbyssCave::new(parse_input(EXAMPLE)));
+        poured.run(12);
+        assert_eq!(driver.simulation().tiles, poured.simulation().tiles);
+        assert_eq!(driver.simulation().resting_sands(), 12);
+    }
+
+    #[test]
+    fn coordinates_too_large_are_malformed() {
+        let result = "498,4 -> 99999999999999999999999,6".parse::<Rock>();
+
+        assert_eq!(
+            result.err(),
+            Some("Invalid rock : 498,4 -> 99999999999999999999999,6".to_string())
+        );
+    }
+}
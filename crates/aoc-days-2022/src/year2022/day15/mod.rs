@@ -0,0 +1,531 @@
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+use std::io::BufRead;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use aoc_core::geometry::Vec2;
+use aoc_core::input::{
+    byte_lines, read_all, validate_lines, Input, InputSource, ParseError, ParseExt, SplitParse,
+};
+use aoc_core::interval::IntervalSet;
+use aoc_core::parse::parse_line;
+use aoc_core::progress::{self, NoProgress, ProgressSink};
+use aoc_core::report::{Phase, PhaseTimer};
+use aoc_core::{cancel, Answer, Example, Params, Part, Solution, SolutionError};
+
+const ROW: isize = 2_000_000;
+const MAX: isize = 4_000_000;
+
+pub struct Day15 {
+    input: Input<Vec<Sensor>>,
+    row: isize,
+    max: isize,
+    search: Search,
+}
+
+aoc_core::register_solution!(Day15);
+
+impl Day15 {
+    pub fn new(input: impl Into<InputSource>) -> Self {
+        Self::with_zone(Input::new(input, parse_input), ROW, MAX)
+    }
+
+    /// `row` is the row scanned by part one and `max` the largest coordinate searched by part two.
+    fn with_zone(input: Input<Vec<Sensor>>, row: isize, max: isize) -> Self {
+        Self {
+            input,
+            row,
+            max,
+            search: Search::default(),
+        }
+    }
+}
+
+impl Default for Day15 {
+    fn default() -> Self {
+        Self::with_zone(Input::for_day(15, parse_input), ROW, MAX)
+    }
+}
+
+impl Solution for Day15 {
+    fn day(&self) -> u8 {
+        15
+    }
+
+    fn with_input(&self, input: InputSource) -> Box<dyn Solution> {
+        Box::new(Self {
+            search: self.search,
+            ..Self::with_zone(Input::new(input, parse_input), self.row, self.max)
+        })
+    }
+
+    fn parse(&mut self) {
+        self.input.parse();
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![example()]
+    }
+
+    fn with_example_input(&self, input: InputSource) -> Box<dyn Solution> {
+        Box::new(Self {
+            search: self.search,
+            ..Self::with_zone(Input::new(input, parse_input), 10, 20)
+        })
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        self.input.validate()?;
+        validate_lines::<Sensor>(self.input.raw())
+    }
+
+    fn skipped_lines(&self) -> Vec<ParseError> {
+        self.input.skipped().to_vec()
+    }
+
+    fn part_one(&self) -> Result<Answer, SolutionError> {
+        Ok(solve_part_one(self.input.try_get()?, self.row).into())
+    }
+
+    fn part_two(&self) -> Result<Answer, SolutionError> {
+        let progress = progress::current();
+        Ok(solve_part_two(self.input.try_get()?, self.max, self.search, &*progress)?.into())
+    }
+
+    fn implementations(&self) -> Vec<&'static str> {
+        IMPLEMENTATIONS.iter().map(|(name, _)| *name).collect()
+    }
+
+    fn with_implementation(&self, name: &str) -> Option<Box<dyn Solution>> {
+        let &(_, search) = IMPLEMENTATIONS.iter().find(|(known, _)| *known == name)?;
+        let input = Input::new(self.input.source().clone(), parse_input);
+        Some(Box::new(Self {
+            search,
+            ..Self::with_zone(input, self.row, self.max)
+        }))
+    }
+
+    fn expected_part_one(&self) -> Option<Answer> {
+        Some(Answer::Unsigned(5147333))
+    }
+
+    fn expected_part_two(&self) -> Option<Answer> {
+        Some(Answer::Unsigned(13734006908372))
+    }
+
+    fn label(&self, part: Part) -> String {
+        match part {
+            Part::One => format!("Number of coordinates without a beacon on row {}", self.row),
+            Part::Two => "Tuning frequency of distress beacon".to_string(),
+        }
+    }
+
+    fn stats(&self, params: &Params) -> Vec<String> {
+        match params
+            .get("rows")
+            .map(parse_rows)
+            .unwrap_or(Ok(vec![self.row]))
+        {
+            Ok(rows) => RowScanner::new(self.input.get())
+                .excluded_positions_on_rows(rows)
+                .into_iter()
+                .map(|(row, count)| {
+                    format!("Number of coordinates without a beacon on row {row}: {count}")
+                })
+                .collect(),
+            Err(error) => vec![error],
+        }
+    }
+
+    fn phases(&self) -> Vec<Phase> {
+        let mut timer = PhaseTimer::default();
+        let sensors = timer.time("parse", || parse_input(self.input.raw()));
+        timer.time("merge ranges", || {
+            ranges_without_beacon_on_row(&sensors, self.row)
+        });
+        timer.time("scan rows", || {
+            find_missing_beacon_within_zone(&sensors, 0, self.max, &NoProgress).ok()
+        });
+        timer.finish()
+    }
+}
+
+pub fn solve_part1(input: impl BufRead) -> Result<Answer, SolutionError> {
+    Ok(solve_part_one(&parse_input(&read_all(input)?), ROW).into())
+}
+
+pub fn solve_part2(input: impl BufRead) -> Result<Answer, SolutionError> {
+    let sensors = parse_input(&read_all(input)?);
+    Ok(solve_part_two(&sensors, MAX, Search::default(), &*progress::current())?.into())
+}
+
+/// How part two searches the missing beacon.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+enum Search {
+    /// Scans every row of the zone for a position that no sensor excludes.
+    #[default]
+    Scanline,
+    /// Only checks the positions just outside the range of each sensor, as the missing beacon is
+    /// the only position of the zone outside all of them.
+    Perimeter,
+}
+
+const IMPLEMENTATIONS: [(&str, Search); 2] = [
+    ("scanline", Search::Scanline),
+    ("perimeter", Search::Perimeter),
+];
+
+fn parse_input(input: &[u8]) -> Vec<Sensor> {
+    byte_lines(input).parse_lossy().collect()
+}
+
+fn solve_part_one(sensors: &[Sensor], row: isize) -> usize {
+    number_of_coordinates_without_beacon_on_row(sensors, row)
+}
+
+fn solve_part_two(
+    sensors: &[Sensor],
+    max: isize,
+    search: Search,
+    progress: &dyn ProgressSink,
+) -> Result<isize, SolutionError> {
+    let beacon = match search {
+        Search::Scanline => find_missing_beacon_within_zone(sensors, 0, max, progress)?,
+        Search::Perimeter => find_missing_beacon_on_perimeters(sensors, 0, max, progress)?,
+    };
+    beacon
+        .map(|Vec2 { x, y }| x * 4_000_000 + y)
+        .ok_or_else(|| SolutionError::Unexpected("No position left for the beacon".to_string()))
+}
+
+fn number_of_coordinates_without_beacon_on_row(sensors: &[Sensor], row: isize) -> usize {
+    ranges_without_beacon_on_row(sensors, row).len()
+}
+
+fn ranges_without_beacon_on_row(sensors: &[Sensor], row: isize) -> IntervalSet {
+    sensors
+        .iter()
+        .flat_map(|sensor| sensor.coordinates_without_beacon_on_row(row))
+        .collect()
+}
+
+fn parse_rows(rows: &str) -> Result<Vec<isize>, String> {
+    let invalid = |_| format!("Invalid rows: {rows}");
+    if let Some((start, end)) = rows.split_once("..=") {
+        Ok((start.parse().map_err(invalid)?..=end.parse().map_err(invalid)?).collect())
+    } else if let Some((start, end)) = rows.split_once("..") {
+        Ok((start.parse().map_err(invalid)?..end.parse().map_err(invalid)?).collect())
+    } else {
+        rows.split_parsed(',')
+            .map_err(|_| format!("Invalid rows: {rows}"))
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum ZoneEvent {
+    Enter(usize),
+    Leave(usize),
+}
+
+#[derive(Debug, Copy, Clone)]
+struct SensorZone {
+    center: Vec2,
+    radius: isize,
+}
+
+impl SensorZone {
+    fn projection_on_row(&self, row: isize) -> Option<RangeInclusive<isize>> {
+        let n = self.radius - (self.center.y - row).abs();
+        (n >= 0).then(|| self.center.x - n..=self.center.x + n)
+    }
+}
+
+struct RowScanner {
+    zones: Vec<SensorZone>,
+    events: Vec<(isize, ZoneEvent)>,
+    beacons_by_row: HashMap<isize, HashSet<isize>>,
+}
+
+impl RowScanner {
+    fn new(sensors: &[Sensor]) -> Self {
+        let zones = sensors
+            .iter()
+            .map(|sensor| SensorZone {
+                center: sensor.position,
+                radius: sensor.beacon_distance(),
+            })
+            .collect::<Vec<_>>();
+        let events = zones
+            .iter()
+            .enumerate()
+            .flat_map(|(index, zone)| {
+                [
+                    (zone.center.y - zone.radius, ZoneEvent::Enter(index)),
+                    (zone.center.y + zone.radius + 1, ZoneEvent::Leave(index)),
+                ]
+            })
+            .sorted_by_key(|(row, _)| *row)
+            .collect();
+        let mut beacons_by_row = HashMap::<isize, HashSet<isize>>::new();
+        for sensor in sensors {
+            beacons_by_row
+                .entry(sensor.beacon.y)
+                .or_default()
+                .insert(sensor.beacon.x);
+        }
+        Self {
+            zones,
+            events,
+            beacons_by_row,
+        }
+    }
+
+    fn excluded_positions_on_rows(
+        &self,
+        rows: impl IntoIterator<Item = isize>,
+    ) -> Vec<(isize, usize)> {
+        let mut events = self.events.iter().peekable();
+        let mut active_zones = HashSet::new();
+
+        rows.into_iter()
+            .sorted()
+            .dedup()
+            .map(|row| {
+                while let Some((_, event)) = events.next_if(|(event_row, _)| *event_row <= row) {
+                    match event {
+                        ZoneEvent::Enter(index) => active_zones.insert(*index),
+                        ZoneEvent::Leave(index) => active_zones.remove(index),
+                    };
+                }
+                let ranges = active_zones
+                    .iter()
+                    .flat_map(|index| self.zones[*index].projection_on_row(row))
+                    .collect::<IntervalSet>();
+                let beacons = self.beacons_by_row.get(&row).map_or(0, |beacons| {
+                    beacons.iter().filter(|&&x| ranges.contains(x)).count()
+                });
+                (row, ranges.len() - beacons)
+            })
+            .collect()
+    }
+}
+
+/// Rows scanned between two checks of the cancellation of the part and reports of its progress.
+const ROWS_PER_CHECKPOINT: isize = 10_000;
+
+fn find_missing_beacon_within_zone(
+    sensors: &[Sensor],
+    min: isize,
+    max: isize,
+    progress: &dyn ProgressSink,
+) -> Result<Option<Vec2>, SolutionError> {
+    progress.start("rows", Some((max - min + 1) as u64));
+    let beacon = scan_rows(sensors, min, max, progress);
+    progress.finish();
+    beacon
+}
+
+fn scan_rows(
+    sensors: &[Sensor],
+    min: isize,
+    max: isize,
+    progress: &dyn ProgressSink,
+) -> Result<Option<Vec2>, SolutionError> {
+    let mut reported = min;
+    for row in min..=max {
+        if row % ROWS_PER_CHECKPOINT == 0 {
+            cancel::checkpoint()?;
+            progress.advance((row - reported) as u64);
+            reported = row;
+        }
+        // The known beacons are left out of the ranges but cannot be the missing one.
+        let possible_beacon = ranges_without_beacon_on_row(sensors, row)
+            .complement(min..=max)
+            .intervals()
+            .iter()
+            .flat_map(|range| range.clone())
+            .map(|x| Vec2::new(x, row))
+            .find(|position| sensors.iter().all(|sensor| sensor.beacon != *position));
+        if possible_beacon.is_some() {
+            return Ok(possible_beacon);
+        }
+    }
+    Ok(None)
+}
+
+fn find_missing_beacon_on_perimeters(
+    sensors: &[Sensor],
+    min: isize,
+    max: isize,
+    progress: &dyn ProgressSink,
+) -> Result<Option<Vec2>, SolutionError> {
+    progress.start("sensors", Some(sensors.len() as u64));
+    let within_zone = |coordinate: &Vec2| {
+        (min..=max).contains(&coordinate.x) && (min..=max).contains(&coordinate.y)
+    };
+    let mut beacon = None;
+    for sensor in sensors {
+        cancel::checkpoint()?;
+        beacon = sensor
+            .perimeter()
+            .filter(within_zone)
+            .find(|coordinate| sensors.iter().all(|other| !other.excludes(coordinate)));
+        progress.advance(1);
+        if beacon.is_some() {
+            break;
+        }
+    }
+    progress.finish();
+    Ok(beacon)
+}
+
+struct Sensor {
+    position: Vec2,
+    beacon: Vec2,
+}
+
+impl Sensor {
+    fn beacon_distance(&self) -> isize {
+        self.position.manhattan(&self.beacon)
+    }
+
+    /// Whether the beacon cannot be at `coordinate`, as it is as close as the beacon of the sensor.
+    fn excludes(&self, coordinate: &Vec2) -> bool {
+        self.position.manhattan(coordinate) <= self.beacon_distance()
+    }
+
+    /// The positions just farther than the beacon of the sensor.
+    fn perimeter(&self) -> impl Iterator<Item = Vec2> + '_ {
+        let radius = self.beacon_distance() + 1;
+        (-radius..=radius).flat_map(move |dx| {
+            let dy = radius - dx.abs();
+            [
+                Vec2::new(self.position.x + dx, self.position.y - dy),
+                Vec2::new(self.position.x + dx, self.position.y + dy),
+            ]
+        })
+    }
+
+    fn distance_with_row(&self, y: isize) -> isize {
+        (self.position.y - y).abs()
+    }
+
+    fn coordinates_without_beacon_on_row(&self, row: isize) -> Option<RangeInclusive<isize>> {
+        let distance_with_row = self.distance_with_row(row);
+        let beacon_distance = self.beacon_distance();
+        if distance_with_row < beacon_distance {
+            let n = beacon_distance - distance_with_row;
+            let range_start = self.position.x - n;
+            let range_end = self.position.x + n;
+            if self.beacon.y == row {
+                if self.beacon.x == range_start {
+                    Some(range_start + 1..=range_end)
+                } else {
+                    Some(range_start..=range_end - 1)
+                }
+            } else {
+                Some(range_start..=range_end)
+            }
+        } else {
+            None
+        }
+    }
+}
+
+impl FromStr for Sensor {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        if let Ok(("", (x, y, beacon_x, beacon_y))) = parse_line!(
+            "Sensor at x={isize}, y={isize}: closest beacon is at x={isize}, y={isize}"
+        )(line)
+        {
+            Ok(Sensor {
+                position: Vec2::new(x, y),
+                beacon: Vec2::new(beacon_x, beacon_y),
+            })
+        } else {
+            Err(format!("Invalid sensor: {line}"))
+        }
+    }
+}
+
+solution_example!(
+    day15,
+    part1(10) = 26,
+    part2(20, Search::Scanline, &NoProgress) = 56000011
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_core::input::for_day;
+    use std::sync::LazyLock;
+
+    static EXAMPLE_SENSORS: LazyLock<Vec<Sensor>> =
+        LazyLock::new(|| byte_lines(EXAMPLE).parse_or_log().collect());
+
+    #[test]
+    fn test_coordinates_without_beacon_on_row() {
+        let sensor = Sensor {
+            position: Vec2::new(8, 7),
+            beacon: Vec2::new(2, 10),
+        };
+
+        let result = sensor.coordinates_without_beacon_on_row(10);
+
+        assert_eq!(result, Some(3..=14));
+    }
+
+    #[test]
+    fn test_input_merged_ranges_for_2_000_000() {
+        let result = ranges_without_beacon_on_row(&parse_input(&for_day(15).unwrap()), ROW);
+
+        assert_eq!(result.intervals(), [-609345..=1374834, 1374836..=4537988]);
+    }
+
+    #[test]
+    fn example_excluded_positions_on_rows() {
+        let scanner = RowScanner::new(&EXAMPLE_SENSORS);
+
+        let result = scanner.excluded_positions_on_rows([11, 9, 10]);
+
+        assert_eq!(result, vec![(9, 25), (10, 26), (11, 28)]);
+    }
+
+    #[test]
+    fn excluded_positions_on_rows_matches_brute_force() {
+        let scanner = RowScanner::new(&EXAMPLE_SENSORS);
+
+        let result = scanner.excluded_positions_on_rows(-15..40);
+
+        for (row, count) in result {
+            let expected = (-50..50)
+                .map(|x| Vec2::new(x, row))
+                .filter(|c| EXAMPLE_SENSORS.iter().all(|sensor| sensor.beacon != *c))
+                .filter(|c| {
+                    EXAMPLE_SENSORS
+                        .iter()
+                        .any(|sensor| sensor.position.manhattan(c) <= sensor.beacon_distance())
+                })
+                .count();
+            assert_eq!(count, expected, "row {row}");
+        }
+    }
+
+    #[test]
+    fn perimeter_search_finds_the_beacon_of_the_example() {
+        let result = solve_part_two(&EXAMPLE_SENSORS, 20, Search::Perimeter, &NoProgress);
+
+        assert_eq!(result, Ok(56000011));
+    }
+
+    #[test]
+    fn test_parse_rows() {
+        assert_eq!(parse_rows("1..4"), Ok(vec![1, 2, 3]));
+        assert_eq!(parse_rows("-1..=1"), Ok(vec![-1, 0, 1]));
+        assert_eq!(parse_rows("10, 2000000"), Ok(vec![10, 2_000_000]));
+        assert!(parse_rows("a..3").is_err());
+    }
+}
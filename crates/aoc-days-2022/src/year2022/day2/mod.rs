@@ -1,36 +1,49 @@
-use lazy_static::lazy_static;
 use std::str::FromStr;
 
-use crate::input::{read_lines, FilterNotEmpty, ParseExt};
-use crate::Solution;
-
-mod input;
+use aoc_core::anonymize::Rng;
+use aoc_core::input::{byte_lines, read_lines, FilterNotEmpty, ParseError, ParseExt, SplitParse};
+
+day!(
+    2,
+    "Rock Paper Scissors",
+    try parse_input,
+    solve_part_one,
+    solve_part_two,
+    part1 = 15,
+    part2 = 12,
+    expected = (10718_u32, 14652_u32),
+    labels = (
+        "My score after playing all rounds",
+        "My score after playing all rounds according to the Elf's strategy"
+    ),
+);
+
+fn parse_input(input: &[u8]) -> Result<Vec<GuideLine>, ParseError> {
+    byte_lines(input).try_parse().collect()
+}
 
-lazy_static! {
-    static ref LINES: Vec<String> = read_lines(input::INPUT).filter_not_empty().collect();
+fn solve_part_one(lines: &[GuideLine]) -> u32 {
+    play_game(lines.iter().map(|line| line.round)).1
 }
 
-pub struct Day2;
+fn solve_part_two(lines: &[GuideLine]) -> u32 {
+    play_game(lines.iter().map(|line| line.strategy.into())).1
+}
 
-impl Solution for Day2 {
-    fn day(&self) -> u8 {
-        2
-    }
+/// A line of the strategy guide, read both as a round and as a strategy.
+struct GuideLine {
+    round: Round,
+    strategy: Strategy,
+}
 
-    fn part_one(&self) -> String {
-        let rounds = LINES.iter().parse();
-        format!("My score after playing all rounds: {}", play_game(rounds).1,)
-    }
+impl FromStr for GuideLine {
+    type Err = String;
 
-    fn part_two(&self) -> String {
-        let rounds = LINES
-            .iter()
-            .parse::<Strategy>()
-            .map(|strategy| strategy.into());
-        format!(
-            "My score after playing all rounds according to the Elf's strategy: {}",
-            play_game(rounds).1,
-        )
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            round: line.parse()?,
+            strategy: line.parse()?,
+        })
     }
 }
 
@@ -73,6 +86,7 @@ impl FromStr for Shape {
     }
 }
 
+#[derive(Copy, Clone)]
 enum Outcome {
     Loss,
     Draw,
@@ -92,6 +106,7 @@ impl FromStr for Outcome {
     }
 }
 
+#[derive(Copy, Clone)]
 struct Strategy {
     player1_shape: Shape,
     player2_outcome: Outcome,
@@ -101,17 +116,15 @@ impl FromStr for Strategy {
     type Err = String;
 
     fn from_str(line: &str) -> Result<Self, Self::Err> {
-        if let Some((shape, outcome)) = line.split_once(' ') {
-            Ok(Strategy {
-                player1_shape: shape.parse()?,
-                player2_outcome: outcome.parse()?,
-            })
-        } else {
-            Err(format!("The line does not contain two shapes: {line}"))
-        }
+        let (player1_shape, player2_outcome) = line.split_on(' ')?;
+        Ok(Strategy {
+            player1_shape,
+            player2_outcome,
+        })
     }
 }
 
+#[derive(Copy, Clone)]
 struct Round {
     player1_shape: Shape,
     player2_shape: Shape,
@@ -158,14 +171,11 @@ impl FromStr for Round {
     type Err = String;
 
     fn from_str(line: &str) -> Result<Self, Self::Err> {
-        if let Some((first, second)) = line.split_once(' ') {
-            Ok(Round {
-                player1_shape: first.parse()?,
-                player2_shape: second.parse()?,
-            })
-        } else {
-            Err(format!("The line does not contain two shapes: {line}"))
-        }
+        let (player1_shape, player2_shape) = line.split_on(' ')?;
+        Ok(Round {
+            player1_shape,
+            player2_shape,
+        })
     }
 }
 
@@ -177,35 +187,34 @@ fn play_game(rounds: impl IntoIterator<Item = Round>) -> (u32, u32) {
         .unwrap_or((0, 0))
 }
 
+/// Replaces every round with a random one.
+pub(crate) fn anonymize_input(input: &[u8], rng: &mut Rng) -> Result<String, String> {
+    Ok(read_lines(input)
+        .filter_not_empty()
+        .map(|_| {
+            format!(
+                "{} {}\n",
+                rng.choose(&['A', 'B', 'C']),
+                rng.choose(&['X', 'Y', 'Z']),
+            )
+        })
+        .collect())
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::input::read_lines;
-
-    static EXAMPLE: &str = r"
-A Y
-B X
-C Z
-";
-
-    #[test]
-    fn part1_example() {
-        let rounds = read_lines(EXAMPLE.as_bytes()).filter_not_empty().parse();
-
-        let result = play_game(rounds);
-
-        assert_eq!(result, (15, 15));
-    }
+    use aoc_core::SolutionError;
 
     #[test]
-    fn part2_example() {
-        let rounds = read_lines(EXAMPLE.as_bytes())
-            .filter_not_empty()
-            .parse::<Strategy>()
-            .map(|strategy| strategy.into());
-
-        let result = play_game(rounds);
-
-        assert_eq!(result, (15, 12));
+    fn malformed_rounds_fail_the_parts() {
+        let result = solve_part1(&b"A Y\nQ Z\n"[..]);
+
+        assert_eq!(
+            result,
+            Err(SolutionError::Input(
+                "Line 2: Invalid value \"Q\": Unknown shape: Q".to_string()
+            ))
+        );
     }
 }
@@ -0,0 +1,266 @@
+use std::io::BufRead;
+
+use aoc_core::cancel;
+use aoc_core::input::{
+    byte_lines, read_all, validate_lines, Input, InputSource, ParseError, ParseExt,
+};
+use aoc_core::{Answer, Example, Part, Solution, SolutionError};
+
+const DECRYPTION_KEY: i64 = 811_589_153;
+const DECRYPTION_ROUNDS: usize = 10;
+/// Positions after the 0 of the numbers summed into the grove coordinates.
+const GROVE_COORDINATE_OFFSETS: [usize; 3] = [1000, 2000, 3000];
+
+pub struct Day20(Input<Result<Vec<i64>, ParseError>>, Mixing);
+
+aoc_core::register_solution!(Day20);
+
+impl Day20 {
+    pub fn new(input: impl Into<InputSource>) -> Self {
+        Self(Input::new(input, parse_input), Mixing::default())
+    }
+}
+
+impl Default for Day20 {
+    fn default() -> Self {
+        Self(Input::for_day(20, parse_input), Mixing::default())
+    }
+}
+
+impl Solution for Day20 {
+    fn day(&self) -> u8 {
+        20
+    }
+
+    fn with_input(&self, input: InputSource) -> Box<dyn Solution> {
+        Box::new(Self(Input::new(input, parse_input), self.1))
+    }
+
+    fn parse(&mut self) {
+        self.0.parse();
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![example()]
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        self.0.validate()?;
+        validate_lines::<i64>(self.0.raw())
+    }
+
+    fn part_one(&self) -> Result<Answer, SolutionError> {
+        Ok(solve_part_one(self.0.try_get_parsed()?, self.1)?.into())
+    }
+
+    fn part_two(&self) -> Result<Answer, SolutionError> {
+        Ok(solve_part_two(self.0.try_get_parsed()?, self.1)?.into())
+    }
+
+    fn implementations(&self) -> Vec<&'static str> {
+        IMPLEMENTATIONS.iter().map(|(name, _)| *name).collect()
+    }
+
+    fn with_implementation(&self, name: &str) -> Option<Box<dyn Solution>> {
+        let &(_, mixing) = IMPLEMENTATIONS.iter().find(|(known, _)| *known == name)?;
+        let input = Input::new(self.0.source().clone(), parse_input);
+        Some(Box::new(Self(input, mixing)))
+    }
+
+    fn label(&self, part: Part) -> String {
+        match part {
+            Part::One => "Sum of the grove coordinates",
+            Part::Two => "Sum of the grove coordinates after decryption",
+        }
+        .to_string()
+    }
+}
+
+pub fn solve_part1(input: impl BufRead) -> Result<Answer, SolutionError> {
+    Ok(solve_part_one(&parse_input(&read_all(input)?)?, Mixing::default())?.into())
+}
+
+pub fn solve_part2(input: impl BufRead) -> Result<Answer, SolutionError> {
+    Ok(solve_part_two(&parse_input(&read_all(input)?)?, Mixing::default())?.into())
+}
+
+/// How the numbers are moved around the circle.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+enum Mixing {
+    /// Moves the positions in the file of the numbers within a vector in the order of the circle,
+    /// shifting the ones in between.
+    #[default]
+    IndexVector,
+    /// Unlinks each number from a circular doubly linked list and walks to its new place, in the
+    /// shorter direction.
+    LinkedList,
+}
+
+const IMPLEMENTATIONS: [(&str, Mixing); 2] = [
+    ("index-vector", Mixing::IndexVector),
+    ("linked-list", Mixing::LinkedList),
+];
+
+/// Every number moves the others when mixed, so a skipped line would change all the coordinates.
+fn parse_input(input: &[u8]) -> Result<Vec<i64>, ParseError> {
+    byte_lines(input).try_parse().collect()
+}
+
+fn solve_part_one(numbers: &[i64], mixing: Mixing) -> Result<i64, SolutionError> {
+    grove_coordinates(&mix(numbers, 1, mixing)?)
+}
+
+fn solve_part_two(numbers: &[i64], mixing: Mixing) -> Result<i64, SolutionError> {
+    let numbers = numbers
+        .iter()
+        .map(|number| number * DECRYPTION_KEY)
+        .collect::<Vec<_>>();
+    grove_coordinates(&mix(&numbers, DECRYPTION_ROUNDS, mixing)?)
+}
+
+/// The numbers in the order of the circle after moving each of them by its value, in the order of
+/// the file, `rounds` times. The numbers are told apart by their position in the file, so that
+/// duplicates each move once.
+fn mix(numbers: &[i64], rounds: usize, mixing: Mixing) -> Result<Vec<i64>, SolutionError> {
+    if numbers.len() < 2 {
+        return Ok(numbers.to_vec());
+    }
+    let order = match mixing {
+        Mixing::IndexVector => mix_index_vector(numbers, rounds)?,
+        Mixing::LinkedList => mix_linked_list(numbers, rounds)?,
+    };
+    Ok(order.into_iter().map(|index| numbers[index]).collect())
+}
+
+/// Positions in the file of the numbers in the order of the circle.
+fn mix_index_vector(numbers: &[i64], rounds: usize) -> Result<Vec<usize>, SolutionError> {
+    // A number moving around the circle skips the other ones only.
+    let others = numbers.len() as i64 - 1;
+    let mut order = (0..numbers.len()).collect::<Vec<_>>();
+    for _ in 0..rounds {
+        cancel::checkpoint()?;
+        for (index, number) in numbers.iter().enumerate() {
+            let from = order
+                .iter()
+                .position(|&other| other == index)
+                .expect("Every number is in the circle");
+            order.remove(from);
+            let to = (from as i64 + number).rem_euclid(others) as usize;
+            order.insert(to, index);
+        }
+    }
+    Ok(order)
+}
+
+/// Positions in the file of the numbers in the order of the circle, starting from the first one.
+fn mix_linked_list(numbers: &[i64], rounds: usize) -> Result<Vec<usize>, SolutionError> {
+    let len = numbers.len();
+    let others = len as i64 - 1;
+    let mut next = (0..len).map(|index| (index + 1) % len).collect::<Vec<_>>();
+    let mut previous = (0..len)
+        .map(|index| (index + len - 1) % len)
+        .collect::<Vec<_>>();
+    for _ in 0..rounds {
+        cancel::checkpoint()?;
+        for (index, number) in numbers.iter().enumerate() {
+            let steps = number.rem_euclid(others) as usize;
+            if steps == 0 {
+                continue;
+            }
+            next[previous[index]] = next[index];
+            previous[next[index]] = previous[index];
+            let mut after = previous[index];
+            if steps <= len / 2 {
+                for _ in 0..steps {
+                    after = next[after];
+                }
+            } else {
+                for _ in steps..len - 1 {
+                    after = previous[after];
+                }
+            }
+            let before = next[after];
+            next[after] = index;
+            previous[index] = after;
+            next[index] = before;
+            previous[before] = index;
+        }
+    }
+    let mut order = Vec::with_capacity(len);
+    let mut index = 0;
+    for _ in 0..len {
+        order.push(index);
+        index = next[index];
+    }
+    Ok(order)
+}
+
+fn grove_coordinates(mixed: &[i64]) -> Result<i64, SolutionError> {
+    let zero = mixed
+        .iter()
+        .position(|&number| number == 0)
+        .ok_or_else(|| SolutionError::Unexpected("No 0 in the file".to_string()))?;
+    Ok(GROVE_COORDINATE_OFFSETS
+        .iter()
+        .map(|offset| mixed[(zero + offset) % mixed.len()])
+        .sum())
+}
+
+solution_example!(day20, part1 = 3, part2 = 1623178306);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn implementations_agree_on_the_example() {
+        let numbers = parse_input(EXAMPLE).unwrap();
+
+        for (name, mixing) in IMPLEMENTATIONS {
+            assert_eq!(solve_part_one(&numbers, mixing), Ok(3), "{name}");
+            assert_eq!(solve_part_two(&numbers, mixing), Ok(1623178306), "{name}");
+        }
+    }
+
+    #[test]
+    fn mix_the_example_once() {
+        let numbers = parse_input(EXAMPLE).unwrap();
+
+        for (name, mixing) in IMPLEMENTATIONS {
+            let mixed = mix(&numbers, 1, mixing).unwrap();
+
+            assert_eq!(from_zero(&mixed), vec![0, 3, -2, 1, 2, -3, 4], "{name}");
+        }
+    }
+
+    #[test]
+    fn duplicates_each_move_once() {
+        let numbers = [1, 1, 0, -2, 3];
+
+        for (name, mixing) in IMPLEMENTATIONS {
+            let mixed = mix(&numbers, 1, mixing).unwrap();
+
+            assert_eq!(from_zero(&mixed), vec![0, 1, -2, 1, 3], "{name}");
+        }
+    }
+
+    /// The numbers of a circle starting from 0, as both implementations start it elsewhere.
+    fn from_zero(mixed: &[i64]) -> Vec<i64> {
+        let zero = mixed.iter().position(|&number| number == 0).unwrap();
+        mixed[zero..]
+            .iter()
+            .chain(&mixed[..zero])
+            .copied()
+            .collect()
+    }
+
+    #[test]
+    fn malformed_numbers_fail_the_parts() {
+        let result = solve_part1(&b"1\n2\n-3\nthree\n0\n"[..]);
+
+        assert!(
+            matches!(&result, Err(SolutionError::Input(error)) if error.starts_with("Line 4")),
+            "{result:?}"
+        );
+    }
+}
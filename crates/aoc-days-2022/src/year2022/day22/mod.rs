@@ -0,0 +1,532 @@
+use std::io::BufRead;
+
+use aoc_core::geometry::{GridPos, Vec3};
+use aoc_core::input::{read_all, sections, Input, InputSource};
+use aoc_core::{Answer, Example, Part, Solution, SolutionError};
+
+const OPEN: u8 = b'.';
+const WALL: u8 = b'#';
+/// Tiles beyond the board, also found past the end of the shorter lines.
+const VOID: u8 = b' ';
+
+pub struct Day22(Input<Notes>);
+
+aoc_core::register_solution!(Day22);
+
+impl Day22 {
+    pub fn new(input: impl Into<InputSource>) -> Self {
+        Self(Input::new(input, parse_input))
+    }
+}
+
+impl Default for Day22 {
+    fn default() -> Self {
+        Self(Input::for_day(22, parse_input))
+    }
+}
+
+impl Solution for Day22 {
+    fn day(&self) -> u8 {
+        22
+    }
+
+    fn with_input(&self, input: InputSource) -> Box<dyn Solution> {
+        Box::new(Self::new(input))
+    }
+
+    fn parse(&mut self) {
+        self.0.parse();
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![example()]
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        self.0.validate()?;
+        let [board, path] = sections(self.0.raw(), SECTIONS);
+        board.require()?;
+        path.require()?;
+        tokenize(path.input)?;
+        Cube::fold(&parse_board(board.lines()))?;
+        Ok(())
+    }
+
+    fn part_one(&self) -> Result<Answer, SolutionError> {
+        Ok(solve_part_one(self.0.try_get()?)?.into())
+    }
+
+    fn part_two(&self) -> Result<Answer, SolutionError> {
+        Ok(solve_part_two(self.0.try_get()?)?.into())
+    }
+
+    fn label(&self, part: Part) -> String {
+        match part {
+            Part::One => "Final password",
+            Part::Two => "Final password on the cube",
+        }
+        .to_string()
+    }
+}
+
+pub fn solve_part1(input: impl BufRead) -> Result<Answer, SolutionError> {
+    Ok(solve_part_one(&parse_input(&read_all(input)?))?.into())
+}
+
+pub fn solve_part2(input: impl BufRead) -> Result<Answer, SolutionError> {
+    Ok(solve_part_two(&parse_input(&read_all(input)?))?.into())
+}
+
+const SECTIONS: [&str; 2] = ["board", "path"];
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Notes {
+    board: Board,
+    /// The instructions, or why the path cannot be read.
+    path: Result<Vec<Instruction>, String>,
+}
+
+fn parse_input(input: &[u8]) -> Notes {
+    let [board, path] = sections(input, SECTIONS);
+    Notes {
+        board: parse_board(board.lines()),
+        path: tokenize(path.input),
+    }
+}
+
+fn solve_part_one(notes: &Notes) -> Result<usize, SolutionError> {
+    let board = &notes.board;
+    walk(board, notes.path()?, |position, facing| {
+        wrap_flat(board, position, facing)
+    })
+}
+
+fn solve_part_two(notes: &Notes) -> Result<usize, SolutionError> {
+    let cube = Cube::fold(&notes.board).map_err(SolutionError::Unexpected)?;
+    walk(&notes.board, notes.path()?, |position, facing| {
+        cube.wrap(position, facing)
+    })
+}
+
+impl Notes {
+    fn path(&self) -> Result<&[Instruction], SolutionError> {
+        self.path
+            .as_deref()
+            .map_err(|error| SolutionError::Input(error.clone()))
+    }
+}
+
+/// Follows the path from the leftmost open tile of the top row, facing right, and returns the
+/// password of the final position. `wrap` gives where a step leaving the board lands.
+fn walk(
+    board: &Board,
+    path: &[Instruction],
+    wrap: impl Fn(GridPos, Facing) -> (GridPos, Facing),
+) -> Result<usize, SolutionError> {
+    let mut position = board
+        .start()
+        .ok_or_else(|| SolutionError::Unexpected("No open tile on the top row".to_string()))?;
+    let mut facing = Facing::Right;
+    for instruction in path {
+        match *instruction {
+            Instruction::Turn(turn) => facing = facing.turn(turn),
+            Instruction::Forward(steps) => {
+                for _ in 0..steps {
+                    let next = facing
+                        .step(position)
+                        .filter(|&next| board.tile(next).is_some())
+                        .map_or_else(|| wrap(position, facing), |next| (next, facing));
+                    if board.tile(next.0) == Some(WALL) {
+                        break;
+                    }
+                    (position, facing) = next;
+                }
+            }
+        }
+    }
+    Ok(1000 * (position.row + 1) + 4 * (position.column + 1) + facing as usize)
+}
+
+/// Wraps around to the other side of the row or column, as on a flat board.
+fn wrap_flat(board: &Board, position: GridPos, facing: Facing) -> (GridPos, Facing) {
+    let back = facing.reverse();
+    let mut position = position;
+    while let Some(previous) = back
+        .step(position)
+        .filter(|&previous| board.tile(previous).is_some())
+    {
+        position = previous;
+    }
+    (position, facing)
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Board {
+    rows: Vec<Vec<u8>>,
+}
+
+fn parse_board<'a>(lines: impl Iterator<Item = &'a [u8]>) -> Board {
+    Board {
+        rows: lines.map(<[u8]>::to_vec).collect(),
+    }
+}
+
+impl Board {
+    /// The open tile or wall at a position, `None` beyond the board.
+    fn tile(&self, position: GridPos) -> Option<u8> {
+        self.rows
+            .get(position.row)?
+            .get(position.column)
+            .copied()
+            .filter(|&tile| tile != VOID)
+    }
+
+    fn start(&self) -> Option<GridPos> {
+        let column = self.rows.first()?.iter().position(|&tile| tile == OPEN)?;
+        Some(GridPos::new(0, column))
+    }
+
+    fn tiles(&self) -> usize {
+        self.rows
+            .iter()
+            .flatten()
+            .filter(|&&tile| tile != VOID)
+            .count()
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Instruction {
+    Forward(usize),
+    Turn(Turn),
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Turn {
+    Left,
+    Right,
+}
+
+/// Splits a path such as `10R5L5` into its numbers of steps and its turns.
+fn tokenize(path: &[u8]) -> Result<Vec<Instruction>, String> {
+    let mut instructions = Vec::new();
+    let mut steps = None;
+    for &byte in path.trim_ascii() {
+        let turn = match byte {
+            b'0'..=b'9' => {
+                steps = Some(steps.unwrap_or(0) * 10 + usize::from(byte - b'0'));
+                continue;
+            }
+            b'L' => Turn::Left,
+            b'R' => Turn::Right,
+            _ => return Err(format!("Invalid path instruction {:?}", char::from(byte))),
+        };
+        instructions.extend(steps.take().map(Instruction::Forward));
+        instructions.push(Instruction::Turn(turn));
+    }
+    instructions.extend(steps.map(Instruction::Forward));
+    Ok(instructions)
+}
+
+/// Where the password points to, with the value it adds to the password.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Facing {
+    Right = 0,
+    Down = 1,
+    Left = 2,
+    Up = 3,
+}
+
+impl Facing {
+    /// Clockwise from the right.
+    const ALL: [Facing; 4] = [Facing::Right, Facing::Down, Facing::Left, Facing::Up];
+
+    fn turn(self, turn: Turn) -> Self {
+        let quarters = match turn {
+            Turn::Right => 1,
+            Turn::Left => 3,
+        };
+        Self::ALL[(self as usize + quarters) % 4]
+    }
+
+    fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    fn step(self, position: GridPos) -> Option<GridPos> {
+        match self {
+            Facing::Right => Some(position.right()),
+            Facing::Down => Some(position.down()),
+            Facing::Left => position.left(),
+            Facing::Up => position.up(),
+        }
+    }
+}
+
+/// A square of the board once folded, with the directions in space of its outside and of its
+/// columns and rows.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct Face {
+    /// Top left tile of the face on the board.
+    origin: GridPos,
+    normal: Vec3,
+    right: Vec3,
+    down: Vec3,
+}
+
+impl Face {
+    fn direction(&self, facing: Facing) -> Vec3 {
+        match facing {
+            Facing::Right => self.right,
+            Facing::Down => self.down,
+            Facing::Left => -self.right,
+            Facing::Up => -self.down,
+        }
+    }
+
+    /// The face next to this one on the board, folded over their common edge.
+    fn fold(&self, facing: Facing, origin: GridPos) -> Self {
+        let Self {
+            normal,
+            right,
+            down,
+            ..
+        } = *self;
+        let (normal, right, down) = match facing {
+            Facing::Right => (right, -normal, down),
+            Facing::Left => (-right, normal, down),
+            Facing::Down => (down, right, -normal),
+            Facing::Up => (-down, right, normal),
+        };
+        Self {
+            origin,
+            normal,
+            right,
+            down,
+        }
+    }
+}
+
+/// The board folded into a cube, whatever the shape of its net.
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Cube {
+    /// Number of tiles along an edge.
+    size: usize,
+    faces: Vec<Face>,
+}
+
+impl Cube {
+    /// Folds the squares of the board around the one of the start, which ends up on top.
+    fn fold(board: &Board) -> Result<Self, String> {
+        let not_a_cube = || "The board does not fold into a cube".to_string();
+        let tiles = board.tiles();
+        let size = (1..)
+            .take_while(|size| 6 * size * size <= tiles)
+            .last()
+            .filter(|size| 6 * size * size == tiles)
+            .ok_or_else(not_a_cube)?;
+        let start = board.start().ok_or_else(not_a_cube)?;
+        let mut faces = vec![Face {
+            origin: GridPos::new(0, start.column / size * size),
+            normal: Vec3::Z,
+            right: Vec3::X,
+            down: Vec3::Y,
+        }];
+        let mut folded = 0;
+        while let Some(&face) = faces.get(folded) {
+            for facing in Facing::ALL {
+                let neighbor = match facing {
+                    Facing::Right => Some(GridPos::new(face.origin.row, face.origin.column + size)),
+                    Facing::Down => Some(GridPos::new(face.origin.row + size, face.origin.column)),
+                    Facing::Left => face
+                        .origin
+                        .column
+                        .checked_sub(size)
+                        .map(|column| GridPos::new(face.origin.row, column)),
+                    Facing::Up => face
+                        .origin
+                        .row
+                        .checked_sub(size)
+                        .map(|row| GridPos::new(row, face.origin.column)),
+                };
+                let Some(neighbor) = neighbor.filter(|&origin| board.tile(origin).is_some()) else {
+                    continue;
+                };
+                if faces.iter().all(|face| face.origin != neighbor) {
+                    faces.push(face.fold(facing, neighbor));
+                }
+            }
+            folded += 1;
+        }
+        let sides = faces.iter().filter(|face| {
+            faces
+                .iter()
+                .filter(|other| other.normal == face.normal)
+                .count()
+                == 1
+        });
+        if faces.len() != 6 || sides.count() != 6 {
+            return Err(not_a_cube());
+        }
+        Ok(Self { size, faces })
+    }
+
+    fn face_of(&self, position: GridPos) -> &Face {
+        let origin = GridPos::new(
+            position.row / self.size * self.size,
+            position.column / self.size * self.size,
+        );
+        self.faces
+            .iter()
+            .find(|face| face.origin == origin)
+            .expect("Every tile of the board is on a face")
+    }
+
+    fn face_towards(&self, normal: Vec3) -> &Face {
+        self.faces
+            .iter()
+            .find(|face| face.normal == normal)
+            .expect("A cube has a face on each side")
+    }
+
+    /// Moves over the edge of the face of a position to the adjacent face of the cube, which
+    /// turns the facing as the board is flat again.
+    fn wrap(&self, position: GridPos, facing: Facing) -> (GridPos, Facing) {
+        let size = self.size as isize;
+        let face = self.face_of(position);
+        // Tile centers in space, in half tiles from the center of the cube.
+        let to_space = |index: usize, origin: usize| 2 * (index - origin) as isize + 1 - size;
+        let center = face.normal * size
+            + face.right * to_space(position.column, face.origin.column)
+            + face.down * to_space(position.row, face.origin.row);
+        let direction = face.direction(facing);
+        let target = self.face_towards(direction);
+        let center = center + direction - face.normal;
+        let to_board =
+            |coordinate: isize, origin: usize| origin + ((coordinate + size - 1) / 2) as usize;
+        let position = GridPos::new(
+            to_board(center.dot(&target.down), target.origin.row),
+            to_board(center.dot(&target.right), target.origin.column),
+        );
+        let away = -face.normal;
+        let facing = Facing::ALL
+            .into_iter()
+            .find(|&facing| target.direction(facing) == away)
+            .expect("The edge between two faces is along one of their sides");
+        (position, facing)
+    }
+}
+
+solution_example!(day22, part1 = 6032, part2 = 5031);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn tokenize_path() {
+        assert_eq!(
+            tokenize(b"10R5L12\n"),
+            Ok(vec![
+                Instruction::Forward(10),
+                Instruction::Turn(Turn::Right),
+                Instruction::Forward(5),
+                Instruction::Turn(Turn::Left),
+                Instruction::Forward(12),
+            ])
+        );
+        assert_eq!(
+            tokenize(b"10X"),
+            Err("Invalid path instruction 'X'".to_string())
+        );
+    }
+
+    #[test]
+    fn wrap_around_the_example_cube() {
+        let notes = parse_input(EXAMPLE);
+        let cube = Cube::fold(&notes.board).unwrap();
+
+        assert_eq!(cube.size, 4);
+        assert_eq!(
+            cube.wrap(GridPos::new(5, 11), Facing::Right),
+            (GridPos::new(8, 14), Facing::Down)
+        );
+        assert_eq!(
+            cube.wrap(GridPos::new(11, 10), Facing::Down),
+            (GridPos::new(7, 1), Facing::Up)
+        );
+    }
+
+    #[test]
+    fn wrap_around_the_cube_of_the_inputs() {
+        let cube = Cube::fold(&net_board(&[".##", ".#.", "##.", "#.."], 3)).unwrap();
+
+        assert_eq!(
+            cube.wrap(GridPos::new(0, 4), Facing::Up),
+            (GridPos::new(10, 0), Facing::Right)
+        );
+        assert_eq!(
+            cube.wrap(GridPos::new(2, 8), Facing::Down),
+            (GridPos::new(5, 5), Facing::Left)
+        );
+    }
+
+    #[test]
+    fn wrapping_back_returns_to_the_same_tile() {
+        // The net of the example and the one of the puzzle inputs.
+        let nets = [
+            &["..#.", "###.", "..##"][..],
+            &[".##", ".#.", "##.", "#.."][..],
+        ];
+        for net in nets {
+            let board = net_board(net, 3);
+            let cube = Cube::fold(&board).unwrap();
+            for (row, tiles) in board.rows.iter().enumerate() {
+                for column in 0..tiles.len() {
+                    let position = GridPos::new(row, column);
+                    for facing in Facing::ALL {
+                        if board.tile(position).is_none()
+                            || facing
+                                .step(position)
+                                .is_some_and(|next| board.tile(next).is_some())
+                        {
+                            continue;
+                        }
+                        let (other, other_facing) = cube.wrap(position, facing);
+
+                        assert_eq!(
+                            cube.wrap(other, other_facing.reverse()),
+                            (position, facing.reverse()),
+                            "{net:?} {position:?} {facing:?}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn boards_which_do_not_fold_into_a_cube() {
+        assert!(Cube::fold(&net_board(&["####", "##.."], 2)).is_err());
+        assert!(Cube::fold(&net_board(&["######"], 2)).is_err());
+    }
+
+    /// An open board with a face of `size` tiles for each `#` of the net.
+    fn net_board(net: &[&str], size: usize) -> Board {
+        Board {
+            rows: net
+                .iter()
+                .flat_map(|line| {
+                    let row = line
+                        .bytes()
+                        .flat_map(|square| {
+                            let tile = if square == b'#' { OPEN } else { VOID };
+                            [tile].repeat(size)
+                        })
+                        .collect::<Vec<_>>();
+                    std::iter::repeat_n(row, size)
+                })
+                .collect(),
+        }
+    }
+}
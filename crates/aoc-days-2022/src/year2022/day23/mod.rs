@@ -0,0 +1,275 @@
+use std::collections::HashMap;
+use std::io::BufRead;
+
+use aoc_core::cancel;
+use aoc_core::geometry::{PositionSet, SparseGrid, Vec2};
+use aoc_core::input::{byte_lines, read_all, Input, InputSource};
+use aoc_core::progress::{self, ProgressSink};
+use aoc_core::{Answer, Example, ExtraPart, Part, Solution, SolutionError};
+
+const ROUNDS: usize = 10;
+
+pub struct Day23(Input<PositionSet<Vec2>>);
+
+aoc_core::register_solution!(Day23);
+
+impl Day23 {
+    pub fn new(input: impl Into<InputSource>) -> Self {
+        Self(Input::new(input, parse_input))
+    }
+}
+
+impl Default for Day23 {
+    fn default() -> Self {
+        Self(Input::for_day(23, parse_input))
+    }
+}
+
+impl Solution for Day23 {
+    fn day(&self) -> u8 {
+        23
+    }
+
+    fn with_input(&self, input: InputSource) -> Box<dyn Solution> {
+        Box::new(Self::new(input))
+    }
+
+    fn parse(&mut self) {
+        self.0.parse();
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![example()]
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        self.0.validate()?;
+        for (row, line) in byte_lines(self.0.raw()).enumerate() {
+            if let Some(&tile) = line.iter().find(|&&tile| tile != b'.' && tile != b'#') {
+                return Err(format!(
+                    "Invalid tile {:?} on line {}",
+                    char::from(tile),
+                    row + 1
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn part_one(&self) -> Result<Answer, SolutionError> {
+        Ok(solve_part_one(self.0.try_get()?)?.into())
+    }
+
+    fn part_two(&self) -> Result<Answer, SolutionError> {
+        Ok(solve_part_two(self.0.try_get()?, &*progress::current())?.into())
+    }
+
+    fn extra_parts(&self) -> Vec<ExtraPart> {
+        vec![ExtraPart {
+            name: "elves",
+            label: "Elves after 10 rounds",
+        }]
+    }
+
+    fn extra_part(&self, name: &str) -> Option<Result<Answer, SolutionError>> {
+        (name == "elves").then(|| Ok(Answer::grid(&render(&spread_rounds(self.0.try_get()?)?))))
+    }
+
+    fn label(&self, part: Part) -> String {
+        match part {
+            Part::One => "Empty ground tiles after 10 rounds",
+            Part::Two => "First round where no Elf moves",
+        }
+        .to_string()
+    }
+}
+
+pub fn solve_part1(input: impl BufRead) -> Result<Answer, SolutionError> {
+    Ok(solve_part_one(&parse_input(&read_all(input)?))?.into())
+}
+
+pub fn solve_part2(input: impl BufRead) -> Result<Answer, SolutionError> {
+    Ok(solve_part_two(&parse_input(&read_all(input)?), &*progress::current())?.into())
+}
+
+/// Positions of the elves, the first line of the scan being on `y = 0` and the next ones below.
+fn parse_input(input: &[u8]) -> PositionSet<Vec2> {
+    byte_lines(input)
+        .enumerate()
+        .flat_map(|(row, line)| {
+            line.iter()
+                .enumerate()
+                .filter(|(_, &tile)| tile == b'#')
+                .map(move |(column, _)| Vec2::new(column as isize, -(row as isize)))
+        })
+        .collect()
+}
+
+fn solve_part_one(elves: &PositionSet<Vec2>) -> Result<usize, SolutionError> {
+    let elves = spread_rounds(elves)?;
+    Ok(elves
+        .bounds()
+        .map_or(0, |bounds| bounds.area() - elves.len()))
+}
+
+/// The elves after the first `ROUNDS` rounds.
+fn spread_rounds(elves: &PositionSet<Vec2>) -> Result<PositionSet<Vec2>, SolutionError> {
+    let mut elves = elves.clone();
+    for round in 0..ROUNDS {
+        cancel::checkpoint()?;
+        elves = spread(&elves, round).0;
+    }
+    Ok(elves)
+}
+
+/// Picture of the smallest rectangle holding the elves, `#`, and the empty ground tiles, `.`.
+fn render(elves: &PositionSet<Vec2>) -> String {
+    let grid = elves
+        .iter()
+        .map(|&elf| (elf, '#'))
+        .collect::<SparseGrid<_, _>>();
+    grid.bounds().map_or_else(String::new, |bounds| {
+        grid.render(bounds, |_, cell| cell.copied().unwrap_or('.'))
+    })
+}
+
+fn solve_part_two(
+    elves: &PositionSet<Vec2>,
+    progress: &dyn ProgressSink,
+) -> Result<usize, SolutionError> {
+    progress.start("rounds", None);
+    let mut elves = elves.clone();
+    let mut round = 0;
+    let result = loop {
+        if let Err(error) = cancel::checkpoint() {
+            break Err(error);
+        }
+        let (spread_elves, moved) = spread(&elves, round);
+        round += 1;
+        progress.advance(1);
+        if !moved {
+            break Ok(round);
+        }
+        elves = spread_elves;
+    };
+    progress.finish();
+    result
+}
+
+/// The directions that the elves consider in order in the first round, the first one going last
+/// in the next round.
+const DIRECTIONS: [Direction; 4] = [
+    Direction::North,
+    Direction::South,
+    Direction::West,
+    Direction::East,
+];
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Direction {
+    North,
+    South,
+    West,
+    East,
+}
+
+impl Direction {
+    /// The three positions that must be free for an elf to move in this direction, the one where
+    /// it moves first.
+    fn scanned(self, elf: Vec2) -> [Vec2; 3] {
+        let next = match self {
+            Direction::North => elf.up(),
+            Direction::South => elf.down(),
+            Direction::West => elf.left(),
+            Direction::East => elf.right(),
+        };
+        match self {
+            Direction::North | Direction::South => [next, next.left(), next.right()],
+            Direction::West | Direction::East => [next, next.up(), next.down()],
+        }
+    }
+}
+
+/// The elves after the round `round`, counted from 0, and whether any of them moved.
+fn spread(elves: &PositionSet<Vec2>, round: usize) -> (PositionSet<Vec2>, bool) {
+    let is_free = |position: &Vec2| !elves.contains(position);
+    // The elf proposing to move to each position, `None` when several elves propose it.
+    let mut proposals = HashMap::new();
+    for &elf in elves {
+        let neighbors = DIRECTIONS.map(|direction| direction.scanned(elf));
+        if neighbors.iter().flatten().all(is_free) {
+            continue;
+        }
+        let proposal = (0..DIRECTIONS.len())
+            .map(|index| neighbors[(round + index) % DIRECTIONS.len()])
+            .find(|scanned| scanned.iter().all(is_free));
+        if let Some([target, ..]) = proposal {
+            proposals
+                .entry(target)
+                .and_modify(|proposer| *proposer = None)
+                .or_insert(Some(elf));
+        }
+    }
+    let moves = proposals
+        .into_iter()
+        .filter_map(|(target, elf)| Some((elf?, target)))
+        .collect::<HashMap<_, _>>();
+    let spread_elves = elves
+        .iter()
+        .map(|elf| moves.get(elf).copied().unwrap_or(*elf))
+        .collect();
+    (spread_elves, !moves.is_empty())
+}
+
+solution_example!(day23, part1 = 110, part2 = 20);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn elves_of_the_small_example_spread() {
+        let mut elves = parse_input(b".....\n..##.\n..#..\n.....\n..##.\n.....\n");
+
+        for round in 0..3 {
+            elves = spread(&elves, round).0;
+        }
+
+        assert_eq!(
+            elves,
+            parse_input(b"..#..\n....#\n#....\n....#\n.....\n..#..\n")
+        );
+    }
+
+    #[test]
+    fn picture_of_the_example_after_10_rounds() {
+        let day = Day23::new(EXAMPLE.to_vec());
+
+        let Some(Ok(picture)) = day.extra_part("elves") else {
+            panic!("no picture of the elves");
+        };
+
+        assert!(picture.matches(
+            "
+......#.....
+..........#.
+.#.#..#.....
+.....#......
+..#.....#..#
+#......##...
+....##......
+.#........#.
+...#.#..#...
+............
+...#..#..#..
+"
+        ));
+    }
+
+    #[test]
+    fn elves_without_neighbors_stay() {
+        let elves = parse_input(b"#...#\n.....\n..#..\n");
+
+        assert_eq!(spread(&elves, 0), (elves, false));
+    }
+}
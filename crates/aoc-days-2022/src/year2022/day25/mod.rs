@@ -0,0 +1,150 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use aoc_core::input::{byte_lines, ParseError, ParseExt};
+use aoc_core::SolutionError;
+
+/// The last day has a single puzzle: its second star is given for the 49 other ones, by pushing the
+/// button that starts the blender.
+const SECOND_STAR: &str = "Start the blender";
+
+day!(
+    25,
+    "Full of Hot Air",
+    try parse_input,
+    solve_part_one,
+    solve_part_two,
+    part1 = "2=-1=0",
+    part2 = SECOND_STAR,
+    labels = ("Sum of the fuel requirements in SNAFU", "Second star"),
+);
+
+/// A skipped requirement would silently change the sum.
+fn parse_input(input: &[u8]) -> Result<Vec<Snafu>, ParseError> {
+    byte_lines(input).try_parse().collect()
+}
+
+fn solve_part_one(requirements: &[Snafu]) -> Result<String, SolutionError> {
+    requirements
+        .iter()
+        .try_fold(0_i64, |sum, requirement| sum.checked_add(requirement.0))
+        .map(|sum| Snafu(sum).to_string())
+        .ok_or_else(|| SolutionError::Unexpected("The fuel requirements overflow".to_string()))
+}
+
+fn solve_part_two(_requirements: &[Snafu]) -> &'static str {
+    SECOND_STAR
+}
+
+/// A number written in balanced base 5, with the digits `=` (-2), `-` (-1), `0`, `1` and `2`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct Snafu(i64);
+
+impl FromStr for Snafu {
+    type Err = String;
+
+    fn from_str(number: &str) -> Result<Self, Self::Err> {
+        if number.is_empty() {
+            return Err("Empty SNAFU number".to_string());
+        }
+        number
+            .chars()
+            .try_fold(0_i64, |value, digit| {
+                let digit = match digit {
+                    '=' => -2,
+                    '-' => -1,
+                    '0' => 0,
+                    '1' => 1,
+                    '2' => 2,
+                    _ => return Err(format!("Invalid SNAFU digit {digit:?} in {number}")),
+                };
+                value
+                    .checked_mul(5)
+                    .and_then(|value| value.checked_add(digit))
+                    .ok_or_else(|| format!("SNAFU number too large: {number}"))
+            })
+            .map(Snafu)
+    }
+}
+
+impl Display for Snafu {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.0 == 0 {
+            return write!(f, "0");
+        }
+        // Widened so that the carry of the digits -1 and -2 cannot overflow.
+        let mut value = i128::from(self.0);
+        let mut digits = Vec::new();
+        while value != 0 {
+            let digit = (value + 2).rem_euclid(5) - 2;
+            digits.push(match digit {
+                -2 => '=',
+                -1 => '-',
+                0 => '0',
+                1 => '1',
+                _ => '2',
+            });
+            value = (value - digit) / 5;
+        }
+        write!(f, "{}", digits.iter().rev().collect::<String>())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn snafu_numbers_of_the_puzzle() {
+        let numbers = [
+            (1, "1"),
+            (3, "1="),
+            (8, "2="),
+            (10, "20"),
+            (15, "1=0"),
+            (20, "1-0"),
+            (2022, "1=11-2"),
+            (12345, "1-0---0"),
+            (314159265, "1121-1110-1=0"),
+        ];
+        for (value, snafu) in numbers {
+            assert_eq!(snafu.parse(), Ok(Snafu(value)));
+            assert_eq!(Snafu(value).to_string(), snafu);
+        }
+    }
+
+    #[test]
+    fn invalid_snafu_numbers() {
+        assert_eq!(
+            "1=3".parse::<Snafu>(),
+            Err("Invalid SNAFU digit '3' in 1=3".to_string())
+        );
+        assert!("".parse::<Snafu>().is_err());
+        assert!("2".repeat(30).parse::<Snafu>().is_err());
+    }
+
+    #[test]
+    fn malformed_requirements_fail_the_parts() {
+        let result = solve_part1(&b"1=-0-2\n12111\n2=0=3\n"[..]);
+
+        assert!(
+            matches!(&result, Err(SolutionError::Input(error)) if error.starts_with("Line 3")),
+            "{result:?}"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn numbers_round_trip_through_snafu(value in any::<i64>()) {
+            prop_assert_eq!(Snafu(value).to_string().parse(), Ok(Snafu(value)));
+        }
+
+        #[test]
+        fn snafu_round_trips_through_numbers(snafu in "[12=-][=012-]{0,20}") {
+            let number = snafu.parse::<Snafu>().unwrap();
+
+            prop_assert_eq!(number.to_string(), snafu);
+        }
+    }
+}
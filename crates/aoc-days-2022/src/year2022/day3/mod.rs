@@ -0,0 +1,331 @@
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::io::BufRead;
+use std::iter::Chain;
+use std::slice::Iter;
+use std::str::FromStr;
+
+use aoc_core::anonymize::Rng;
+use aoc_core::input::{
+    read_all, read_lines, validate_lines, FilterNotEmpty, Input, InputSource, ParseError, ParseExt,
+};
+use aoc_core::{Answer, Example, Params, Part, Solution, SolutionError};
+
+pub struct Day3(Input<Vec<Rucksack>>);
+
+aoc_core::register_solution!(Day3);
+
+impl Day3 {
+    pub fn new(input: impl Into<InputSource>) -> Self {
+        Self(Input::new(input, parse_input))
+    }
+}
+
+impl Default for Day3 {
+    fn default() -> Self {
+        Self(Input::for_day(3, parse_input))
+    }
+}
+
+impl Solution for Day3 {
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn with_input(&self, input: InputSource) -> Box<dyn Solution> {
+        Box::new(Self::new(input))
+    }
+
+    fn parse(&mut self) {
+        self.0.parse();
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![example()]
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        self.0.validate()?;
+        validate_lines::<Rucksack>(self.0.raw())
+    }
+
+    fn skipped_lines(&self) -> Vec<ParseError> {
+        self.0.skipped().to_vec()
+    }
+
+    fn part_one(&self) -> Result<Answer, SolutionError> {
+        Ok(solve_part_one(self.0.try_get()?).into())
+    }
+
+    fn part_two(&self) -> Result<Answer, SolutionError> {
+        Ok(solve_part_two(self.0.try_get()?).into())
+    }
+
+    fn expected_part_one(&self) -> Option<Answer> {
+        Some(Answer::Unsigned(7568))
+    }
+
+    fn expected_part_two(&self) -> Option<Answer> {
+        Some(Answer::Unsigned(2780))
+    }
+
+    fn label(&self, part: Part) -> String {
+        match part {
+            Part::One => "Sum of the priorities of item in both compartment of a rucksack",
+            Part::Two => "Sum of all group badges",
+        }
+        .to_string()
+    }
+
+    fn stats(&self, _params: &Params) -> Vec<String> {
+        vec![
+            format!(
+                "Most frequent items: {}",
+                format_frequencies(&item_frequencies(self.0.get()), STATS_TOP_SIZE),
+            ),
+            format!(
+                "Most common badges: {}",
+                format_frequencies(&badge_frequencies(self.0.get()), STATS_TOP_SIZE),
+            ),
+            format!(
+                "Priority sums per group: {}",
+                group_priority_sums(self.0.get()).iter().join(", "),
+            ),
+        ]
+    }
+}
+
+pub fn solve_part1(input: impl BufRead) -> Result<Answer, SolutionError> {
+    Ok(solve_part_one(&parse_input(&read_all(input)?)).into())
+}
+
+pub fn solve_part2(input: impl BufRead) -> Result<Answer, SolutionError> {
+    Ok(solve_part_two(&parse_input(&read_all(input)?)).into())
+}
+
+fn parse_input(input: &[u8]) -> Vec<Rucksack> {
+    read_lines(input).parse_lossy().collect()
+}
+
+fn solve_part_one(rucksacks: &[Rucksack]) -> u32 {
+    sum_priorities_of_item_in_both_compartment(rucksacks)
+}
+
+fn solve_part_two(rucksacks: &[Rucksack]) -> u32 {
+    sum_of_all_badges(rucksacks)
+}
+
+const GROUP_SIZE: usize = 3;
+const STATS_TOP_SIZE: usize = 5;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+struct Item(char);
+
+const LOWERCASE_A_PRIORITY: u32 = 1;
+const UPPERCASE_A_PRIORITY: u32 = 27;
+const LOWERCASE_CHAR_PRIORITY_DIFFERENCE: u32 = 'a' as u32 - LOWERCASE_A_PRIORITY;
+const UPPERCASE_CHAR_PRIORITY_DIFFERENCE: u32 = 'A' as u32 - UPPERCASE_A_PRIORITY;
+
+impl Item {
+    fn priority(&self) -> u32 {
+        let difference = match self.0 {
+            'a'..='z' => LOWERCASE_CHAR_PRIORITY_DIFFERENCE,
+            'A'..='Z' => UPPERCASE_CHAR_PRIORITY_DIFFERENCE,
+            _ => panic!("Invalid item: {}", self.0),
+        };
+        self.0 as u32 - difference
+    }
+}
+
+#[derive(Debug)]
+struct Rucksack {
+    compartment_1: Vec<Item>,
+    compartment_2: Vec<Item>,
+}
+
+impl Rucksack {
+    fn items_in_both_compartments(&self) -> impl Iterator<Item = &Item> + '_ {
+        self.compartment_1
+            .iter()
+            .filter(|item| self.compartment_2.contains(item))
+            .unique()
+    }
+
+    fn contains(&self, item: &Item) -> bool {
+        self.compartment_1.contains(item) || self.compartment_2.contains(item)
+    }
+
+    fn iter(&self) -> Chain<Iter<'_, Item>, Iter<'_, Item>> {
+        self.compartment_1.iter().chain(self.compartment_2.iter())
+    }
+}
+
+impl FromStr for Rucksack {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (compartment_1, compartment_2) = line.split_at(line.len() / 2);
+        Ok(Rucksack {
+            compartment_1: compartment_1.chars().map(Item).collect(),
+            compartment_2: compartment_2.chars().map(Item).collect(),
+        })
+    }
+}
+
+fn sum_priorities_of_item_in_both_compartment(rucksacks: &[Rucksack]) -> u32 {
+    rucksacks
+        .iter()
+        .flat_map(|rucksack| rucksack.items_in_both_compartments())
+        .map(Item::priority)
+        .sum()
+}
+
+fn groups(rucksacks: &[Rucksack]) -> impl Iterator<Item = &[Rucksack]> {
+    rucksacks.chunks_exact(GROUP_SIZE)
+}
+
+fn find_badge(rucksacks: &[Rucksack]) -> Option<Item> {
+    if let Some((rucksack, tail)) = rucksacks.split_first() {
+        rucksack
+            .iter()
+            .find(|item| {
+                tail.iter()
+                    .all(|other_rucksack| other_rucksack.contains(item))
+            })
+            .copied()
+    } else {
+        None
+    }
+}
+
+fn find_all_badges(rucksacks: &[Rucksack]) -> impl Iterator<Item = Item> + '_ {
+    groups(rucksacks).flat_map(find_badge)
+}
+
+fn sum_of_all_badges(rucksacks: &[Rucksack]) -> u32 {
+    find_all_badges(rucksacks)
+        .map(|badge| badge.priority())
+        .sum()
+}
+
+fn item_frequencies(rucksacks: &[Rucksack]) -> Vec<(Item, usize)> {
+    sort_frequencies(rucksacks.iter().flat_map(Rucksack::iter).copied().counts())
+}
+
+fn badge_frequencies(rucksacks: &[Rucksack]) -> Vec<(Item, usize)> {
+    sort_frequencies(find_all_badges(rucksacks).counts())
+}
+
+fn sort_frequencies(frequencies: HashMap<Item, usize>) -> Vec<(Item, usize)> {
+    frequencies
+        .into_iter()
+        .sorted_by(|(item1, count1), (item2, count2)| {
+            count2.cmp(count1).then(item1.0.cmp(&item2.0))
+        })
+        .collect()
+}
+
+fn format_frequencies(frequencies: &[(Item, usize)], size: usize) -> String {
+    frequencies
+        .iter()
+        .take(size)
+        .map(|(item, count)| format!("{} ({count})", item.0))
+        .join(", ")
+}
+
+fn group_priority_sums(rucksacks: &[Rucksack]) -> Vec<u32> {
+    groups(rucksacks)
+        .map(sum_priorities_of_item_in_both_compartment)
+        .collect()
+}
+
+/// Renames the items with a random permutation and shuffles the groups, which keeps exactly one
+/// shared item per rucksack and one badge per group.
+pub(crate) fn anonymize_input(input: &[u8], rng: &mut Rng) -> Result<String, String> {
+    let items = ('a'..='z').chain('A'..='Z').collect::<Vec<_>>();
+    let mut renamed_items = items.clone();
+    rng.shuffle(&mut renamed_items);
+    let renaming = items
+        .into_iter()
+        .zip(renamed_items)
+        .collect::<HashMap<_, _>>();
+    let mut groups = read_lines(input)
+        .filter_not_empty()
+        .map(|line| {
+            line.chars()
+                .map(|item| renaming.get(&item).copied().unwrap_or(item))
+                .collect::<String>()
+        })
+        .chunks(GROUP_SIZE)
+        .into_iter()
+        .map(|group| group.collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut groups);
+    Ok(groups
+        .into_iter()
+        .flatten()
+        .map(|line| line + "\n")
+        .collect())
+}
+
+solution_example!(day3, part1 = 157, part2 = 70);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_core::input::read_lines;
+    use std::sync::LazyLock;
+
+    static EXAMPLE_RUCKSACKS: LazyLock<Vec<Rucksack>> = LazyLock::new(|| {
+        read_lines(EXAMPLE)
+            .filter_not_empty()
+            .parse()
+            .collect::<Vec<_>>()
+    });
+
+    #[test]
+    fn example_first_group_badge() {
+        let first_group = &EXAMPLE_RUCKSACKS[0..3];
+
+        let result = find_badge(first_group);
+
+        assert_eq!(result, Some(Item('r')));
+    }
+
+    #[test]
+    fn example_item_frequencies() {
+        let result = item_frequencies(&EXAMPLE_RUCKSACKS);
+
+        assert_eq!(result.len(), 35);
+        assert_eq!(
+            &result[..3],
+            &[(Item('w'), 12), (Item('F'), 8), (Item('L'), 8)],
+        );
+    }
+
+    #[test]
+    fn example_badge_frequencies() {
+        let result = badge_frequencies(&EXAMPLE_RUCKSACKS);
+
+        assert_eq!(result, vec![(Item('Z'), 1), (Item('r'), 1)]);
+    }
+
+    #[test]
+    fn example_group_priority_sums() {
+        let result = group_priority_sums(&EXAMPLE_RUCKSACKS);
+
+        assert_eq!(result, vec![96, 61]);
+    }
+
+    #[test]
+    fn anonymized_example_keeps_badges() {
+        let input = anonymize_input(EXAMPLE, &mut Rng::new(1)).unwrap();
+
+        let rucksacks = parse_input(input.as_bytes());
+
+        assert_eq!(rucksacks.len(), 6);
+        assert!(rucksacks
+            .chunks(GROUP_SIZE)
+            .all(|group| find_badge(group).is_some()));
+    }
+}
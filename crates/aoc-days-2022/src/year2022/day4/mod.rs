@@ -0,0 +1,157 @@
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use aoc_core::anonymize::Rng;
+use aoc_core::input::{read_lines, validate_lines, FilterNotEmpty, ParseExt, SplitParse};
+use aoc_core::interval::IntervalSet;
+
+day!(
+    4,
+    "Camp Cleanup",
+    parse_input,
+    solve_part_one,
+    solve_part_two,
+    part1 = 2,
+    part2 = 4,
+    expected = (477_u32, 830_u32),
+    labels = (
+        "Number of pairs with complete overlap",
+        "Number of pairs with overlap"
+    ),
+    validate = validate_lines::<AssignmentPair>,
+);
+
+fn parse_input(input: &[u8]) -> Vec<AssignmentPair> {
+    read_lines(input).parse_lossy().collect()
+}
+
+fn solve_part_one(pairs: &[AssignmentPair]) -> usize {
+    compute_pairs_with_complete_overlap(pairs)
+}
+
+fn solve_part_two(pairs: &[AssignmentPair]) -> usize {
+    compute_pairs_with_overlap(pairs)
+}
+
+struct Assignment(RangeInclusive<isize>);
+
+impl Assignment {
+    fn sections(&self) -> IntervalSet {
+        IntervalSet::from(self.0.clone())
+    }
+
+    fn contains(&self, Assignment(assignment): &Assignment) -> bool {
+        self.sections().contains_range(assignment)
+    }
+
+    fn overlaps(&self, Assignment(assignment): &Assignment) -> bool {
+        self.sections().overlaps(assignment)
+    }
+}
+
+impl FromStr for Assignment {
+    type Err = String;
+
+    fn from_str(assignment: &str) -> Result<Self, Self::Err> {
+        let (start, end) = assignment.split_on('-')?;
+        Ok(Assignment(start..=end))
+    }
+}
+
+struct AssignmentPair(Assignment, Assignment);
+
+impl FromStr for AssignmentPair {
+    type Err = String;
+
+    fn from_str(pair: &str) -> Result<Self, Self::Err> {
+        let (first, second) = pair.split_on(',')?;
+        Ok(AssignmentPair(first, second))
+    }
+}
+
+fn compute_pairs_with_complete_overlap(pairs: &[AssignmentPair]) -> usize {
+    pairs
+        .iter()
+        .filter(|AssignmentPair(first, second)| first.contains(second) || second.contains(first))
+        .count()
+}
+
+fn compute_pairs_with_overlap(pairs: &[AssignmentPair]) -> usize {
+    pairs
+        .iter()
+        .filter(|AssignmentPair(first, second)| first.overlaps(second))
+        .count()
+}
+
+/// Shifts every pair by a random offset, which keeps how the two assignments overlap. Lines that
+/// are not pairs are kept as they are.
+pub(crate) fn anonymize_input(input: &[u8], rng: &mut Rng) -> Result<String, String> {
+    Ok(read_lines(input)
+        .filter_not_empty()
+        .map(|line| {
+            let sections = line
+                .split([',', '-'])
+                .map(str::parse::<i64>)
+                .collect::<Result<Vec<_>, _>>()
+                .ok()
+                .and_then(|sections| <[i64; 4]>::try_from(sections).ok());
+            let Some(sections) = sections else {
+                return format!("{line}\n");
+            };
+            let min = *sections.iter().min().unwrap_or(&0);
+            let max = *sections.iter().max().unwrap_or(&0);
+            let offset = rng.in_range((1 - min).min(0)..=(99 - max).max(0));
+            let [first_start, first_end, second_start, second_end] =
+                sections.map(|section| section + offset);
+            format!("{first_start}-{first_end},{second_start}-{second_end}\n")
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn anonymized_example_keeps_overlaps() {
+        let input = anonymize_input(EXAMPLE, &mut Rng::new(1)).unwrap();
+
+        let pairs = parse_input(input.as_bytes());
+
+        assert_ne!(input.as_bytes(), EXAMPLE);
+        assert_eq!(solve_part_one(&pairs), 2);
+        assert_eq!(solve_part_two(&pairs), 4);
+    }
+
+    #[test]
+    fn invalid_utf8_lines_are_skipped_and_reported() {
+        use aoc_core::input::InputSource;
+        use aoc_core::{Answer, Solution};
+
+        let input = [&EXAMPLE[..8], b"\xff\n", &EXAMPLE[8..]].concat();
+        let day = solution().with_input(InputSource::from(input));
+
+        assert_eq!(day.part_one(), Ok(Answer::Unsigned(2)));
+        assert_eq!(day.part_two(), Ok(Answer::Unsigned(4)));
+        assert_eq!(
+            day.skipped_lines()
+                .iter()
+                .map(|error| error.line)
+                .collect::<Vec<_>>(),
+            vec![2]
+        );
+    }
+
+    #[test]
+    fn anonymized_input_keeps_malformed_lines() {
+        let input = anonymize_input(
+            b"2-4,6-8\nhello\n2-3,4-99999999999999999999\n",
+            &mut Rng::new(1),
+        );
+
+        let input = input.unwrap();
+        let lines = input.lines().collect::<Vec<_>>();
+        assert_ne!(lines[0], "2-4,6-8");
+        assert_eq!(lines[1..], ["hello", "2-3,4-99999999999999999999"]);
+    }
+}
@@ -1,46 +1,73 @@
 use itertools::Itertools;
-use lazy_static::lazy_static;
-use regex::Regex;
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
-use crate::input::{read_lines, FilterNotEmpty, ParseExt};
-use crate::Solution;
+use aoc_core::input::sections;
+use aoc_core::parse::parse_line;
+
+day!(
+    5,
+    "Supply Stacks",
+    parse_input,
+    solve_part_one,
+    solve_part_two,
+    part1 = "CMZ",
+    part2 = "MCD",
+    expected = ("TBVFVDZPN", "VLCWHTDSZ"),
+    labels = (
+        "Top crates after all moves with CrateMover 9000",
+        "Top crates after all moves with CrateMover 9001"
+    ),
+    validate = validate_procedure,
+);
 
-mod input;
-
-lazy_static! {
-    static ref STACKS: Stacks = parse_stacks(read_lines(input::STACKS).filter_not_empty());
-    static ref INSTRUCTIONS: Vec<MoveInstruction> = read_lines(input::INSTRUCTIONS)
-        .filter_not_empty()
-        .parse()
-        .collect();
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Procedure {
+    stacks: Stacks,
+    instructions: Vec<MoveInstruction>,
 }
 
-pub struct Day5;
+const SECTIONS: [&str; 2] = ["stacks", "instructions"];
 
-impl Solution for Day5 {
-    fn day(&self) -> u8 {
-        5
+/// Validates that both sections are there and that every instruction parses.
+fn validate_procedure(input: &[u8]) -> Result<(), String> {
+    let [stacks, instructions] = sections(input, SECTIONS);
+    stacks.require()?;
+    instructions.require()?;
+    for instruction in instructions.try_parse::<MoveInstruction>() {
+        instruction?;
     }
+    Ok(())
+}
 
-    fn part_one(&self) -> String {
-        let mut stacks = STACKS.clone();
-        stacks.move_all_with_crate_mover_9000(&INSTRUCTIONS);
-        format!(
-            "Top crates after all moves with CrateMover 9000: {}",
-            crates_to_string(&compute_top_crates(&stacks)),
-        )
+fn parse_input(input: &[u8]) -> Procedure {
+    let [stacks, instructions] = sections(input, SECTIONS);
+    Procedure {
+        stacks: parse_stacks(stacks.lines()),
+        instructions: instructions.try_parse().lossy().collect(),
     }
+}
 
-    fn part_two(&self) -> String {
-        let mut stacks = STACKS.clone();
-        stacks.move_all_with_crate_mover_9001(&INSTRUCTIONS);
-        format!(
-            "Top crates after all moves with CrateMover 9001: {}",
-            crates_to_string(&compute_top_crates(&stacks)),
-        )
-    }
+fn solve_part_one(
+    Procedure {
+        stacks,
+        instructions,
+    }: &Procedure,
+) -> String {
+    let mut stacks = stacks.clone();
+    stacks.move_all_with_crate_mover_9000(instructions);
+    crates_to_string(&compute_top_crates(&stacks))
+}
+
+fn solve_part_two(
+    Procedure {
+        stacks,
+        instructions,
+    }: &Procedure,
+) -> String {
+    let mut stacks = stacks.clone();
+    stacks.move_all_with_crate_mover_9001(instructions);
+    crates_to_string(&compute_top_crates(&stacks))
 }
 
 fn compute_top_crates(stacks: &Stacks) -> Vec<Option<Crate>> {
@@ -129,15 +156,15 @@ impl Stacks {
     }
 }
 
-fn parse_stack(line: &str) -> Vec<Crate> {
-    line.split(' ')
-        .flat_map(|c| c.chars().nth(1))
-        .map(Crate)
+fn parse_stack(line: &[u8]) -> Vec<Crate> {
+    line.split(|&byte| byte == b' ')
+        .flat_map(|c| c.get(1))
+        .map(|&c| Crate(c as char))
         .collect()
 }
 
-fn parse_stacks(lines: impl Iterator<Item = String>) -> Stacks {
-    Stacks(lines.map(|line| parse_stack(&line)).collect())
+fn parse_stacks<'a>(lines: impl Iterator<Item = &'a [u8]>) -> Stacks {
+    Stacks(lines.map(parse_stack).collect())
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -147,23 +174,17 @@ struct MoveInstruction {
     to: usize,
 }
 
-lazy_static! {
-    static ref MOVE_INSTRUCTION_REGEX: Regex =
-        Regex::new(r"^move (\d+) from (\d+) to (\d+)$").unwrap();
-}
-
 impl FromStr for MoveInstruction {
     type Err = String;
 
     fn from_str(instruction: &str) -> Result<Self, Self::Err> {
-        if let Some(captures) = MOVE_INSTRUCTION_REGEX.captures(instruction) {
-            Ok(MoveInstruction {
-                number: captures.get(1).unwrap().as_str().parse::<usize>().unwrap(),
-                from: captures.get(2).unwrap().as_str().parse::<usize>().unwrap() - 1,
-                to: captures.get(3).unwrap().as_str().parse::<usize>().unwrap() - 1,
-            })
-        } else {
-            Err(format!("Invalid instruction: {instruction}"))
+        match parse_line!("move {usize} from {usize} to {usize}")(instruction) {
+            Ok(("", (number, from @ 1.., to @ 1..))) => Ok(MoveInstruction {
+                number,
+                from: from - 1,
+                to: to - 1,
+            }),
+            _ => Err(format!("Invalid instruction: {instruction}")),
         }
     }
 }
@@ -171,29 +192,12 @@ impl FromStr for MoveInstruction {
 #[cfg(test)]
 mod test {
     use super::*;
+    use aoc_core::input::for_day;
+    use std::sync::LazyLock;
 
-    const EXAMPLE_STACKS_INPUT: &str = r"
-[Z] [N]
-[M] [C] [D]
-[P]
-";
-
-    const EXAMPLE_INSTRUCTIONS_INPUT: &str = r"
-move 1 from 2 to 1
-move 3 from 1 to 3
-move 2 from 2 to 1
-move 1 from 1 to 2
-";
-
-    lazy_static! {
-        static ref EXAMPLE_STACKS: Stacks =
-            parse_stacks(read_lines(EXAMPLE_STACKS_INPUT.as_bytes()).filter_not_empty());
-        static ref EXAMPLE_INSTRUCTIONS: Vec<MoveInstruction> =
-            read_lines(EXAMPLE_INSTRUCTIONS_INPUT.as_bytes())
-                .filter_not_empty()
-                .parse()
-                .collect();
-    }
+    static EXAMPLE_STACKS: LazyLock<Stacks> = LazyLock::new(|| parse_input(EXAMPLE).stacks);
+    static EXAMPLE_INSTRUCTIONS: LazyLock<Vec<MoveInstruction>> =
+        LazyLock::new(|| parse_input(EXAMPLE).instructions);
 
     #[test]
     fn parse_example_stacks() {
@@ -210,7 +214,7 @@ move 1 from 1 to 2
     #[test]
     fn parse_input_stacks() {
         assert_eq!(
-            STACKS.to_string(),
+            parse_input(&for_day(5).unwrap()).stacks.to_string(),
             r"[V] [C] [D] [R] [Z] [G] [B] [W] 
 [G] [W] [F] [C] [B] [S] [T] [V] 
 [C] [B] [S] [N] [W] 
@@ -294,15 +298,13 @@ move 1 from 1 to 2
     }
 
     #[test]
-    fn part1_example() {
-        let mut stacks = EXAMPLE_STACKS.clone();
-
-        stacks.move_all_with_crate_mover_9000(&EXAMPLE_INSTRUCTIONS);
-        let result = compute_top_crates(&stacks);
+    fn malformed_instruction_is_reported_with_its_line() {
+        let input = b"    [D]\n[N] [C]\n 1   2\n\nmove 1 from 2 to 1\nmove one from 1 to 2\n";
 
         assert_eq!(
-            result,
-            vec![Some(Crate('C')), Some(Crate('M')), Some(Crate('Z'))],
+            validate_procedure(input),
+            Err("Line 6: Invalid instruction: move one from 1 to 2".to_string()),
         );
+        assert_eq!(parse_input(input).instructions.len(), 1);
     }
 }
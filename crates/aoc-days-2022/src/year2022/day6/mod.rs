@@ -0,0 +1,119 @@
+use std::io::BufRead;
+
+use aoc_core::input::{read_all, Input, InputSource, WindowReader};
+use aoc_core::{Answer, Example, ExampleAnswers, Part, Solution, SolutionError};
+use itertools::Itertools;
+
+const START_OF_PACKET_MARKER_SIZE: usize = 4;
+const START_OF_MESSAGE_MARKER_SIZE: usize = 14;
+
+pub struct Day6(Input<String>);
+
+aoc_core::register_solution!(Day6);
+
+impl Day6 {
+    pub fn new(input: impl Into<InputSource>) -> Self {
+        Self(Input::new(input, |input| parse_input(input).to_string()))
+    }
+}
+
+impl Default for Day6 {
+    fn default() -> Self {
+        Self(Input::for_day(6, |input| parse_input(input).to_string()))
+    }
+}
+
+impl Solution for Day6 {
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn with_input(&self, input: InputSource) -> Box<dyn Solution> {
+        Box::new(Self::new(input))
+    }
+
+    fn parse(&mut self) {
+        self.0.parse();
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        let other_example = |name, input, part_one, part_two| Example {
+            name: Some(name),
+            input,
+            answers: ExampleAnswers::new(part_one, part_two),
+        };
+        vec![
+            example(),
+            other_example("second", b"bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+            other_example("third", b"nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+            other_example("fourth", b"nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+            other_example("fifth", b"zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+        ]
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        self.0.validate()
+    }
+
+    fn part_one(&self) -> Result<Answer, SolutionError> {
+        Ok(solve_part_one(self.0.try_get()?)?.into())
+    }
+
+    fn part_two(&self) -> Result<Answer, SolutionError> {
+        Ok(solve_part_two(self.0.try_get()?)?.into())
+    }
+
+    fn expected_part_one(&self) -> Option<Answer> {
+        Some(Answer::Unsigned(1134))
+    }
+
+    fn expected_part_two(&self) -> Option<Answer> {
+        Some(Answer::Unsigned(2263))
+    }
+
+    fn label(&self, part: Part) -> String {
+        match part {
+            Part::One => "Number of read characters to get start-of-packet marker",
+            Part::Two => "Number of read characters to get start-of-message marker",
+        }
+        .to_string()
+    }
+}
+
+pub fn solve_part1(input: impl BufRead) -> Result<Answer, SolutionError> {
+    Ok(solve_part_one(parse_input(&read_all(input)?))?.into())
+}
+
+pub fn solve_part2(input: impl BufRead) -> Result<Answer, SolutionError> {
+    Ok(solve_part_two(parse_input(&read_all(input)?))?.into())
+}
+
+fn parse_input(input: &[u8]) -> &str {
+    std::str::from_utf8(input).unwrap_or_default().trim()
+}
+
+fn solve_part_one(signal: &str) -> Result<usize, SolutionError> {
+    find_start_of_packet_marker_position(signal)
+        .ok_or_else(|| SolutionError::Unexpected("No start-of-packet marker".to_string()))
+}
+
+fn solve_part_two(signal: &str) -> Result<usize, SolutionError> {
+    find_start_of_message_marker_position(signal)
+        .ok_or_else(|| SolutionError::Unexpected("No start-of-message marker".to_string()))
+}
+
+fn find_unique_chars_marker_position(signal: &str, marker_size: usize) -> Option<usize> {
+    WindowReader::new(signal.as_bytes(), marker_size)
+        .position(|window| window.iter().all_unique())
+        .unwrap_or_default()
+}
+
+fn find_start_of_packet_marker_position(signal: &str) -> Option<usize> {
+    find_unique_chars_marker_position(signal, START_OF_PACKET_MARKER_SIZE)
+}
+
+fn find_start_of_message_marker_position(signal: &str) -> Option<usize> {
+    find_unique_chars_marker_position(signal, START_OF_MESSAGE_MARKER_SIZE)
+}
+
+solution_example!(day6, part1 = 7, part2 = 19);
@@ -0,0 +1,352 @@
+use aoc_core::input::{validate_lines, FromLines, ParseAll, ParseExt, Token};
+use aoc_core::memo::memoize;
+use aoc_core::SolutionError;
+
+const DEVICE_STORAGE: u64 = 70_000_000;
+const UPDATE_SIZE: u64 = 30_000_000;
+
+day!(
+    7,
+    "No Space Left On Device",
+    parse_input,
+    solve_part_one,
+    solve_part_two,
+    part1 = 95437,
+    part2 = 24933642,
+    expected = (1989474_u64, 1111607_u64),
+    labels = (
+        "Sum of the size of all directories under 100 000",
+        "Size of smallest directory to delete for update"
+    ),
+    validate = validate_lines::<Token>,
+);
+
+fn parse_input(input: &[u8]) -> Directory {
+    input.parse_all()
+}
+
+fn solve_part_one(root: &Directory) -> u64 {
+    DirectorySizes::of(root)
+        .subdirectories
+        .into_iter()
+        .filter(|&size| size <= 100_000)
+        .sum()
+}
+
+fn solve_part_two(root: &Directory) -> Result<u64, SolutionError> {
+    find_size_of_smallest_directory_to_delete_for_update(root).ok_or_else(|| {
+        SolutionError::Unexpected("No directory frees enough space for the update".to_string())
+    })
+}
+
+fn find_size_of_smallest_directory_to_delete_for_update(root: &Directory) -> Option<u64> {
+    let sizes = DirectorySizes::of(root);
+    let to_free = sizes.root - (DEVICE_STORAGE - UPDATE_SIZE);
+    sizes
+        .subdirectories
+        .into_iter()
+        .filter(|&size| size >= to_free)
+        .min()
+}
+
+/// Sizes of the root and of every directory below it.
+struct DirectorySizes {
+    root: u64,
+    subdirectories: Vec<u64>,
+}
+
+impl DirectorySizes {
+    /// Adds up the sizes of the subdirectories of each directory rather than walking its whole
+    /// tree again.
+    fn of(root: &Directory) -> Self {
+        let mut size = memoize(|directory_size, path: String| {
+            root.directory(&path).map_or(0, |directory| {
+                directory
+                    .items
+                    .iter()
+                    .map(|item| match item {
+                        FSItem::File { size, .. } => *size,
+                        FSItem::Directory(subdirectory) => {
+                            directory_size(format!("{path}/{}", subdirectory.name))
+                        }
+                    })
+                    .sum()
+            })
+        });
+        Self {
+            root: size.get(String::new()),
+            subdirectories: root
+                .subdirectory_paths()
+                .into_iter()
+                .map(|path| size.get(path))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+struct Directory {
+    name: String,
+    items: Vec<FSItem>,
+}
+
+impl Directory {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            items: Vec::new(),
+        }
+    }
+
+    /// The directory at `path` below this one, e.g. `/a/e`, this one for an empty path.
+    fn directory(&self, path: &str) -> Option<&Self> {
+        path.split('/').skip(1).try_fold(self, |directory, name| {
+            directory.items.iter().find_map(|item| match item {
+                FSItem::Directory(subdirectory) if subdirectory.name == name => Some(subdirectory),
+                _ => None,
+            })
+        })
+    }
+
+    /// Paths of every directory below this one, e.g. `/a` and `/a/e`.
+    fn subdirectory_paths(&self) -> Vec<String> {
+        let mut paths = Vec::new();
+        let mut to_visit = vec![(String::new(), self)];
+
+        while let Some((path, directory)) = to_visit.pop() {
+            for item in &directory.items {
+                if let FSItem::Directory(subdirectory) = item {
+                    let subdirectory_path = format!("{path}/{}", subdirectory.name);
+                    paths.push(subdirectory_path.clone());
+                    to_visit.push((subdirectory_path, subdirectory));
+                }
+            }
+        }
+
+        paths
+    }
+
+    pub fn add_file(&mut self, name: &str, size: u64) {
+        if !self.contains(name) {
+            self.items.push(FSItem::new_file(name, size));
+        }
+    }
+
+    pub fn add_directory(&mut self, name: &str) {
+        if !self.contains(name) {
+            self.items.push(FSItem::Directory(Self::new(name)));
+        }
+    }
+
+    fn contains(&self, name: &str) -> bool {
+        self.items.iter().any(|item| item.name() == name)
+    }
+
+    pub fn get_directory_mut(&mut self, directory: &str) -> Option<&mut Self> {
+        match self.items.iter_mut().find(|item| item.name() == directory) {
+            Some(FSItem::Directory(directory)) => Some(directory),
+            _ => None,
+        }
+    }
+}
+
+impl FromLines for Directory {
+    fn from_lines(lines: impl Iterator<Item = String>) -> Self {
+        let mut terminal = Terminal::default();
+        terminal.feed(lines);
+        terminal.root
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum FSItem {
+    File { name: String, size: u64 },
+    Directory(Directory),
+}
+
+impl FSItem {
+    pub fn new_file(name: &str, size: u64) -> Self {
+        Self::File {
+            name: name.to_string(),
+            size,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Self::File { name, .. } | Self::Directory(Directory { name, .. }) => name,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Terminal {
+    root: Directory,
+    current_path: Vec<String>,
+    listing: bool,
+}
+
+impl Default for Terminal {
+    fn default() -> Self {
+        Self {
+            root: Directory::new("/"),
+            current_path: Vec::new(),
+            listing: false,
+        }
+    }
+}
+
+impl Terminal {
+    pub fn feed<I: Iterator<Item = String>>(&mut self, lines: I) {
+        for token in lines.parse_lossy() {
+            self.feed_token(token);
+        }
+    }
+
+    fn feed_token(&mut self, token: Token) {
+        match token {
+            Token::Command { name, args } => {
+                self.listing = name == "ls";
+                if let ("cd", Some(directory_name)) = (name.as_str(), args.first()) {
+                    self.change_directory(directory_name);
+                }
+            }
+            Token::DirEntry(name) if self.listing => {
+                self.current_directory_mut().add_directory(&name)
+            }
+            Token::FileEntry { size, name } if self.listing => {
+                self.current_directory_mut().add_file(&name, size)
+            }
+            Token::DirEntry(_) | Token::FileEntry { .. } => {}
+        }
+    }
+
+    fn change_directory(&mut self, directory_name: &str) {
+        match directory_name {
+            "/" => self.current_path.clear(),
+            ".." => {
+                self.current_path.pop();
+            }
+            directory_name => {
+                if self
+                    .current_directory_mut()
+                    .get_directory_mut(directory_name)
+                    .is_some()
+                {
+                    self.current_path.push(directory_name.to_string());
+                }
+            }
+        }
+    }
+
+    fn current_directory_mut(&mut self) -> &mut Directory {
+        let mut directory = &mut self.root;
+        for name in &self.current_path {
+            directory = directory.get_directory_mut(name).unwrap();
+        }
+        directory
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_core::input::{read_lines, FilterNotEmpty};
+    use std::{assert_eq, vec};
+
+    #[test]
+    fn test_parse_empty() {
+        assert_eq!(
+            Directory::from_lines(std::iter::empty()),
+            Directory {
+                name: "/".to_string(),
+                items: vec![]
+            }
+        )
+    }
+
+    #[test]
+    fn test_parse_single_file() {
+        assert_eq!(
+            b"$ ls\n23 f".parse_all::<Directory>(),
+            Directory {
+                name: "/".to_string(),
+                items: vec![FSItem::new_file("f", 23)]
+            }
+        )
+    }
+
+    #[test]
+    fn parse_example() {
+        let root = EXAMPLE.parse_all::<Directory>();
+
+        assert_eq!(
+            root,
+            Directory {
+                name: "/".to_string(),
+                items: vec![
+                    FSItem::Directory(Directory {
+                        name: "a".to_string(),
+                        items: vec![
+                            FSItem::Directory(Directory {
+                                name: "e".to_string(),
+                                items: vec![FSItem::new_file("i", 584)]
+                            }),
+                            FSItem::new_file("f", 29116),
+                            FSItem::new_file("g", 2557),
+                            FSItem::new_file("h.lst", 62596),
+                        ],
+                    }),
+                    FSItem::new_file("b.txt", 14848514),
+                    FSItem::new_file("c.dat", 8504156),
+                    FSItem::Directory(Directory {
+                        name: "d".to_string(),
+                        items: vec![
+                            FSItem::new_file("j", 4060174),
+                            FSItem::new_file("d.log", 8033020),
+                            FSItem::new_file("d.ext", 5626152),
+                            FSItem::new_file("k", 7214296),
+                        ]
+                    })
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn feed_example_in_chunks() {
+        let lines = read_lines(EXAMPLE).filter_not_empty().collect::<Vec<_>>();
+        let mut terminal = Terminal::default();
+
+        terminal.feed(lines[..13].iter().cloned());
+
+        assert_eq!(terminal.current_path, vec!["a", "e"]);
+
+        terminal.feed(lines[13..].iter().cloned());
+
+        assert_eq!(terminal.current_path, vec!["d"]);
+        assert_eq!(terminal.root, EXAMPLE.parse_all::<Directory>());
+    }
+
+    #[test]
+    fn feed_continues_listing_across_chunks() {
+        let mut terminal = Terminal::default();
+
+        terminal.feed(read_lines(b"$ ls\ndir a\n$ cd a\n$ ls\n1 b".as_slice()));
+        terminal.feed(read_lines(b"2 c\n$ cd ..\n$ ls\ndir a\n3 d".as_slice()));
+
+        assert_eq!(
+            terminal.root,
+            Directory {
+                name: "/".to_string(),
+                items: vec![
+                    FSItem::Directory(Directory {
+                        name: "a".to_string(),
+                        items: vec![FSItem::new_file("b", 1), FSItem::new_file("c", 2)],
+                    }),
+                    FSItem::new_file("d", 3),
+                ],
+            }
+        );
+    }
+}
@@ -1,52 +1,67 @@
-use lazy_static::lazy_static;
 use std::collections::HashSet;
+use std::str::FromStr;
 
-use crate::input::{read_lines, FilterNotEmpty};
-use crate::Solution;
+use aoc_core::input::{byte_lines, ParseError, ParseExt};
 use Direction::{East, North, South, West};
 
-mod input;
+day!(
+    8,
+    "Treetop Tree House",
+    try parse_input,
+    solve_part_one,
+    solve_part_two,
+    part1 = 21,
+    part2 = 8,
+    expected = (1794_usize, 199272_usize),
+    labels = ("Number of visible trees", "Highest scenic score"),
+);
+
+fn parse_input(input: &[u8]) -> Result<Trees, ParseError> {
+    byte_lines(input)
+        .try_parse()
+        .map(|row| row.map(|TreeRow(heights)| heights))
+        .collect::<Result<_, _>>()
+        .map(Trees)
+}
 
-lazy_static! {
-    static ref TREES: Trees<99> = Trees::parse(read_lines(input::INPUT).filter_not_empty());
+fn solve_part_one(trees: &Trees) -> usize {
+    trees.visible_trees().len()
 }
 
-pub struct Day8;
+fn solve_part_two(trees: &Trees) -> usize {
+    trees.highest_scenic_score()
+}
 
-impl Solution for Day8 {
-    fn day(&self) -> u8 {
-        8
-    }
+#[derive(Debug, Eq, PartialEq, Clone)]
+struct Trees(Vec<Vec<u8>>);
 
-    fn part_one(&self) -> String {
-        format!("Number of visible trees: {}", TREES.visible_trees().len())
-    }
+/// The heights of the trees of a row.
+struct TreeRow(Vec<u8>);
+
+impl FromStr for TreeRow {
+    type Err = String;
 
-    fn part_two(&self) -> String {
-        format!("Highest scenic score: {}", TREES.highest_scenic_score())
+    fn from_str(row: &str) -> Result<Self, Self::Err> {
+        row.chars()
+            .map(|char| {
+                char.to_digit(10)
+                    .map(|height| height as u8)
+                    .ok_or_else(|| format!("Invalid tree height: {char}"))
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
     }
 }
 
-#[derive(Debug, Eq, PartialEq, Copy, Clone)]
-struct Trees<const WIDTH: usize>([[u8; WIDTH]; WIDTH]);
-
-impl<const WIDTH: usize> Trees<WIDTH> {
-    fn parse(rows: impl Iterator<Item = String>) -> Self {
-        let mut trees = [[0; WIDTH]; WIDTH];
-
-        for (row, row_chars) in rows.take(WIDTH).enumerate() {
-            for (column, char) in row_chars.chars().take(WIDTH).enumerate() {
-                trees[row][column] = char.to_digit(10).unwrap() as u8;
-            }
-        }
-
-        Self(trees)
+impl Trees {
+    fn width(&self) -> usize {
+        self.0.len()
     }
 
     fn visible_trees(&self) -> HashSet<Tree> {
         let mut visible_trees = HashSet::new();
 
-        for i in 0..WIDTH {
+        for i in 0..self.width() {
             visible_trees.extend(TreeLineIterator::north(&self.0, i).visible_trees_on_line());
             visible_trees.extend(TreeLineIterator::east(&self.0, i).visible_trees_on_line());
             visible_trees.extend(TreeLineIterator::south(&self.0, i).visible_trees_on_line());
@@ -59,8 +74,8 @@ impl<const WIDTH: usize> Trees<WIDTH> {
     fn highest_scenic_score(&self) -> usize {
         let mut max = 0;
 
-        for row in 0..WIDTH {
-            for column in 0..WIDTH {
+        for row in 0..self.width() {
+            for column in 0..self.width() {
                 max = max.max(self.scenic_score(row, column));
             }
         }
@@ -100,24 +115,24 @@ enum Direction {
 }
 
 #[derive(Debug)]
-struct TreeLineIterator<'a, const WIDTH: usize> {
-    trees: &'a [[u8; WIDTH]; WIDTH],
+struct TreeLineIterator<'a> {
+    trees: &'a [Vec<u8>],
     row: Option<usize>,
     column: Option<usize>,
     direction: Direction,
 }
 
-impl<'a, const WIDTH: usize> TreeLineIterator<'a, WIDTH> {
-    fn north(trees: &'a [[u8; WIDTH]; WIDTH], column: usize) -> Self {
+impl<'a> TreeLineIterator<'a> {
+    fn north(trees: &'a [Vec<u8>], column: usize) -> Self {
         Self {
             trees,
-            row: Some(WIDTH - 1),
+            row: Some(trees.len() - 1),
             column: Some(column),
             direction: North,
         }
     }
 
-    fn east(trees: &'a [[u8; WIDTH]; WIDTH], row: usize) -> Self {
+    fn east(trees: &'a [Vec<u8>], row: usize) -> Self {
         Self {
             trees,
             row: Some(row),
@@ -126,7 +141,7 @@ impl<'a, const WIDTH: usize> TreeLineIterator<'a, WIDTH> {
         }
     }
 
-    fn south(trees: &'a [[u8; WIDTH]; WIDTH], column: usize) -> Self {
+    fn south(trees: &'a [Vec<u8>], column: usize) -> Self {
         Self {
             trees,
             row: Some(0),
@@ -135,21 +150,16 @@ impl<'a, const WIDTH: usize> TreeLineIterator<'a, WIDTH> {
         }
     }
 
-    fn west(trees: &'a [[u8; WIDTH]; WIDTH], row: usize) -> Self {
+    fn west(trees: &'a [Vec<u8>], row: usize) -> Self {
         Self {
             trees,
             row: Some(row),
-            column: Some(WIDTH - 1),
+            column: Some(trees.len() - 1),
             direction: West,
         }
     }
 
-    fn from(
-        trees: &'a [[u8; WIDTH]; WIDTH],
-        row: usize,
-        column: usize,
-        direction: Direction,
-    ) -> Self {
+    fn from(trees: &'a [Vec<u8>], row: usize, column: usize, direction: Direction) -> Self {
         Self {
             trees,
             row: Some(row),
@@ -159,11 +169,12 @@ impl<'a, const WIDTH: usize> TreeLineIterator<'a, WIDTH> {
     }
 
     fn increment(&mut self) {
+        let width = self.trees.len();
         match self.direction {
             East => {
                 self.column = self
                     .column
-                    .filter(|column| *column < WIDTH - 1)
+                    .filter(|column| *column < width - 1)
                     .map(|column| column + 1)
             }
             West => {
@@ -172,13 +183,13 @@ impl<'a, const WIDTH: usize> TreeLineIterator<'a, WIDTH> {
                     .filter(|column| *column > 0)
                     .map(|column| column - 1);
             }
-            South => self.row = self.row.filter(|row| *row < WIDTH - 1).map(|row| row + 1),
+            South => self.row = self.row.filter(|row| *row < width - 1).map(|row| row + 1),
             North => self.row = self.row.filter(|row| *row > 0).map(|row| row - 1),
         }
     }
 }
 
-impl<'a, const WIDTH: usize> Iterator for TreeLineIterator<'a, WIDTH> {
+impl Iterator for TreeLineIterator<'_> {
     type Item = Tree;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -306,40 +317,25 @@ where
 #[cfg(test)]
 mod test {
     use super::*;
+    use aoc_core::SolutionError;
+    use std::sync::LazyLock;
 
-    const EXAMPLE: &[u8] = b"
-30373
-25512
-65332
-33549
-35390
-";
-
-    lazy_static! {
-        static ref EXAMPLE_TREES: Trees<5> = Trees::parse(read_lines(EXAMPLE).filter_not_empty());
-    }
+    static EXAMPLE_TREES: LazyLock<Trees> = LazyLock::new(|| parse_input(EXAMPLE).unwrap());
 
     #[test]
     fn parse_example() {
         assert_eq!(
             *EXAMPLE_TREES,
-            Trees([
-                [3, 0, 3, 7, 3],
-                [2, 5, 5, 1, 2],
-                [6, 5, 3, 3, 2],
-                [3, 3, 5, 4, 9],
-                [3, 5, 3, 9, 0],
+            Trees(vec![
+                vec![3, 0, 3, 7, 3],
+                vec![2, 5, 5, 1, 2],
+                vec![6, 5, 3, 3, 2],
+                vec![3, 3, 5, 4, 9],
+                vec![3, 5, 3, 9, 0],
             ]),
         );
     }
 
-    #[test]
-    fn part1_example() {
-        let result = EXAMPLE_TREES.visible_trees();
-
-        assert_eq!(result.len(), 21);
-    }
-
     #[test]
     fn test_visible_trees_from_tree() {
         let result = TreeLineIterator::from(&EXAMPLE_TREES.0, 1, 2, North)
@@ -364,9 +360,14 @@ mod test {
     }
 
     #[test]
-    fn part2_example() {
-        let result = EXAMPLE_TREES.highest_scenic_score();
+    fn invalid_tree_heights_fail_the_parts() {
+        let result = solve_part1(&b"303\n2x5\n653\n"[..]);
 
-        assert_eq!(result, 8);
+        assert_eq!(
+            result,
+            Err(SolutionError::Input(
+                "Line 2: Invalid tree height: x".to_string()
+            ))
+        );
     }
 }
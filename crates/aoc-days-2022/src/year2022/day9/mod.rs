@@ -0,0 +1,431 @@
+use itertools::Itertools;
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use std::str::FromStr;
+
+use crate::year2022::day9::Direction::{Down, Left, Right, Up};
+use aoc_core::anonymize::Rng;
+use aoc_core::geometry::Vec2;
+use aoc_core::input::{
+    read_all, read_lines, validate_lines, FilterNotEmpty, Input, InputSource, ParseError, ParseExt,
+};
+use aoc_core::plot::{plot, TERMINAL_HEIGHT, TERMINAL_WIDTH};
+use aoc_core::{Answer, Example, ExampleAnswers, ExtraPart, Params, Part, Solution, SolutionError};
+
+pub struct Day9(Input<Vec<Instruction>>);
+
+aoc_core::register_solution!(Day9);
+
+impl Day9 {
+    pub fn new(input: impl Into<InputSource>) -> Self {
+        Self(Input::new(input, parse_input))
+    }
+}
+
+impl Default for Day9 {
+    fn default() -> Self {
+        Self(Input::for_day(9, parse_input))
+    }
+}
+
+impl Solution for Day9 {
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn with_input(&self, input: InputSource) -> Box<dyn Solution> {
+        Box::new(Self::new(input))
+    }
+
+    fn parse(&mut self) {
+        self.0.parse();
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            example(),
+            Example {
+                name: Some("large"),
+                input: example_file!("day9-large"),
+                answers: ExampleAnswers::new(88, 36),
+            },
+        ]
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        self.0.validate()?;
+        validate_lines::<Instruction>(self.0.raw())
+    }
+
+    fn skipped_lines(&self) -> Vec<ParseError> {
+        self.0.skipped().to_vec()
+    }
+
+    fn part_one(&self) -> Result<Answer, SolutionError> {
+        Ok(solve_part_one(self.0.try_get()?).into())
+    }
+
+    fn part_two(&self) -> Result<Answer, SolutionError> {
+        Ok(solve_part_two(self.0.try_get()?).into())
+    }
+
+    fn expected_part_one(&self) -> Option<Answer> {
+        Some(Answer::Unsigned(5874))
+    }
+
+    fn expected_part_two(&self) -> Option<Answer> {
+        Some(Answer::Unsigned(2467))
+    }
+
+    fn extra_parts(&self) -> Vec<ExtraPart> {
+        vec![ExtraPart {
+            name: "tail",
+            label: "Positions of the 10 knots rope tail",
+        }]
+    }
+
+    fn extra_part(&self, name: &str) -> Option<Result<Answer, SolutionError>> {
+        (name == "tail").then(|| {
+            let positions = Rope::new(10, FollowerRule::default()).execute_all(self.0.try_get()?);
+            Ok(Answer::grid(&plot(
+                positions,
+                TERMINAL_WIDTH,
+                TERMINAL_HEIGHT,
+            )))
+        })
+    }
+
+    fn label(&self, part: Part) -> String {
+        match part {
+            Part::One => "Number of different positions of the two knots rope tail",
+            Part::Two => "Number of different positions of the 10 knots rope tail",
+        }
+        .to_string()
+    }
+
+    fn stats(&self, params: &Params) -> Vec<String> {
+        let rules = match params.get("rule").map(str::parse).transpose() {
+            Ok(Some(rule)) => vec![rule],
+            Ok(None) => FollowerRule::ALL.to_vec(),
+            Err(error) => return vec![error],
+        };
+        let lengths = match params.get("knots").map(parse_rope_length).transpose() {
+            Ok(Some(length)) => vec![length],
+            Ok(None) => vec![2, 10],
+            Err(error) => return vec![error],
+        };
+        rules
+            .into_iter()
+            .cartesian_product(lengths)
+            .map(|(rule, length)| {
+                format!(
+                    "Number of different positions of the {length} knots rope tail with the {rule} rule: {}",
+                    Rope::new(length, rule).execute_all(self.0.get()).len(),
+                )
+            })
+            .collect()
+    }
+}
+
+pub fn solve_part1(input: impl BufRead) -> Result<Answer, SolutionError> {
+    Ok(solve_part_one(&parse_input(&read_all(input)?)).into())
+}
+
+pub fn solve_part2(input: impl BufRead) -> Result<Answer, SolutionError> {
+    Ok(solve_part_two(&parse_input(&read_all(input)?)).into())
+}
+
+fn parse_input(input: &[u8]) -> Vec<Instruction> {
+    read_lines(input).parse_lossy().collect()
+}
+
+fn solve_part_one(instructions: &[Instruction]) -> usize {
+    Rope::new(2, FollowerRule::default())
+        .execute_all(instructions)
+        .len()
+}
+
+fn solve_part_two(instructions: &[Instruction]) -> usize {
+    Rope::new(10, FollowerRule::default())
+        .execute_all(instructions)
+        .len()
+}
+
+fn parse_rope_length(knots: &str) -> Result<usize, String> {
+    match knots.parse() {
+        Ok(length) if length > 0 => Ok(length),
+        _ => Err(format!("Invalid number of knots: {knots}")),
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+enum FollowerRule {
+    /// Moves one step towards the previous knot, diagonally if needed, when they are not adjacent
+    #[default]
+    Standard,
+    /// Moves one step towards the previous knot only when it is 2+ away on the same row or column
+    LazyTail,
+    /// Takes the old position of the previous knot whenever the previous knot moves
+    RigidRope,
+}
+
+impl FollowerRule {
+    const ALL: [FollowerRule; 3] = [
+        FollowerRule::Standard,
+        FollowerRule::LazyTail,
+        FollowerRule::RigidRope,
+    ];
+
+    fn follow(self, knot: Vec2, previous_before: Vec2, previous: Vec2) -> Vec2 {
+        match self {
+            FollowerRule::Standard if !knot.is_adjacent(&previous) => {
+                knot + (previous - knot).signum()
+            }
+            FollowerRule::LazyTail
+                if !knot.is_adjacent(&previous)
+                    && (knot.x == previous.x || knot.y == previous.y) =>
+            {
+                knot + (previous - knot).signum()
+            }
+            FollowerRule::RigidRope if previous_before != previous => previous_before,
+            _ => knot,
+        }
+    }
+}
+
+impl FromStr for FollowerRule {
+    type Err = String;
+
+    fn from_str(rule: &str) -> Result<Self, Self::Err> {
+        match rule {
+            "standard" => Ok(FollowerRule::Standard),
+            "lazy" => Ok(FollowerRule::LazyTail),
+            "rigid" => Ok(FollowerRule::RigidRope),
+            _ => Err(format!(
+                "Invalid follower rule, expected standard, lazy or rigid: {rule}"
+            )),
+        }
+    }
+}
+
+impl Display for FollowerRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FollowerRule::Standard => write!(f, "standard"),
+            FollowerRule::LazyTail => write!(f, "lazy"),
+            FollowerRule::RigidRope => write!(f, "rigid"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Rope {
+    knots: Vec<Vec2>,
+    rule: FollowerRule,
+}
+
+impl Rope {
+    fn new(length: usize, rule: FollowerRule) -> Self {
+        Rope {
+            knots: vec![Vec2::default(); length],
+            rule,
+        }
+    }
+
+    pub fn execute(&mut self, Instruction { direction, steps }: Instruction) -> HashSet<Vec2> {
+        let mut tail_positions = HashSet::new();
+
+        for _ in 0..steps {
+            self.move_head(direction);
+            tail_positions.insert(self.knots[self.knots.len() - 1]);
+        }
+
+        tail_positions
+    }
+
+    fn move_head(&mut self, direction: Direction) {
+        let head = self.knots[0];
+        self.knots[0] = head
+            + match direction {
+                Up => Vec2::UP,
+                Down => Vec2::DOWN,
+                Right => Vec2::RIGHT,
+                Left => Vec2::LEFT,
+            };
+        self.move_knots(head)
+    }
+
+    pub fn execute_all(&mut self, instructions: &[Instruction]) -> HashSet<Vec2> {
+        instructions
+            .iter()
+            .flat_map(|instruction| self.execute(*instruction))
+            .collect()
+    }
+
+    fn move_knots(&mut self, mut previous_before: Vec2) {
+        for i in 1..self.knots.len() {
+            let current_before = self.knots[i];
+            self.knots[i] = self
+                .rule
+                .follow(current_before, previous_before, self.knots[i - 1]);
+            previous_before = current_before;
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Direction {
+    Up,
+    Down,
+    Right,
+    Left,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct Instruction {
+    direction: Direction,
+    steps: usize,
+}
+
+impl FromStr for Instruction {
+    type Err = String;
+
+    fn from_str(instruction: &str) -> Result<Self, Self::Err> {
+        if let Some((direction, steps)) = instruction.split_once(' ') {
+            let direction = match direction {
+                "U" => Up,
+                "D" => Down,
+                "R" => Right,
+                "L" => Left,
+                _ => return Err(format!("Invalid instruction: {instruction}")),
+            };
+            steps
+                .parse()
+                .map(|steps| Instruction { direction, steps })
+                .map_err(|_| format!("Invalid instruction: {instruction}"))
+        } else {
+            Err(format!("Invalid instruction: {instruction}"))
+        }
+    }
+}
+
+/// Rotates or mirrors the whole path with a random symmetry of the grid, so the tail visits the
+/// same number of positions. Lines that are not motions are kept as they are.
+pub(crate) fn anonymize_input(input: &[u8], rng: &mut Rng) -> Result<String, String> {
+    let clockwise = ["U", "R", "D", "L"];
+    let rotation = rng.below(4);
+    let mirror = rng.below(2) == 1;
+    Ok(read_lines(input)
+        .filter_not_empty()
+        .map(|line| {
+            let motion = line.split_once(' ').and_then(|(direction, steps)| {
+                let index = clockwise.iter().position(|d| *d == direction)?;
+                Some((index, steps))
+            });
+            let Some((index, steps)) = motion else {
+                return format!("{line}\n");
+            };
+            let index = if mirror { (4 - index) % 4 } else { index };
+            format!("{} {steps}\n", clockwise[(index + rotation) % 4])
+        })
+        .collect())
+}
+
+solution_example!(day9, part1 = 13, part2 = 1);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_core::input::example;
+
+    #[test]
+    fn part2_large_example() {
+        let instructions = parse_input(&example(9, "large"));
+
+        let result = solve_part_two(&instructions);
+
+        assert_eq!(result, 36);
+    }
+
+    #[test]
+    fn tail_of_the_large_example() {
+        let day = Day9::new(example(9, "large").to_vec());
+
+        let Some(Ok(picture)) = day.extra_part("tail") else {
+            panic!("no map of the tail");
+        };
+
+        assert!(picture.matches(
+            "
+ 6 |#
+   |#             ###
+   |#            #   #
+   | #          #     #
+   |  #          #     #
+   |   #        #       #
+   |    #      #         #
+   |     #              #
+   |      #            #
+   |       #          #
+   |        #        #
+-5 |         ########
+   +----------------------
+    -11                 10
+"
+        ));
+    }
+
+    fn visited_cells(example: &[u8], length: usize, rule: FollowerRule) -> usize {
+        Rope::new(length, rule)
+            .execute_all(&parse_input(example))
+            .len()
+    }
+
+    #[test]
+    fn follower_rules_on_small_example() {
+        let result = FollowerRule::ALL.map(|rule| visited_cells(EXAMPLE, 2, rule));
+
+        assert_eq!(result, [13, 9, 21]);
+    }
+
+    #[test]
+    fn follower_rules_on_large_example() {
+        let result = FollowerRule::ALL.map(|rule| visited_cells(&example(9, "large"), 10, rule));
+
+        assert_eq!(result, [36, 1, 87]);
+    }
+
+    #[test]
+    fn rope_length_from_params() {
+        let params = [("knots".to_string(), "5".to_string())]
+            .into_iter()
+            .collect();
+
+        let result = Day9::new(example(9, "large")).stats(&params);
+
+        assert_eq!(result.len(), FollowerRule::ALL.len());
+        assert!(result[0].starts_with("Number of different positions of the 5 knots rope tail"));
+        assert_eq!(
+            parse_rope_length("0"),
+            Err("Invalid number of knots: 0".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_follower_rule() {
+        assert_eq!("lazy".parse(), Ok(FollowerRule::LazyTail));
+        assert!("stiff".parse::<FollowerRule>().is_err());
+    }
+
+    #[test]
+    fn anonymized_input_keeps_visited_positions() {
+        let large_example = example(9, "large");
+        let input = anonymize_input(&large_example, &mut Rng::new(5)).unwrap();
+
+        let result = solve_part_two(&parse_input(input.as_bytes()));
+
+        assert_ne!(input.as_bytes(), large_example.as_slice());
+        assert_eq!(result, 36);
+    }
+}
@@ -0,0 +1,150 @@
+use proc_macro::{Delimiter, TokenStream, TokenTree};
+
+/// A nom parser of the lines shaped like a format string, e.g.
+/// `parse_line!("move {usize} from {usize} to {usize}")`, returning the values read in place of
+/// the placeholders: a tuple of them, the value alone for a single placeholder or `()` for none.
+///
+/// The types of the placeholders implement `aoc_core::parse::Field`, and `{{` and `}}` stand for
+/// the braces themselves. The parser stops after the end of the pattern rather than requiring
+/// the end of the input, to be combined with other nom parsers.
+#[proc_macro]
+pub fn parse_line(input: TokenStream) -> TokenStream {
+    match pattern(input).and_then(|pattern| segments(&pattern)) {
+        Ok(segments) => parser(&segments),
+        Err(message) => format!("::core::compile_error!({message:?})")
+            .parse()
+            .unwrap(),
+    }
+}
+
+enum Segment {
+    Literal(String),
+    Field(String),
+}
+
+/// The content of the string literal given to the macro.
+fn pattern(input: TokenStream) -> Result<String, String> {
+    let mut tokens = input.into_iter().collect::<Vec<_>>();
+    // A literal passed through a `macro_rules!` macro arrives in an invisible group.
+    while let [TokenTree::Group(group)] = tokens.as_slice() {
+        if group.delimiter() != Delimiter::None {
+            break;
+        }
+        tokens = group.stream().into_iter().collect();
+    }
+    let [TokenTree::Literal(literal)] = tokens.as_slice() else {
+        return Err("parse_line! expects a string literal".to_string());
+    };
+    let literal = literal.to_string();
+    let content = literal
+        .strip_prefix('"')
+        .and_then(|literal| literal.strip_suffix('"'))
+        .ok_or("parse_line! expects a string literal")?;
+    unescape(content)
+}
+
+fn unescape(content: &str) -> Result<String, String> {
+    let mut unescaped = String::with_capacity(content.len());
+    let mut chars = content.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('\\') => unescaped.push('\\'),
+            Some('"') => unescaped.push('"'),
+            Some('\'') => unescaped.push('\''),
+            Some('n') => unescaped.push('\n'),
+            Some('t') => unescaped.push('\t'),
+            escape => {
+                return Err(format!(
+                    "Unsupported escape in parse_line!: \\{}",
+                    escape.map(String::from).unwrap_or_default()
+                ))
+            }
+        }
+    }
+    Ok(unescaped)
+}
+
+fn segments(pattern: &str) -> Result<Vec<Segment>, String> {
+    let mut segments = Vec::new();
+    let mut literal = String::new();
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let field = chars.by_ref().take_while(|&c| c != '}').collect::<String>();
+                let field = field.trim();
+                if field.is_empty() {
+                    return Err(format!("Missing type in placeholder of {pattern:?}"));
+                }
+                if !literal.is_empty() {
+                    segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                }
+                segments.push(Segment::Field(field.to_string()));
+            }
+            '}' => return Err(format!("Unmatched }} in {pattern:?}")),
+            c => literal.push(c),
+        }
+    }
+    if !literal.is_empty() {
+        segments.push(Segment::Literal(literal));
+    }
+    Ok(segments)
+}
+
+/// A function item rather than a closure, whose signature ties the lifetime of the rest of the
+/// input to the input.
+fn parser(segments: &[Segment]) -> TokenStream {
+    let mut types = Vec::new();
+    let mut steps = String::new();
+    for segment in segments {
+        match segment {
+            Segment::Literal(literal) => steps.push_str(&format!(
+                "let (input, _) = ::aoc_core::parse::nom::bytes::complete::tag({literal:?})(input)?;"
+            )),
+            Segment::Field(field) => {
+                steps.push_str(&format!(
+                    "let (input, field{}) = <{field} as ::aoc_core::parse::Field>::parse_field(input)?;",
+                    types.len()
+                ));
+                types.push(field.as_str());
+            }
+        }
+    }
+    let fields = (0..types.len()).map(|index| format!("field{index}"));
+    let (output, value) = match types.as_slice() {
+        [field] => (field.to_string(), "field0".to_string()),
+        _ => (
+            format!(
+                "({})",
+                types
+                    .iter()
+                    .map(|field| format!("{field}, "))
+                    .collect::<String>()
+            ),
+            format!("({})", fields.map(|field| field + ", ").collect::<String>()),
+        ),
+    };
+    format!(
+        "{{
+            fn parse_line(input: &str) -> ::aoc_core::parse::nom::IResult<&str, {output}> {{
+                {steps}
+                Ok((input, {value}))
+            }}
+            parse_line
+        }}"
+    )
+    .parse()
+    .unwrap()
+}